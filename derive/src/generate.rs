@@ -1,12 +1,13 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use iref::{InvalidIri, Iri, IriBuf};
-use proc_macro2::{Span, TokenStream, TokenTree};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::{ToTokens, format_ident, quote};
 use static_iref::iri;
 
-use syn::{punctuated::Punctuated, spanned::Spanned};
+use syn::{ext::IdentExt, punctuated::Punctuated, spanned::Spanned};
 
+pub mod context;
 pub mod de;
 pub mod ser;
 
@@ -20,14 +21,38 @@ pub enum Error {
 	#[error("invalid `ld` attribute: {0}")]
 	InvalidAttribute(AttributeError, Span),
 
-	#[error("missing field serialization method")]
+	#[error("missing `#[ld(...)]` attribute")]
 	UnknownFieldSerializationMethod(Span),
 
 	#[error("invalid IRI `{0}`")]
 	InvalidIri(String, Span),
 
+	#[error("unknown prefix `{0}`")]
+	UnknownPrefix(String, Option<String>, Span),
+
+	#[error("predicate `{0}` is used by more than one field")]
+	DuplicatePredicate(String, Span),
+
+	#[error("only one field can be marked with `#[ld(id)]`")]
+	DuplicateId(Span),
+
+	#[error("only one field can be marked with `#[ld(default_graph)]`")]
+	DuplicateDefaultGraph(Span),
+
+	#[error("graph `{0}` is used by more than one field")]
+	DuplicateNamedGraph(String, Span),
+
 	#[error("missing variant IRI")]
 	MissingVariantIri(Span),
+
+	#[error("only structs can be datasets")]
+	InvalidDatasetType(Span),
+
+	#[error("missing field graph")]
+	MissingFieldGraph(Span),
+
+	#[error("deserializing `#[ld(include)]` requires the type of the included nodes")]
+	UntypedInclude(Span),
 }
 
 impl Error {
@@ -37,10 +62,75 @@ impl Error {
 			Self::InvalidAttribute(_, span) => *span,
 			Self::UnknownFieldSerializationMethod(span) => *span,
 			Self::InvalidIri(_, span) => *span,
+			Self::UnknownPrefix(_, _, span) => *span,
+			Self::DuplicatePredicate(_, span) => *span,
+			Self::DuplicateId(span) => *span,
+			Self::DuplicateDefaultGraph(span) => *span,
+			Self::DuplicateNamedGraph(_, span) => *span,
 			Self::MissingVariantIri(span) => *span,
+			Self::InvalidDatasetType(span) => *span,
+			Self::MissingFieldGraph(span) => *span,
+			Self::UntypedInclude(span) => *span,
+		}
+	}
+
+	/// Suggests a correction for the error, if any.
+	pub fn help(&self) -> Option<String> {
+		match self {
+			Self::UnknownFieldSerializationMethod(_) => Some(
+				"add a predicate IRI such as `#[ld(\"prefix:name\")]`, or one of `id`, `flatten`, `include` or `ignore`".to_owned(),
+			),
+			Self::InvalidIri(iri, _)
+			| Self::InvalidAttribute(AttributeError::InvalidCompactIri(iri), _) => {
+				if iri.contains(char::is_whitespace) {
+					Some("IRIs cannot contain whitespace, percent-encode it as `%20`".to_owned())
+				} else if !iri.contains(':') {
+					Some("expected an absolute IRI or a compact IRI such as `prefix:name`".to_owned())
+				} else {
+					None
+				}
+			}
+			Self::UnknownPrefix(prefix, Some(suggestion), _) => Some(format!(
+				"did you mean `{suggestion}`? To use `{prefix}` as a scheme, declare it with `#[ld(prefix(\"{prefix}\" = \"{prefix}:\"))]`"
+			)),
+			Self::UnknownPrefix(prefix, None, _) => {
+				let namespace = WELL_KNOWN_PREFIXES
+					.iter()
+					.find(|(p, _)| p == prefix)
+					.map(|(_, namespace)| *namespace)
+					.unwrap_or("...");
+				Some(format!(
+					"declare it on the type with `#[ld(prefix(\"{prefix}\" = \"{namespace}\"))]`"
+				))
+			}
+			Self::InvalidAttribute(AttributeError::MissingDefaultPredicate, _) => Some(
+				"add a predicate IRI, or use `#[ld(ignore)]` to always use the default value"
+					.to_owned(),
+			),
+			Self::UntypedInclude(_) => {
+				Some("add an `rdf:type` such as `#[ld(include = \"prefix:Class\")]`".to_owned())
+			}
+			Self::InvalidAttribute(AttributeError::UnsupportedDatasetField(_), _) => Some(
+				"dataset fields only accept `default_graph`, `named_graph` or `ignore`".to_owned(),
+			),
+			Self::DuplicatePredicate(_, _) => Some(
+				"merge the fields into a collection, or mark one of them with `#[ld(ignore)]`"
+					.to_owned(),
+			),
+			_ => None,
 		}
 	}
 }
+
+/// Prefixes suggested when used without being declared.
+const WELL_KNOWN_PREFIXES: &[(&str, &str)] = &[
+	("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+	("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+	("xsd", "http://www.w3.org/2001/XMLSchema#"),
+	("owl", "http://www.w3.org/2002/07/owl#"),
+	("schema", "https://schema.org/"),
+];
+
 #[derive(Debug, thiserror::Error)]
 pub enum AttributeError {
 	#[error("invalid shape")]
@@ -58,8 +148,8 @@ pub enum AttributeError {
 	#[error("unexpected token")]
 	UnexpectedToken,
 
-	#[error("invalid compact IRI")]
-	InvalidCompactIri,
+	#[error("invalid compact IRI `{0}`")]
+	InvalidCompactIri(String),
 
 	#[error("missing `=`")]
 	MissingEq,
@@ -75,24 +165,264 @@ pub enum AttributeError {
 
 	#[error("invalid type")]
 	InvalidType,
+
+	#[error("a variant class cannot be combined with a variant IRI")]
+	NestedClass,
+
+	#[error("`set` and `list` cannot be combined")]
+	ConflictingContainers,
+
+	#[error("`lang` cannot be combined with `set`, `list`, `lenient` or `reverse`")]
+	ConflictingLanguages,
+
+	#[error("`lang` requires a `String` or `Option<String>` field")]
+	InvalidLanguageField,
+
+	#[error(
+		"`datatype` cannot be combined with `graph`, `set`, `list`, `lenient`, `lang` or `reverse`"
+	)]
+	ConflictingDatatype,
+
+	#[error("invalid function path")]
+	InvalidPath,
+
+	#[error(
+		"`with` cannot be combined with `graph`, `set`, `list`, `lenient`, `lang` or `datatype`"
+	)]
+	ConflictingWith,
+
+	#[error("`default` cannot be combined with `id`, `flatten` or `include`")]
+	ConflictingDefault,
+
+	#[error("`default` requires a predicate IRI")]
+	MissingDefaultPredicate,
+
+	#[error("`id` cannot be combined with a predicate IRI")]
+	ConflictingId,
+
+	#[error("`default_graph` cannot be combined with `named_graph`")]
+	ConflictingGraph,
+
+	#[error("{0} is not supported on dataset fields")]
+	UnsupportedDatasetField(&'static str),
+
+	#[error(
+		"unknown rename rule, expected one of `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case` or `SCREAMING-KEBAB-CASE`"
+	)]
+	UnknownRenameRule,
 }
 
 pub struct CompactIri(IriBuf, Span);
 
 impl CompactIri {
+	fn new(value: String, span: Span) -> Result<Self, Error> {
+		IriBuf::new(value)
+			.map(|iri| Self(iri, span))
+			.map_err(|InvalidIri(s)| Error::InvalidIri(s, span))
+	}
+
+	/// Expands the compact IRI with the given prefixes.
+	///
+	/// An undeclared prefix is taken as the scheme of an absolute IRI
+	/// (`urn:isbn:...`, `geo:...`), unless it is a well-known prefix or close
+	/// to a declared or well-known one, in which case it is most likely a
+	/// mistake.
 	pub fn expand(&self, prefixes: &HashMap<String, String>) -> Result<IriBuf, Error> {
 		let (prefix, suffix) = self.0.split_once(':').unwrap();
 		match prefixes.get(prefix) {
 			Some(expanded_prefix) => IriBuf::new(format!("{expanded_prefix}{suffix}"))
 				.map_err(|InvalidIri(s)| Error::InvalidIri(s, self.1)),
-			None => Ok(self.0.clone()),
+			None if suffix.starts_with("//") => Ok(self.0.clone()),
+			None if WELL_KNOWN_PREFIXES.iter().any(|(p, _)| *p == prefix) => {
+				Err(Error::UnknownPrefix(prefix.to_owned(), None, self.1))
+			}
+			None => match closest_prefix(prefix, prefixes) {
+				Some(p) => Err(Error::UnknownPrefix(
+					prefix.to_owned(),
+					Some(format!("{p}:{suffix}")),
+					self.1,
+				)),
+				None => Ok(self.0.clone()),
+			},
 		}
 	}
 }
 
+/// Finds the declared or well-known prefix closest to the given unknown one,
+/// if it is close enough to be a typo.
+fn closest_prefix<'a>(prefix: &str, prefixes: &'a HashMap<String, String>) -> Option<&'a str> {
+	// Short prefixes are only one edit away from many unrelated schemes.
+	let max_distance = if prefix.chars().count() < 5 { 1 } else { 2 };
+
+	prefixes
+		.keys()
+		.map(String::as_str)
+		.chain(WELL_KNOWN_PREFIXES.iter().map(|(p, _)| *p))
+		.map(|p| (edit_distance(prefix, p), p))
+		.filter(|(d, _)| *d <= max_distance)
+		.min()
+		.map(|(_, p)| p)
+}
+
+/// Edit distance between two strings, counting the transposition of two
+/// adjacent characters as a single edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+	let a: Vec<char> = a.chars().collect();
+	let b: Vec<char> = b.chars().collect();
+	let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+	for (i, row) in rows.iter_mut().enumerate() {
+		row[0] = i;
+	}
+
+	for (j, d) in rows[0].iter_mut().enumerate() {
+		*d = j;
+	}
+
+	for i in 1..=a.len() {
+		for j in 1..=b.len() {
+			let cost = usize::from(a[i - 1] != b[j - 1]);
+			let mut d = (rows[i - 1][j] + 1)
+				.min(rows[i][j - 1] + 1)
+				.min(rows[i - 1][j - 1] + cost);
+
+			if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+				d = d.min(rows[i - 2][j - 2] + 1)
+			}
+
+			rows[i][j] = d;
+		}
+	}
+
+	rows[a.len()][b.len()]
+}
+
+/// Tracks the identifier, predicates and graphs of the fields of a struct or
+/// enum variant, to report duplicates.
+#[derive(Default)]
+pub struct FieldsUsage {
+	id: bool,
+	predicates: HashSet<(IriBuf, bool)>,
+	default_graph: bool,
+	named_graphs: HashSet<IriBuf>,
+}
+
+impl FieldsUsage {
+	pub fn check(
+		&mut self,
+		prefixes: &HashMap<String, String>,
+		attrs: &FieldAttributes,
+		span: Span,
+	) -> Result<(), Error> {
+		if attrs.ignore {
+			return Ok(());
+		}
+
+		if attrs.is_id && std::mem::replace(&mut self.id, true) {
+			return Err(Error::DuplicateId(span));
+		}
+
+		if attrs.default_graph && std::mem::replace(&mut self.default_graph, true) {
+			return Err(Error::DuplicateDefaultGraph(span));
+		}
+
+		if let Some(compact_iri) = &attrs.named_graph {
+			let iri = compact_iri.expand(prefixes)?;
+			if !self.named_graphs.insert(iri.clone()) {
+				return Err(Error::DuplicateNamedGraph(iri.into_string(), compact_iri.1));
+			}
+		}
+
+		// Language fields may select different languages of the same predicate.
+		if let Some(compact_iri) = &attrs.iri
+			&& attrs.lang.is_none()
+		{
+			let iri = compact_iri.expand(prefixes)?;
+			if !self.predicates.insert((iri.clone(), attrs.reverse)) {
+				return Err(Error::DuplicatePredicate(iri.into_string(), compact_iri.1));
+			}
+		}
+
+		Ok(())
+	}
+}
+
 pub struct TypeAttributes {
 	prefixes: HashMap<String, String>,
 	type_: Option<CompactIri>,
+	skip_type_check: bool,
+	dataset: bool,
+	vocab: Option<String>,
+	rename_all: RenameRule,
+}
+
+/// Case convention turning field names into vocabulary terms.
+#[derive(Default, Clone, Copy)]
+pub enum RenameRule {
+	/// Keep the field name as is.
+	#[default]
+	None,
+	LowerCase,
+	UpperCase,
+	PascalCase,
+	CamelCase,
+	SnakeCase,
+	ScreamingSnakeCase,
+	KebabCase,
+	ScreamingKebabCase,
+}
+
+impl RenameRule {
+	fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"lowercase" => Some(Self::LowerCase),
+			"UPPERCASE" => Some(Self::UpperCase),
+			"PascalCase" => Some(Self::PascalCase),
+			"camelCase" => Some(Self::CamelCase),
+			"snake_case" => Some(Self::SnakeCase),
+			"SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnakeCase),
+			"kebab-case" => Some(Self::KebabCase),
+			"SCREAMING-KEBAB-CASE" => Some(Self::ScreamingKebabCase),
+			_ => None,
+		}
+	}
+
+	/// Applies the rule to a `snake_case` field name.
+	pub fn apply(&self, name: &str) -> String {
+		let words = name.split('_').filter(|w| !w.is_empty());
+		match self {
+			Self::None => name.to_owned(),
+			Self::LowerCase => name.replace('_', "").to_lowercase(),
+			Self::UpperCase => name.replace('_', "").to_uppercase(),
+			Self::PascalCase => words.map(capitalize).collect(),
+			Self::CamelCase => {
+				let mut result = String::new();
+				for (i, word) in words.enumerate() {
+					if i == 0 {
+						result.push_str(&word.to_lowercase())
+					} else {
+						result.push_str(&capitalize(word))
+					}
+				}
+				result
+			}
+			Self::SnakeCase => name.to_lowercase(),
+			Self::ScreamingSnakeCase => name.to_uppercase(),
+			Self::KebabCase => name.to_lowercase().replace('_', "-"),
+			Self::ScreamingKebabCase => name.to_uppercase().replace('_', "-"),
+		}
+	}
+}
+
+fn capitalize(word: &str) -> String {
+	let mut chars = word.chars();
+	match chars.next() {
+		Some(c) => c
+			.to_uppercase()
+			.chain(chars.flat_map(char::to_lowercase))
+			.collect(),
+		None => String::new(),
+	}
 }
 
 pub struct FieldAttributes {
@@ -101,10 +431,65 @@ pub struct FieldAttributes {
 	flatten: bool,
 	is_id: bool,
 	graph_value: bool,
+	reverse: bool,
+	include: bool,
+	include_type: Option<CompactIri>,
+	default_graph: bool,
+	named_graph: Option<CompactIri>,
+	set: bool,
+	list: bool,
+	lenient: bool,
+	lang: Option<Vec<syn::LitStr>>,
+	datatype: Option<CompactIri>,
+	serialize_with: Option<syn::Path>,
+	deserialize_with: Option<syn::Path>,
+	default: Option<(FieldDefault, Span)>,
+	skip_serializing_if: Option<syn::Path>,
+}
+
+impl FieldAttributes {
+	/// Checks that only graph attributes are used on a dataset field.
+	pub fn check_dataset_field(&self, span: Span) -> Result<(), Error> {
+		let unsupported = [
+			(self.iri.is_some(), "a predicate IRI"),
+			(self.flatten, "`flatten`"),
+			(self.is_id, "`id`"),
+			(self.graph_value, "`graph`"),
+			(self.reverse, "`reverse`"),
+			(self.include, "`include`"),
+			(self.set, "`set`"),
+			(self.list, "`list`"),
+			(self.lenient, "`lenient`"),
+			(self.lang.is_some(), "`lang`"),
+			(self.datatype.is_some(), "`datatype`"),
+			(self.serialize_with.is_some(), "`serialize_with`"),
+			(self.deserialize_with.is_some(), "`deserialize_with`"),
+			(self.default.is_some(), "`default`"),
+			(self.skip_serializing_if.is_some(), "`skip_serializing_if`"),
+		];
+
+		match unsupported.into_iter().find(|(used, _)| *used) {
+			Some((_, name)) => Err(Error::InvalidAttribute(
+				AttributeError::UnsupportedDatasetField(name),
+				span,
+			)),
+			None => Ok(()),
+		}
+	}
+}
+
+/// Value given to a field whose predicate has no object.
+pub enum FieldDefault {
+	/// `#[ld(default)]`, using the `Default` implementation of the field type.
+	Default,
+
+	/// `#[ld(default = "fn")]`, calling the given function.
+	Function(syn::Path),
 }
 
 pub struct VariantAttributes {
 	iri: Option<CompactIri>,
+	class: Option<CompactIri>,
 }
 
 #[derive(Default, Clone, Copy)]
@@ -219,6 +604,10 @@ fn read_type_attributes(attributes: Vec<syn::Attribute>) -> Result<TypeAttribute
 	let mut result = TypeAttributes {
 		prefixes: HashMap::new(),
 		type_: None,
+		skip_type_check: false,
+		dataset: false,
+		vocab: None,
+		rename_all: RenameRule::None,
 	};
 
 	for attr in attributes {
@@ -234,9 +623,9 @@ fn read_type_attributes(attributes: Vec<syn::Attribute>) -> Result<TypeAttribute
 								if id == "prefix" {
 									match tokens.next() {
 										Some(TokenTree::Group(g)) => {
-											let (prefix, suffix) =
-												parse_prefix_binding(g.stream(), span)?;
-											result.prefixes.insert(prefix, suffix);
+											result
+												.prefixes
+												.extend(parse_prefix_bindings(g.stream(), span)?);
 										}
 										Some(token) => {
 											return Err(Error::InvalidAttribute(
@@ -307,6 +696,22 @@ fn read_type_attributes(attributes: Vec<syn::Attribute>) -> Result<TypeAttribute
 											));
 										}
 									}
+								} else if id == "skip_type_check" {
+									result.skip_type_check = true
+								} else if id == "dataset" {
+									result.dataset = true
+								} else if id == "vocab" {
+									let l = read_attribute_value(&mut tokens, id.span())?;
+									result.vocab = Some(l.value());
+								} else if id == "rename_all" {
+									let l = read_attribute_value(&mut tokens, id.span())?;
+									result.rename_all = RenameRule::from_name(&l.value())
+										.ok_or_else(|| {
+											Error::InvalidAttribute(
+												AttributeError::UnknownRenameRule,
+												l.span(),
+											)
+										})?;
 								} else {
 									return Err(Error::InvalidAttribute(
 										AttributeError::UnknownIdent,
@@ -337,8 +742,36 @@ fn read_type_attributes(attributes: Vec<syn::Attribute>) -> Result<TypeAttribute
 	Ok(result)
 }
 
-fn parse_prefix_binding(tokens: TokenStream, span: Span) -> Result<(String, String), Error> {
-	let mut tokens = tokens.into_iter();
+/// Parses the comma separated `"prefix" = "namespace"` bindings of a
+/// `prefix(...)` type attribute.
+fn parse_prefix_bindings(tokens: TokenStream, span: Span) -> Result<Vec<(String, String)>, Error> {
+	let mut tokens = tokens.into_iter().peekable();
+	let mut bindings = vec![parse_prefix_binding(&mut tokens, span)?];
+
+	loop {
+		match tokens.next() {
+			Some(TokenTree::Punct(p)) if p.as_char() == ',' => {
+				if tokens.peek().is_none() {
+					break Ok(bindings);
+				}
+
+				bindings.push(parse_prefix_binding(&mut tokens, span)?)
+			}
+			Some(token) => {
+				break Err(Error::InvalidAttribute(
+					AttributeError::UnexpectedToken,
+					token.span(),
+				));
+			}
+			None => break Ok(bindings),
+		}
+	}
+}
+
+fn parse_prefix_binding(
+	tokens: &mut impl Iterator<Item = TokenTree>,
+	span: Span,
+) -> Result<(String, String), Error> {
 	match tokens.next() {
 		Some(TokenTree::Literal(l)) => {
 			let l = syn::Lit::new(l);
@@ -381,23 +814,83 @@ fn parse_prefix_binding(tokens: TokenStream, span: Span) -> Result<(String, Stri
 	}
 }
 
+/// Reads the attributes of the given field, deriving its predicate IRI from
+/// the type `vocab` when none is given explicitly.
+fn read_field_attributes_in(
+	type_attrs: &TypeAttributes,
+	ident: Option<&Ident>,
+	attributes: Vec<syn::Attribute>,
+) -> Result<FieldAttributes, Error> {
+	let mut attrs = read_field_attributes(attributes)?;
+
+	if let (Some(vocab), Some(ident)) = (&type_attrs.vocab, ident)
+		&& attrs.iri.is_none()
+		&& !(attrs.ignore || attrs.flatten || attrs.is_id || attrs.include)
+	{
+		let name = ident.unraw().to_string();
+		let term = type_attrs.rename_all.apply(&name);
+		let iri = CompactIri::new(format!("{vocab}{term}"), ident.span())?;
+		attrs.iri = Some(iri);
+	}
+
+	// Without a predicate, the field could be deserialized but not
+	// serialized.
+	if let (Some((_, span)), None) = (&attrs.default, &attrs.iri) {
+		return Err(Error::InvalidAttribute(
+			AttributeError::MissingDefaultPredicate,
+			*span,
+		));
+	}
+
+	Ok(attrs)
+}
+
 fn read_field_attributes(attributes: Vec<syn::Attribute>) -> Result<FieldAttributes, Error> {
 	let mut ignore = false;
 	let mut iri = None;
 	let mut flatten = false;
 	let mut is_id = false;
 	let mut graph_value = false;
+	let mut reverse = false;
+	let mut include = false;
+	let mut include_type = None;
+	let mut default_graph = false;
+	let mut named_graph = None;
+	let mut set = false;
+	let mut list = false;
+	let mut lenient = false;
+	let mut lang = None;
+	let mut datatype = None;
+	let mut serialize_with = None;
+	let mut deserialize_with = None;
+	let mut with_span = None;
+	let mut default = None;
+	let mut skip_serializing_if = None;
 
 	for attr in attributes {
 		if attr.path().is_ident("ld") {
 			match attr.meta {
-				syn::Meta::List(list) => {
-					let mut tokens = list.tokens.into_iter();
+				syn::Meta::List(meta) => {
+					let mut tokens = meta.tokens.into_iter().peekable();
 					while let Some(token) = tokens.next() {
 						match token {
 							TokenTree::Ident(id) => {
 								if id == "ignore" {
 									ignore = true
+								} else if id == "default" {
+									let value = match tokens.peek() {
+										Some(TokenTree::Punct(p)) if p.as_char() == '=' => {
+											FieldDefault::Function(read_path_value(
+												&mut tokens,
+												id.span(),
+											)?)
+										}
+										_ => FieldDefault::Default,
+									};
+									default = Some((value, id.span()));
+								} else if id == "skip_serializing_if" {
+									skip_serializing_if =
+										Some(read_path_value(&mut tokens, id.span())?);
 								} else if id == "flatten" {
 									flatten = true
 								} else if id == "id" {
@@ -406,6 +899,55 @@ fn read_field_attributes(attributes: Vec<syn::Attribute>) -> Result<FieldAttribu
 									iri = Some(CompactIri(RDF_TYPE.to_owned(), id.span()));
 								} else if id == "graph" {
 									graph_value = true
+								} else if id == "reverse" {
+									let l = read_attribute_value(&mut tokens, id.span())?;
+									iri = Some(compact_iri_literal(l)?);
+									reverse = true
+								} else if id == "include" {
+									if matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '=')
+									{
+										let l = read_attribute_value(&mut tokens, id.span())?;
+										include_type = Some(compact_iri_literal(l)?);
+									}
+
+									include = true
+								} else if id == "default_graph" {
+									default_graph = true
+								} else if id == "named_graph" {
+									let l = read_attribute_value(&mut tokens, id.span())?;
+									named_graph = Some(compact_iri_literal(l)?);
+								} else if id == "set" || id == "list" {
+									if id == "set" {
+										set = true
+									} else {
+										list = true
+									}
+
+									if set && list {
+										return Err(Error::InvalidAttribute(
+											AttributeError::ConflictingContainers,
+											id.span(),
+										));
+									}
+								} else if id == "lenient" {
+									lenient = true
+								} else if id == "datatype" {
+									let l = read_attribute_value(&mut tokens, id.span())?;
+									datatype = Some(compact_iri_literal(l)?);
+								} else if id == "with" {
+									let path = read_path_value(&mut tokens, id.span())?;
+									serialize_with = Some(with_function(&path, "serialize"));
+									deserialize_with = Some(with_function(&path, "deserialize"));
+									with_span = Some(id.span());
+								} else if id == "serialize_with" {
+									serialize_with = Some(read_path_value(&mut tokens, id.span())?);
+									with_span = Some(id.span());
+								} else if id == "deserialize_with" {
+									deserialize_with =
+										Some(read_path_value(&mut tokens, id.span())?);
+									with_span = Some(id.span());
+								} else if id == "lang" {
+									lang = Some(read_language_ranges(&mut tokens, id.span())?);
 								} else {
 									return Err(Error::InvalidAttribute(
 										AttributeError::UnknownIdent,
@@ -422,7 +964,7 @@ fn read_field_attributes(attributes: Vec<syn::Attribute>) -> Result<FieldAttribu
 										}
 										Err(_) => {
 											return Err(Error::InvalidAttribute(
-												AttributeError::InvalidCompactIri,
+												AttributeError::InvalidCompactIri(l.value()),
 												l.span(),
 											));
 										}
@@ -465,26 +1007,221 @@ fn read_field_attributes(attributes: Vec<syn::Attribute>) -> Result<FieldAttribu
 		}
 	}
 
+	if let Some(ranges) = &lang
+		&& (set || list || lenient || reverse)
+	{
+		return Err(Error::InvalidAttribute(
+			AttributeError::ConflictingLanguages,
+			ranges[0].span(),
+		));
+	}
+
+	if let Some(datatype) = &datatype
+		&& (graph_value || set || list || lenient || reverse || lang.is_some())
+	{
+		return Err(Error::InvalidAttribute(
+			AttributeError::ConflictingDatatype,
+			datatype.1,
+		));
+	}
+
+	if let Some(span) = with_span
+		&& (graph_value || set || list || lenient || lang.is_some() || datatype.is_some())
+	{
+		return Err(Error::InvalidAttribute(
+			AttributeError::ConflictingWith,
+			span,
+		));
+	}
+
+	if is_id && let Some(iri) = &iri {
+		return Err(Error::InvalidAttribute(
+			AttributeError::ConflictingId,
+			iri.1,
+		));
+	}
+
+	if default_graph && let Some(named_graph) = &named_graph {
+		return Err(Error::InvalidAttribute(
+			AttributeError::ConflictingGraph,
+			named_graph.1,
+		));
+	}
+
+	if let Some((_, span)) = &default
+		&& (is_id || flatten || include)
+	{
+		return Err(Error::InvalidAttribute(
+			AttributeError::ConflictingDefault,
+			*span,
+		));
+	}
+
 	Ok(FieldAttributes {
 		ignore,
 		iri,
 		flatten,
 		is_id,
 		graph_value,
+		reverse,
+		include,
+		include_type,
+		default_graph,
+		named_graph,
+		set,
+		list,
+		lenient,
+		lang,
+		datatype,
+		serialize_with,
+		deserialize_with,
+		default,
+		skip_serializing_if,
 	})
 }
 
+/// Number of values held by a field, guessed from the shape of its type.
+pub enum Multiplicity<'a> {
+	/// Single value.
+	One,
+
+	/// `Option<T>`, holding at most one `T`.
+	Optional(&'a syn::Type),
+
+	/// Collection of `T` (`Vec`, `VecDeque`, `HashSet`, `BTreeSet` or slice).
+	Many(&'a syn::Type),
+}
+
+impl<'a> Multiplicity<'a> {
+	pub fn of(ty: &'a syn::Type) -> Self {
+		match ty {
+			syn::Type::Path(path) => {
+				let Some(segment) = path.path.segments.last() else {
+					return Self::One;
+				};
+
+				let item = match &segment.arguments {
+					syn::PathArguments::AngleBracketed(args) => match args.args.first() {
+						Some(syn::GenericArgument::Type(item)) => item,
+						_ => return Self::One,
+					},
+					_ => return Self::One,
+				};
+
+				match segment.ident.to_string().as_str() {
+					"Option" => Self::Optional(item),
+					"Vec" | "VecDeque" | "HashSet" | "BTreeSet" => Self::Many(item),
+					_ => Self::One,
+				}
+			}
+			syn::Type::Reference(r) => Self::of(&r.elem),
+			syn::Type::Slice(s) => Self::Many(&s.elem),
+			_ => Self::One,
+		}
+	}
+}
+
+/// Reads the `= "value"` part of a `name = "value"` attribute.
+fn read_attribute_value(
+	tokens: &mut impl Iterator<Item = TokenTree>,
+	span: Span,
+) -> Result<syn::LitStr, Error> {
+	match tokens.next() {
+		Some(TokenTree::Punct(p)) if p.as_char() == '=' => match tokens.next() {
+			Some(TokenTree::Literal(l)) => match syn::Lit::new(l) {
+				syn::Lit::Str(l) => Ok(l),
+				l => Err(Error::InvalidAttribute(
+					AttributeError::ExpectedString,
+					l.span(),
+				)),
+			},
+			Some(token) => Err(Error::InvalidAttribute(
+				AttributeError::ExpectedString,
+				token.span(),
+			)),
+			None => Err(Error::InvalidAttribute(
+				AttributeError::ExpectedString,
+				span,
+			)),
+		},
+		Some(token) => Err(Error::InvalidAttribute(
+			AttributeError::UnexpectedToken,
+			token.span(),
+		)),
+		None => Err(Error::InvalidAttribute(AttributeError::MissingEq, span)),
+	}
+}
+
+/// Reads the parenthesized list of language ranges following `lang`.
+fn read_language_ranges(
+	tokens: &mut impl Iterator<Item = TokenTree>,
+	span: Span,
+) -> Result<Vec<syn::LitStr>, Error> {
+	use syn::parse::Parser;
+	match tokens.next() {
+		Some(TokenTree::Group(group))
+			if group.delimiter() == proc_macro2::Delimiter::Parenthesis =>
+		{
+			let ranges = Punctuated::<syn::LitStr, syn::Token![,]>::parse_terminated
+				.parse2(group.stream())
+				.map_err(|e| Error::InvalidAttribute(AttributeError::ExpectedString, e.span()))?;
+
+			if ranges.is_empty() {
+				return Err(Error::InvalidAttribute(AttributeError::Empty, group.span()));
+			}
+
+			Ok(ranges.into_iter().collect())
+		}
+		Some(token) => Err(Error::InvalidAttribute(
+			AttributeError::UnexpectedToken,
+			token.span(),
+		)),
+		None => Err(Error::InvalidAttribute(AttributeError::Empty, span)),
+	}
+}
+
+/// Reads a `= "path"` attribute value as a function path.
+fn read_path_value(
+	tokens: &mut impl Iterator<Item = TokenTree>,
+	span: Span,
+) -> Result<syn::Path, Error> {
+	let l = read_attribute_value(tokens, span)?;
+	l.parse()
+		.map_err(|_| Error::InvalidAttribute(AttributeError::InvalidPath, l.span()))
+}
+
+/// Appends the given function name to a `with` module path.
+fn with_function(module: &syn::Path, name: &str) -> syn::Path {
+	let mut path = module.clone();
+	path.segments.push(syn::PathSegment::from(syn::Ident::new(
+		name,
+		Span::call_site(),
+	)));
+	path
+}
+
+fn compact_iri_literal(l: syn::LitStr) -> Result<CompactIri, Error> {
+	match IriBuf::new(l.value()) {
+		Ok(value) => Ok(CompactIri(value, l.span())),
+		Err(_) => Err(Error::InvalidAttribute(
+			AttributeError::InvalidCompactIri(l.value()),
+			l.span(),
+		)),
+	}
+}
+
 fn read_variant_attributes(attributes: Vec<syn::Attribute>) -> Result<VariantAttributes, Error> {
 	let mut iri = None;
+	let mut class = None;
 
 	for attr in attributes {
 		if attr.path().is_ident("ld") {
 			let span = attr.span();
 			match attr.meta {
-				syn::Meta::List(list) => {
-					let VariantAttribute::Iri(i) = read_variant_attribute(list.tokens, span)?;
-					iri = Some(i);
-				}
+				syn::Meta::List(list) => match read_variant_attribute(list.tokens, span)? {
+					VariantAttribute::Iri(i) => iri = Some(i),
+					VariantAttribute::Class(c) => class = Some(c),
+				},
 				_ => {
 					return Err(Error::InvalidAttribute(
 						AttributeError::InvalidShape,
@@ -495,37 +1232,40 @@ fn read_variant_attributes(attributes: Vec<syn::Attribute>) -> Result<VariantAtt
 		}
 	}
 
-	Ok(VariantAttributes { iri })
+	if let (Some(_), Some(class)) = (&iri, &class) {
+		return Err(Error::InvalidAttribute(
+			AttributeError::NestedClass,
+			class.1,
+		));
+	}
+
+	Ok(VariantAttributes { iri, class })
 }
 
 enum VariantAttribute {
 	Iri(CompactIri),
+	Class(CompactIri),
 }
 
 fn read_variant_attribute(tokens: TokenStream, span: Span) -> Result<VariantAttribute, Error> {
 	let mut tokens = tokens.into_iter();
 	match tokens.next() {
 		Some(TokenTree::Group(g)) => read_variant_attribute(g.stream(), span),
-		Some(TokenTree::Literal(l)) => {
-			let l = syn::Lit::new(l);
-			match l {
-				syn::Lit::Str(l) => match IriBuf::new(l.value()) {
-					Ok(value) => Ok(VariantAttribute::Iri(CompactIri(value, l.span()))),
-					Err(_) => Err(Error::InvalidAttribute(
-						AttributeError::InvalidCompactIri,
-						l.span(),
-					)),
-				},
-				l => Err(Error::InvalidAttribute(
-					AttributeError::ExpectedString,
-					l.span(),
-				)),
-			}
-		}
+		Some(TokenTree::Literal(l)) => match syn::Lit::new(l) {
+			syn::Lit::Str(l) => compact_iri_literal(l).map(VariantAttribute::Iri),
+			l => Err(Error::InvalidAttribute(
+				AttributeError::ExpectedString,
+				l.span(),
+			)),
+		},
 		Some(TokenTree::Ident(id)) if id == "type" => Ok(VariantAttribute::Iri(CompactIri(
 			RDF_TYPE.to_owned(),
 			id.span(),
 		))),
+		Some(TokenTree::Ident(id)) if id == "class" => {
+			let l = read_attribute_value(&mut tokens, id.span())?;
+			compact_iri_literal(l).map(VariantAttribute::Class)
+		}
 		Some(token) => Err(Error::InvalidAttribute(
 			AttributeError::UnexpectedToken,
 			token.span(),
@@ -0,0 +1,254 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{DeriveInput, ext::IdentExt, spanned::Spanned};
+
+use super::{
+	Error, FieldAttributes, FieldsUsage, TypeAttributes, read_field_attributes_in,
+	read_type_attributes, read_variant_attributes,
+};
+
+pub fn generate(input: DeriveInput) -> Result<TokenStream, Error> {
+	let attrs = read_type_attributes(input.attrs)?;
+	let mut context = Context::default();
+
+	let mut bindings: Vec<_> = attrs.prefixes.iter().collect();
+	bindings.sort();
+	for (prefix, namespace) in bindings {
+		context.term(prefix, quote!(#namespace));
+	}
+
+	if let Some(vocab) = &attrs.vocab {
+		context.term("@vocab", quote!(#vocab));
+	}
+
+	if let Some(ty) = &attrs.type_ {
+		let iri = ty.expand(&attrs.prefixes)?.into_string();
+		context.term(&input.ident.to_string(), quote!(#iri));
+	}
+
+	match input.data {
+		syn::Data::Struct(s) => context.fields(&attrs, s.fields)?,
+		syn::Data::Enum(e) => {
+			for variant in e.variants {
+				let v_attrs = read_variant_attributes(variant.attrs)?;
+				let name = variant.ident.to_string();
+
+				if let Some(class) = &v_attrs.class {
+					let iri = class.expand(&attrs.prefixes)?.into_string();
+					context.term(&name, quote!(#iri));
+				}
+
+				if let Some(compact_iri) = &v_attrs.iri {
+					let iri = compact_iri.expand(&attrs.prefixes)?.into_string();
+					context.term(&name, quote!(#iri));
+				}
+
+				context.fields(&attrs, variant.fields)?
+			}
+		}
+		syn::Data::Union(u) => return Err(Error::UnionType(u.union_token.span())),
+	}
+
+	Ok(context.into_impl(input.ident, input.generics))
+}
+
+#[derive(Default)]
+struct Context {
+	bounds: Vec<syn::WherePredicate>,
+	body: TokenStream,
+}
+
+impl Context {
+	fn term(&mut self, name: &str, definition: TokenStream) {
+		self.body.extend(quote! {
+			context_.insert(#name.into(), #definition.into());
+		})
+	}
+
+	fn fields(&mut self, attrs: &TypeAttributes, fields: syn::Fields) -> Result<(), Error> {
+		let mut usage = FieldsUsage::default();
+		for field in fields {
+			let span = field.span();
+			let field_attrs = read_field_attributes_in(attrs, field.ident.as_ref(), field.attrs)?;
+			usage.check(&attrs.prefixes, &field_attrs, span)?;
+			if field_attrs.ignore {
+				continue;
+			}
+
+			let ty = field.ty;
+
+			if field_attrs.flatten {
+				self.bounds.push(
+					syn::parse2(quote! {
+						#ty: ::linked_data_next::JsonLdContext
+					})
+					.unwrap(),
+				);
+
+				self.body.extend(quote! {
+					if let ::linked_data_next::json_syntax::Value::Object(flattened_) =
+						<#ty as ::linked_data_next::JsonLdContext>::json_ld_context()
+					{
+						for entry_ in flattened_ {
+							context_.insert(entry_.key, entry_.value);
+						}
+					}
+				});
+
+				continue;
+			}
+
+			let Some(name) = field.ident.map(|id| id.unraw().to_string()) else {
+				continue;
+			};
+
+			if field_attrs.is_id {
+				self.term(&name, quote!("@id"));
+				continue;
+			}
+
+			if field_attrs.include {
+				self.term(&name, quote!("@included"));
+				continue;
+			}
+
+			let Some(compact_iri) = &field_attrs.iri else {
+				continue;
+			};
+
+			let iri = compact_iri.expand(&attrs.prefixes)?.into_string();
+
+			if field_attrs.reverse {
+				// Reverse properties only accept the `@set` container.
+				let container = field_container(&field_attrs, &ty)
+					.filter(|c| *c == "@set")
+					.map(|c| quote!(("@container".into(), #c.into()),));
+
+				self.term(
+					&name,
+					quote! {
+						::linked_data_next::json_syntax::Value::Object(
+							[
+								("@reverse".into(), #iri.into()),
+								#container
+							]
+							.into_iter()
+							.collect()
+						)
+					},
+				);
+				continue;
+			}
+
+			if let Some(datatype) = &field_attrs.datatype {
+				let datatype = datatype.expand(&attrs.prefixes)?.into_string();
+				self.term(
+					&name,
+					quote! {
+						::linked_data_next::json_syntax::Value::Object(
+							[
+								("@id".into(), #iri.into()),
+								("@type".into(), #datatype.into()),
+							]
+							.into_iter()
+							.collect()
+						)
+					},
+				);
+				continue;
+			}
+
+			match field_container(&field_attrs, &ty) {
+				Some(container) => self.term(
+					&name,
+					quote! {
+						::linked_data_next::json_syntax::Value::Object(
+							[
+								("@id".into(), #iri.into()),
+								("@container".into(), #container.into()),
+							]
+							.into_iter()
+							.collect()
+						)
+					},
+				),
+				None => self.term(&name, quote!(#iri)),
+			}
+		}
+
+		Ok(())
+	}
+
+	fn into_impl(self, ident: Ident, generics: syn::Generics) -> TokenStream {
+		let mut ctx_generics = generics.clone();
+		ctx_generics
+			.make_where_clause()
+			.predicates
+			.extend(self.bounds);
+
+		let (_, ty_generics, _) = generics.split_for_impl();
+		let (impl_generics, _, where_clause) = ctx_generics.split_for_impl();
+		let body = self.body;
+
+		quote! {
+			impl #impl_generics ::linked_data_next::JsonLdContext for #ident #ty_generics #where_clause {
+				fn json_ld_context() -> ::linked_data_next::json_syntax::Value {
+					let mut context_ = ::linked_data_next::json_syntax::Object::new();
+					#body
+					::linked_data_next::json_syntax::Value::Object(context_)
+				}
+			}
+		}
+	}
+}
+
+/// Returns the JSON-LD container of the given field, if any.
+///
+/// The `graph`, `set` and `list` attributes take precedence. Fields with a
+/// custom serialization have no container unless one of these is given.
+/// Otherwise the container is guessed with [`container`].
+fn field_container(attrs: &FieldAttributes, ty: &syn::Type) -> Option<&'static str> {
+	if attrs.graph_value {
+		Some("@graph")
+	} else if attrs.set {
+		Some("@set")
+	} else if attrs.list {
+		Some("@list")
+	} else if attrs.serialize_with.is_some() {
+		None
+	} else {
+		container(ty)
+	}
+}
+
+/// Returns the JSON-LD container matching the way the given field type is
+/// serialized, if any.
+///
+/// Like [`Multiplicity`](super::Multiplicity), this only looks at the syntax
+/// of the type: the container is picked from the name of the last path
+/// segment, looking through `Option`, `Box`, `Rc`, `Arc` and references.
+/// Type aliases, and user types named after a standard collection, are not
+/// resolved.
+fn container(ty: &syn::Type) -> Option<&'static str> {
+	match ty {
+		syn::Type::Path(path) => {
+			let segment = path.path.segments.last()?;
+			match segment.ident.to_string().as_str() {
+				"Vec" | "VecDeque" => Some("@list"),
+				"HashSet" | "BTreeSet" => Some("@set"),
+				"LangMap" => Some("@language"),
+				"Option" | "Box" | "Rc" | "Arc" => match &segment.arguments {
+					syn::PathArguments::AngleBracketed(args) => match args.args.first()? {
+						syn::GenericArgument::Type(ty) => container(ty),
+						_ => None,
+					},
+					_ => None,
+				},
+				_ => None,
+			}
+		}
+		syn::Type::Reference(r) => container(&r.elem),
+		syn::Type::Slice(_) => Some("@set"),
+		_ => None,
+	}
+}
@@ -1,26 +1,104 @@
 use std::collections::HashMap;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 
 use quote::{format_ident, quote};
 use syn::{DeriveInput, spanned::Spanned};
 
 use super::{
-	Error, InterpretationBounds, TypeAttributes, read_field_attributes, read_type_attributes,
+	AttributeError, Error, FieldAttributes, FieldDefault, FieldsUsage, InterpretationBounds,
+	Multiplicity, RDF_TYPE, TypeAttributes, read_field_attributes_in, read_type_attributes,
 };
 
+mod dataset;
 mod r#enum;
 mod r#struct;
 
 pub fn subject(input: DeriveInput) -> Result<TokenStream, Error> {
 	let attrs = read_type_attributes(input.attrs)?;
 	match input.data {
+		syn::Data::Struct(s) if attrs.dataset => {
+			dataset::generate(&attrs, input.ident, input.generics, s)
+		}
 		syn::Data::Struct(s) => r#struct::generate(&attrs, input.ident, input.generics, s),
+		syn::Data::Enum(e) if attrs.dataset => Err(Error::InvalidDatasetType(e.enum_token.span())),
 		syn::Data::Enum(e) => r#enum::generate(&attrs, input.ident, input.generics, e),
 		syn::Data::Union(u) => Err(Error::UnionType(u.union_token.span())),
 	}
 }
 
+/// Generates an expression collecting the IRIs of the `rdf:type` values of
+/// `resource_` into a `Vec<IriBuf>`.
+fn resource_types(interpretation_bounds: &mut InterpretationBounds) -> TokenStream {
+	let rdf_type = RDF_TYPE.as_str();
+	interpretation_bounds.iri_mut = true;
+	interpretation_bounds.reverse_iri = true;
+
+	quote! {
+		{
+			let mut types_ = Vec::new();
+
+			if let Some(predicate_) = vocabulary_.get(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#rdf_type) }).and_then(|iri| interpretation_.iri_interpretation(&iri)) {
+				for ty_ in ::linked_data_next::rdf_types::dataset::PatternMatchingDataset::quad_objects(dataset_, graph_, resource_, &predicate_) {
+					for i in interpretation_.iris_of(ty_) {
+						types_.push(vocabulary_.iri(i).unwrap().to_owned());
+					}
+				}
+			}
+
+			types_
+		}
+	}
+}
+
+/// Generates a statement returning a `TypeMismatch` error listing the
+/// `expected` types and the types `found_` on the resource.
+fn type_mismatch(expected: &[String]) -> TokenStream {
+	let rdf_type = RDF_TYPE.as_str();
+
+	quote! {
+		return Err(::linked_data_next::FromLinkedDataError::TypeMismatch {
+			context: context_.with_predicate_iri(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#rdf_type) }).into_iris(vocabulary_, interpretation_),
+			expected: vec![#(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#expected) }.to_owned()),*],
+			found: found_
+		})
+	}
+}
+
+/// Generates the `LinkedDataDeserializeGraph` implementation of a type
+/// deserialized from the root subject of the graph.
+fn deserialize_graph(
+	ident: &syn::Ident,
+	generics: &syn::Generics,
+	ld_generics: &syn::Generics,
+) -> TokenStream {
+	let mut graph_generics = ld_generics.clone();
+	graph_generics
+		.make_where_clause()
+		.predicates
+		.push(syn::parse2(quote!(I_::Resource: Ord)).unwrap());
+
+	let (_, ty_generics, _) = generics.split_for_impl();
+	let (impl_generics, _, where_clause) = graph_generics.split_for_impl();
+
+	quote! {
+		impl #impl_generics ::linked_data_next::LinkedDataDeserializeGraph<I_, V_> for #ident #ty_generics #where_clause {
+			fn deserialize_graph_in<D_>(
+				vocabulary: &V_,
+				interpretation: &I_,
+				dataset: &D_,
+				graph: Option<&I_::Resource>,
+				context: ::linked_data_next::Context<I_>
+			) -> Result<Self, ::linked_data_next::FromLinkedDataError>
+			where
+				D_: ::linked_data_next::rdf_types::dataset::PatternMatchingDataset<Resource = I_::Resource>
+			{
+				::linked_data_next::deserialize_root_subject_in(vocabulary, interpretation, dataset, graph, context)
+			}
+		}
+	}
+}
+
 struct FieldsDeserialization {
 	deserialize_fields: Vec<TokenStream>,
 	constructor: TokenStream,
@@ -34,6 +112,7 @@ fn generate_fields(
 ) -> Result<FieldsDeserialization, Error> {
 	let mut interpretation_bounds = InterpretationBounds::default();
 	let mut bounds = Vec::new();
+	let mut usage = FieldsUsage::default();
 	let mut deserialize_fields = Vec::with_capacity(fields.len());
 	let constructor = match fields {
 		syn::Fields::Unit => quote!(),
@@ -42,9 +121,9 @@ fn generate_fields(
 			for (i, f) in fields.unnamed.into_iter().enumerate() {
 				let ident = format_ident!("a{i}");
 				match generate_field(
-					&attrs.prefixes,
-					f.attrs,
-					&f.ty,
+					attrs,
+					&mut usage,
+					&f,
 					&mut interpretation_bounds,
 					&mut bounds,
 				)? {
@@ -68,15 +147,16 @@ fn generate_fields(
 		syn::Fields::Named(fields) => {
 			let mut fields_constructors = Vec::with_capacity(fields.named.len());
 			for f in fields.named {
-				let ident = f.ident;
-
-				match generate_field(
-					&attrs.prefixes,
-					f.attrs,
-					&f.ty,
+				let deserialize_field = generate_field(
+					attrs,
+					&mut usage,
+					&f,
 					&mut interpretation_bounds,
 					&mut bounds,
-				)? {
+				)?;
+				let ident = f.ident;
+
+				match deserialize_field {
 					Some(deserialize_field) => {
 						deserialize_fields.push(quote! {
 							let #ident = #deserialize_field ;
@@ -105,49 +185,191 @@ fn generate_fields(
 }
 
 fn generate_field(
+	type_attrs: &TypeAttributes,
+	usage: &mut FieldsUsage,
+	field: &syn::Field,
+	interpretation_bounds: &mut InterpretationBounds,
+	bounds: &mut Vec<syn::WherePredicate>,
+) -> Result<Option<TokenStream>, Error> {
+	let prefixes = &type_attrs.prefixes;
+	let span = field.span();
+	let ty = &field.ty;
+	let mut attrs =
+		read_field_attributes_in(type_attrs, field.ident.as_ref(), field.attrs.clone())?;
+	usage.check(prefixes, &attrs, span)?;
+
+	// Fields with a default value always have a predicate.
+	let (Some((default, _)), Some(compact_iri)) = (attrs.default.take(), &attrs.iri) else {
+		return deserialize_field(prefixes, attrs, span, ty, interpretation_bounds, bounds);
+	};
+
+	let default = match default {
+		FieldDefault::Default => {
+			bounds.push(syn::parse2(quote!(#ty: ::std::default::Default)).unwrap());
+			quote!(<#ty as ::std::default::Default>::default())
+		}
+		FieldDefault::Function(path) => quote!(#path()),
+	};
+
+	let iri = compact_iri.expand(prefixes)?.into_string();
+	let objects = predicate_objects(attrs.reverse);
+	let deserialize = deserialize_field(prefixes, attrs, span, ty, interpretation_bounds, bounds)?;
+
+	// The default value is only used when the predicate has no object.
+	Ok(Some(quote! {
+		{
+			let has_objects_ = match vocabulary_.get(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#iri) }).and_then(|iri| interpretation_.iri_interpretation(&iri)) {
+				Some(predicate_) => (#objects).next().is_some(),
+				None => false
+			};
+
+			if has_objects_ {
+				#deserialize
+			} else {
+				#default
+			}
+		}
+	}))
+}
+
+fn deserialize_field(
 	prefixes: &HashMap<String, String>,
-	attrs: Vec<syn::Attribute>,
+	attrs: FieldAttributes,
+	span: Span,
 	ty: &syn::Type,
 	interpretation_bounds: &mut InterpretationBounds,
 	bounds: &mut Vec<syn::WherePredicate>,
 ) -> Result<Option<TokenStream>, Error> {
-	let attrs = read_field_attributes(attrs)?;
+	if attrs.include {
+		let type_ = match &attrs.include_type {
+			Some(compact_iri) => compact_iri.expand(prefixes)?.into_string(),
+			None => return Err(Error::UntypedInclude(span)),
+		};
+
+		return Ok(Some(included_field(
+			ty,
+			&type_,
+			interpretation_bounds,
+			bounds,
+		)));
+	}
 
 	match attrs.iri {
 		Some(compact_iri) => {
 			let iri = compact_iri.expand(prefixes)?.into_string();
 			interpretation_bounds.iri_mut = true;
 
-			bounds.push(
-				syn::parse2(
-					quote!(#ty: ::linked_data_next::LinkedDataDeserializePredicateObjects<I_, V_>),
-				)
-				.unwrap(),
-			);
+			if attrs.graph_value {
+				interpretation_bounds.reverse_iri = true;
+
+				bounds.push(
+					syn::parse2(
+						quote!(#ty: ::linked_data_next::LinkedDataDeserializeGraph<I_, V_>),
+					)
+					.unwrap(),
+				);
+
+				return Ok(Some(quote! {
+					match vocabulary_.get(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#iri) }).and_then(|iri| interpretation_.iri_interpretation(&iri)) {
+						Some(predicate_) => {
+							let context_ = context_.with_predicate(&predicate_);
+							let mut objects_ = ::linked_data_next::rdf_types::dataset::PatternMatchingDataset::quad_objects(dataset_, graph_, resource_, &predicate_);
+							match (objects_.next(), objects_.next()) {
+								(Some(graph_name_), None) => {
+									<#ty as ::linked_data_next::LinkedDataDeserializeGraph<I_, V_>>::deserialize_graph_in(
+										vocabulary_,
+										interpretation_,
+										dataset_,
+										Some(graph_name_),
+										context_
+									)?
+								}
+								(None, _) => return Err(::linked_data_next::FromLinkedDataError::MissingRequiredValue(
+									context_.into_iris(vocabulary_, interpretation_)
+								)),
+								_ => return Err(::linked_data_next::FromLinkedDataError::TooManyValues(
+									context_.into_iris(vocabulary_, interpretation_)
+								))
+							}
+						}
+						None => return Err(::linked_data_next::FromLinkedDataError::MissingRequiredValue(
+							context_.with_predicate_iri(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#iri) }).into_iris(vocabulary_, interpretation_)
+						))
+					}
+				}));
+			}
+
+			if let Some(ranges) = attrs.lang {
+				return language_field(&iri, ty, ranges, interpretation_bounds, bounds).map(Some);
+			}
+
+			if let Some(datatype) = attrs.datatype {
+				let datatype = datatype.expand(prefixes)?.into_string();
+				return Ok(Some(datatype_field(
+					&iri,
+					&datatype,
+					ty,
+					interpretation_bounds,
+					bounds,
+				)));
+			}
+
+			let (deserialize, unwrap) = match attrs.deserialize_with {
+				Some(path) => {
+					literal_bounds(interpretation_bounds, bounds);
+					(quote!(#path), quote!())
+				}
+				None => {
+					// Container wrapper choosing how the objects are read.
+					let (deserialize_ty, unwrap) = if attrs.lenient {
+						(quote!(::linked_data_next::AsSetOrList<#ty>), quote!(.0))
+					} else if attrs.set {
+						(quote!(::linked_data_next::AsSet<#ty>), quote!(.0))
+					} else if attrs.list {
+						(quote!(::linked_data_next::AsList<#ty>), quote!(.0))
+					} else {
+						(quote!(#ty), quote!())
+					};
+
+					bounds.push(
+						syn::parse2(quote!(
+							#deserialize_ty: ::linked_data_next::LinkedDataDeserializePredicateObjects<I_, V_>
+						))
+						.unwrap(),
+					);
+
+					(
+						quote!(<#deserialize_ty as ::linked_data_next::LinkedDataDeserializePredicateObjects<I_, V_>>::deserialize_objects_in),
+						unwrap,
+					)
+				}
+			};
+
+			let objects = predicate_objects(attrs.reverse);
 
 			Ok(Some(quote! {
 				match vocabulary_.get(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#iri) }).and_then(|iri| interpretation_.iri_interpretation(&iri)) {
 					Some(predicate_) => {
 						let context_ = context_.with_predicate(&predicate_);
-						::linked_data_next::LinkedDataDeserializePredicateObjects::deserialize_objects_in(
+						#deserialize(
 							vocabulary_,
 							interpretation_,
 							dataset_,
 							graph_,
-							::linked_data_next::rdf_types::dataset::PatternMatchingDataset::quad_objects(dataset_, graph_, resource_, &predicate_),
+							#objects,
 							context_
-						)?
+						)?#unwrap
 					}
 					None => {
 						let context_ = context_.with_predicate_iri(unsafe {::linked_data_next::iref::Iri::new_unchecked(#iri) });
-						::linked_data_next::LinkedDataDeserializePredicateObjects::deserialize_objects_in(
+						#deserialize(
 							vocabulary_,
 							interpretation_,
 							dataset_,
 							graph_,
 							[],
 							context_
-						)?
+						)?#unwrap
 					}
 				}
 			}))
@@ -174,8 +396,237 @@ fn generate_field(
 			} else if attrs.ignore {
 				Ok(None)
 			} else {
-				panic!("Missing #[ld(...)] attribute")
+				Err(Error::UnknownFieldSerializationMethod(span))
 			}
 		}
 	}
 }
+
+/// Iterator over the objects of `predicate_`, or over the subjects pointing
+/// to the current resource through `predicate_` if `reverse` is set.
+fn predicate_objects(reverse: bool) -> TokenStream {
+	if reverse {
+		// Subjects of the quads whose object is the current resource.
+		quote! {
+			::linked_data_next::rdf_types::dataset::PatternMatchingDataset::quad_pattern_matching(
+				dataset_,
+				::linked_data_next::rdf_types::pattern::CanonicalQuadPattern::from_option_quad(
+					::linked_data_next::rdf_types::Quad(None, Some(&predicate_), Some(resource_), Some(graph_))
+				)
+			).map(|quad_| quad_.0)
+		}
+	} else {
+		quote! {
+			::linked_data_next::rdf_types::dataset::PatternMatchingDataset::quad_objects(dataset_, graph_, resource_, &predicate_)
+		}
+	}
+}
+
+/// Adds the bounds required to read the literals of the dataset.
+fn literal_bounds(
+	interpretation_bounds: &mut InterpretationBounds,
+	bounds: &mut Vec<syn::WherePredicate>,
+) {
+	interpretation_bounds.reverse_iri = true;
+	bounds.push(
+		syn::parse2(quote!(V_: ::linked_data_next::rdf_types::vocabulary::LiteralVocabulary))
+			.unwrap(),
+	);
+	bounds.push(
+		syn::parse2(quote!(
+			I_: ::linked_data_next::rdf_types::interpretation::ReverseLiteralInterpretation<Literal = V_::Literal>
+		))
+		.unwrap(),
+	);
+}
+
+/// Generates an expression deserializing an `#[ld(lang(...))]` field, picking
+/// the string in the preferred language among the predicate objects.
+fn language_field(
+	iri: &str,
+	ty: &syn::Type,
+	ranges: Vec<syn::LitStr>,
+	interpretation_bounds: &mut InterpretationBounds,
+	bounds: &mut Vec<syn::WherePredicate>,
+) -> Result<TokenStream, Error> {
+	literal_bounds(interpretation_bounds, bounds);
+
+	let select = match Multiplicity::of(ty) {
+		Multiplicity::One => quote! {
+			deserialize_objects_in(vocabulary_, interpretation_, objects_, context_)?
+		},
+		Multiplicity::Optional(_) => quote! {
+			select(vocabulary_, interpretation_, objects_)
+		},
+		Multiplicity::Many(_) => {
+			return Err(Error::InvalidAttribute(
+				AttributeError::InvalidLanguageField,
+				ty.span(),
+			));
+		}
+	};
+
+	Ok(quote! {
+		{
+			let languages_ = ::linked_data_next::LanguagePreference::new(&[#(#ranges),*]);
+			match vocabulary_.get(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#iri) }).and_then(|iri| interpretation_.iri_interpretation(&iri)) {
+				Some(predicate_) => {
+					let context_ = context_.with_predicate(&predicate_);
+					let objects_ = ::linked_data_next::rdf_types::dataset::PatternMatchingDataset::quad_objects(dataset_, graph_, resource_, &predicate_);
+					languages_.#select
+				}
+				None => {
+					let context_ = context_.with_predicate_iri(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#iri) });
+					let objects_ = [];
+					languages_.#select
+				}
+			}
+		}
+	})
+}
+
+/// Generates an expression deserializing an `#[ld(datatype = "...")]` field,
+/// parsing the literals of the given datatype with `FromStr`.
+fn datatype_field(
+	iri: &str,
+	datatype: &str,
+	ty: &syn::Type,
+	interpretation_bounds: &mut InterpretationBounds,
+	bounds: &mut Vec<syn::WherePredicate>,
+) -> TokenStream {
+	literal_bounds(interpretation_bounds, bounds);
+
+	let (item, read) = match Multiplicity::of(ty) {
+		Multiplicity::One => (ty, quote!(read_one)),
+		Multiplicity::Optional(item) => (item, quote!(read_optional)),
+		Multiplicity::Many(item) => (item, quote!(read_all::<_, _, #item, _>)),
+	};
+
+	bounds.push(syn::parse2(quote!(#item: ::std::str::FromStr)).unwrap());
+
+	quote! {
+		{
+			let reader_ = ::linked_data_next::TypedLiteralReader::new(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#datatype) });
+			match vocabulary_.get(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#iri) }).and_then(|iri| interpretation_.iri_interpretation(&iri)) {
+				Some(predicate_) => {
+					let context_ = context_.with_predicate(&predicate_);
+					let objects_ = ::linked_data_next::rdf_types::dataset::PatternMatchingDataset::quad_objects(dataset_, graph_, resource_, &predicate_);
+					reader_.#read(vocabulary_, interpretation_, objects_, context_)?
+				}
+				None => {
+					let context_ = context_.with_predicate_iri(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#iri) });
+					reader_.#read(vocabulary_, interpretation_, [], context_)?
+				}
+			}
+		}
+	}
+}
+
+/// Generates an expression deserializing an `#[ld(include)]` field.
+///
+/// Included nodes are not linked to the current resource, so they are found
+/// by their `rdf:type` among the subjects of the graph that cannot be reached
+/// from it. Sibling instances of the same type therefore all receive the
+/// included nodes of the graph.
+fn included_field(
+	ty: &syn::Type,
+	type_: &str,
+	interpretation_bounds: &mut InterpretationBounds,
+	bounds: &mut Vec<syn::WherePredicate>,
+) -> TokenStream {
+	interpretation_bounds.reverse_iri = true;
+	interpretation_bounds.iri_mut = true;
+	bounds.push(syn::parse2(quote!(I_::Resource: Ord)).unwrap());
+
+	let multiplicity = Multiplicity::of(ty);
+	let item = match multiplicity {
+		Multiplicity::One => ty,
+		Multiplicity::Optional(item) | Multiplicity::Many(item) => item,
+	};
+
+	bounds.push(
+		syn::parse2(quote!(#item: ::linked_data_next::LinkedDataDeserializeSubject<I_, V_>))
+			.unwrap(),
+	);
+
+	let rdf_type = RDF_TYPE.as_str();
+	let candidates = quote! {
+		let rdf_type_ = vocabulary_.get(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#rdf_type) }).and_then(|iri| interpretation_.iri_interpretation(&iri));
+		let type_ = vocabulary_.get(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#type_) }).and_then(|iri| interpretation_.iri_interpretation(&iri));
+		if let (Some(rdf_type_), Some(type_)) = (rdf_type_, type_) {
+			for quad_ in ::linked_data_next::rdf_types::dataset::PatternMatchingDataset::quad_pattern_matching(
+				dataset_,
+				::linked_data_next::rdf_types::pattern::CanonicalQuadPattern::from_option_quad(
+					::linked_data_next::rdf_types::Quad(None, Some(&rdf_type_), Some(&type_), Some(graph_))
+				)
+			) {
+				if !reachable_.contains(quad_.0) {
+					subjects_.insert(quad_.0);
+				}
+			}
+		}
+	};
+
+	let items = quote! {
+		{
+			// Nodes reachable from the current resource are its values.
+			let mut reachable_ = ::std::collections::BTreeSet::new();
+			let mut stack_ = vec![resource_];
+			while let Some(node_) = stack_.pop() {
+				if reachable_.insert(node_) {
+					for quad_ in ::linked_data_next::rdf_types::dataset::PatternMatchingDataset::quad_pattern_matching(
+						dataset_,
+						::linked_data_next::rdf_types::pattern::CanonicalQuadPattern::from_option_quad(
+							::linked_data_next::rdf_types::Quad(Some(node_), None, None, Some(graph_))
+						)
+					) {
+						stack_.push(quad_.2);
+					}
+				}
+			}
+
+			let mut subjects_ = ::std::collections::BTreeSet::new();
+			#candidates
+
+			subjects_.into_iter().map(|subject_| {
+				<#item as ::linked_data_next::LinkedDataDeserializeSubject<I_, V_>>::deserialize_subject_in(
+					vocabulary_,
+					interpretation_,
+					dataset_,
+					graph_,
+					subject_,
+					context_
+				)
+			})
+		}
+	};
+
+	match multiplicity {
+		Multiplicity::Many(_) => quote!(#items.collect::<Result<#ty, _>>()?),
+		Multiplicity::Optional(_) => quote! {
+			{
+				let mut items_ = #items;
+				match (items_.next(), items_.next()) {
+					(item_, None) => item_.transpose()?,
+					_ => return Err(::linked_data_next::FromLinkedDataError::TooManyValues(
+						context_.with_anonymous_predicate().into_iris(vocabulary_, interpretation_)
+					))
+				}
+			}
+		},
+		Multiplicity::One => quote! {
+			{
+				let mut items_ = #items;
+				match (items_.next(), items_.next()) {
+					(Some(item_), None) => item_?,
+					(None, _) => return Err(::linked_data_next::FromLinkedDataError::MissingRequiredValue(
+						context_.with_anonymous_predicate().into_iris(vocabulary_, interpretation_)
+					)),
+					_ => return Err(::linked_data_next::FromLinkedDataError::TooManyValues(
+						context_.with_anonymous_predicate().into_iris(vocabulary_, interpretation_)
+					))
+				}
+			}
+		},
+	}
+}
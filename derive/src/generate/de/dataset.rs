@@ -0,0 +1,123 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+
+use crate::generate::{
+	FieldsUsage, InterpretationBounds, TypeAttributes, VocabularyBounds, extend_generics,
+	read_field_attributes,
+};
+
+use super::Error;
+
+pub fn generate(
+	attrs: &TypeAttributes,
+	ident: Ident,
+	generics: syn::Generics,
+	s: syn::DataStruct,
+) -> Result<TokenStream, Error> {
+	let mut bounds: Vec<syn::WherePredicate> = Vec::new();
+	let interpretation_bounds = InterpretationBounds {
+		iri_mut: true,
+		reverse_iri: true,
+	};
+
+	let mut deserialize_fields = Vec::new();
+	let mut fields_constructors = Vec::new();
+	let shape = match &s.fields {
+		syn::Fields::Named(_) => Some(true),
+		syn::Fields::Unnamed(_) => Some(false),
+		syn::Fields::Unit => None,
+	};
+
+	let mut usage = FieldsUsage::default();
+	for (i, field) in s.fields.into_iter().enumerate() {
+		let span = field.span();
+		let field_attrs = read_field_attributes(field.attrs)?;
+		field_attrs.check_dataset_field(span)?;
+		usage.check(&attrs.prefixes, &field_attrs, span)?;
+		let ty = field.ty;
+		let var = format_ident!("a{i}");
+
+		let constructor = if field_attrs.ignore {
+			quote!(<#ty as ::std::default::Default>::default())
+		} else {
+			bounds.push(
+				syn::parse2(quote! {
+					#ty: ::linked_data_next::LinkedDataDeserializeGraph<I_, V_>
+				})
+				.unwrap(),
+			);
+
+			let deserialize_field = if field_attrs.default_graph {
+				quote! {
+					<#ty as ::linked_data_next::LinkedDataDeserializeGraph<I_, V_>>::deserialize_graph_in(
+						vocabulary_,
+						interpretation_,
+						dataset_,
+						None,
+						context_
+					)?
+				}
+			} else if let Some(compact_iri) = field_attrs.named_graph {
+				let iri = compact_iri.expand(&attrs.prefixes)?.into_string();
+
+				quote! {
+					match vocabulary_.get(unsafe { ::linked_data_next::iref::Iri::new_unchecked(#iri) }).and_then(|iri| interpretation_.iri_interpretation(&iri)) {
+						Some(graph_) => <#ty as ::linked_data_next::LinkedDataDeserializeGraph<I_, V_>>::deserialize_graph_in(
+							vocabulary_,
+							interpretation_,
+							dataset_,
+							Some(&graph_),
+							context_
+						)?,
+						None => return Err(::linked_data_next::FromLinkedDataError::EmptyGraph(
+							context_.into_iris(vocabulary_, interpretation_)
+						))
+					}
+				}
+			} else {
+				return Err(Error::MissingFieldGraph(span));
+			};
+
+			deserialize_fields.push(quote! {
+				let #var = #deserialize_field;
+			});
+
+			quote!(#var)
+		};
+
+		fields_constructors.push(match field.ident {
+			Some(id) => quote!(#id: #constructor),
+			None => constructor,
+		});
+	}
+
+	let constructor = match shape {
+		Some(true) => quote!({ #(#fields_constructors),* }),
+		Some(false) => quote!(( #(#fields_constructors),* )),
+		None => quote!(),
+	};
+
+	let ld_generics = extend_generics(
+		&generics,
+		VocabularyBounds::default(),
+		interpretation_bounds,
+		bounds,
+	);
+	let (_, ty_generics, _) = generics.split_for_impl();
+	let (impl_generics, _, where_clause) = ld_generics.split_for_impl();
+
+	Ok(quote! {
+		impl #impl_generics ::linked_data_next::LinkedDataDeserialize<V_, I_> for #ident #ty_generics #where_clause {
+			fn deserialize_dataset_in(
+				vocabulary_: &V_,
+				interpretation_: &I_,
+				dataset_: &(impl ::linked_data_next::rdf_types::dataset::TraversableDataset<Resource = I_::Resource> + ::linked_data_next::rdf_types::dataset::PatternMatchingDataset),
+				context_: ::linked_data_next::Context<I_>
+			) -> Result<Self, ::linked_data_next::FromLinkedDataError> {
+				#(#deserialize_fields)*
+				Ok(Self #constructor)
+			}
+		}
+	})
+}
@@ -1,7 +1,7 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use super::{Error, generate_fields};
+use super::{Error, deserialize_graph, generate_fields, resource_types, type_mismatch};
 use crate::generate::{
 	InterpretationBounds, TypeAttributes, VocabularyBounds, extend_generics,
 	read_variant_attributes,
@@ -20,11 +20,66 @@ pub fn generate(
 
 	let mut bounds = Vec::new();
 
+	let mut classes = Vec::new();
+	let mut dispatch_variants = Vec::new();
 	let mut deserialize_variants = Vec::with_capacity(e.variants.len());
 	for v in e.variants {
 		let v_ident = &v.ident;
 		let v_attrs = read_variant_attributes(v.attrs)?;
 
+		if let Some(class) = v_attrs.class {
+			let class = class.expand(&attrs.prefixes)?.into_string();
+
+			let deserialize_variant = match variant_shape(&v.fields) {
+				VariantShape::Simple(ty) => {
+					bounds.push(
+						syn::parse2(
+							quote!(#ty: ::linked_data_next::LinkedDataDeserializeSubject<I_, V_>),
+						)
+						.unwrap(),
+					);
+
+					quote! {
+						::linked_data_next::LinkedDataDeserializeSubject::deserialize_subject_in(
+							vocabulary_,
+							interpretation_,
+							dataset_,
+							graph_,
+							resource_,
+							context_
+						).map(Self::#v_ident)
+					}
+				}
+				VariantShape::Compound => {
+					let variant_de = generate_fields(attrs, v.fields)?;
+					interpretation_bounds.add(variant_de.interpretation_bounds);
+					bounds.extend(variant_de.bounds);
+
+					let deserialize_fields = variant_de.deserialize_fields;
+					let constructor = variant_de.constructor;
+
+					quote! {
+						(|| {
+							#(#deserialize_fields)*
+							Ok(Self::#v_ident #constructor)
+						})()
+					}
+				}
+				VariantShape::Unit => quote! {
+					Ok(Self::#v_ident)
+				},
+			};
+
+			dispatch_variants.push(quote! {
+				if found_.iter().any(|ty_| ty_.as_str() == #class) {
+					return #deserialize_variant
+				}
+			});
+
+			classes.push(class);
+			continue;
+		}
+
 		let deserialize_variant = match v_attrs.iri {
 			Some(compact_iri) => {
 				let iri = compact_iri.expand(&attrs.prefixes)?.into_string();
@@ -178,12 +233,36 @@ pub fn generate(
 		deserialize_variants.push(deserialize_variant)
 	}
 
+	let dispatch = if classes.is_empty() {
+		quote!()
+	} else {
+		let types = resource_types(&mut interpretation_bounds);
+		quote! {
+			let found_ = #types;
+			#(#dispatch_variants)*
+		}
+	};
+
+	let no_match = if classes.is_empty() {
+		quote!()
+	} else {
+		let mismatch = type_mismatch(&classes);
+		quote! {
+			if error.is_none() {
+				#mismatch
+			}
+		}
+	};
+
 	let vocabulary_bounds = VocabularyBounds::default();
 	let ld_generics = extend_generics(&generics, vocabulary_bounds, interpretation_bounds, bounds);
 	let (_, ty_generics, _) = generics.split_for_impl();
 	let (impl_generics, _, where_clause) = ld_generics.split_for_impl();
+	let deserialize_graph = deserialize_graph(&ident, &generics, &ld_generics);
 
 	Ok(quote! {
+		#deserialize_graph
+
 		impl #impl_generics ::linked_data_next::LinkedDataDeserializeSubject<I_, V_> for #ident #ty_generics #where_clause {
 			fn deserialize_subject_in<D_>(
 				vocabulary_: &V_,
@@ -199,7 +278,9 @@ pub fn generate(
 				let context_ = outer_context_.with_subject(resource_);
 				let mut error = None;
 
+				#dispatch
 				#(#deserialize_variants)*
+				#no_match
 
 				Err(error.unwrap_or_else(|| {
 					::linked_data_next::FromLinkedDataError::InvalidSubject {
@@ -3,7 +3,9 @@ use quote::quote;
 
 use crate::generate::{TypeAttributes, VocabularyBounds, extend_generics};
 
-use super::{Error, FieldsDeserialization, generate_fields};
+use super::{
+	Error, FieldsDeserialization, deserialize_graph, generate_fields, resource_types, type_mismatch,
+};
 
 pub fn generate(
 	attrs: &TypeAttributes,
@@ -20,12 +22,31 @@ pub fn generate(
 
 	interpretation_bounds.reverse_iri = true;
 
+	let check_type = match &attrs.type_ {
+		Some(ty) if !attrs.skip_type_check => {
+			let iri = ty.expand(&attrs.prefixes)?.into_string();
+			let types = resource_types(&mut interpretation_bounds);
+			let mismatch = type_mismatch(std::slice::from_ref(&iri));
+
+			quote! {
+				let found_ = #types;
+				if !found_.iter().any(|ty_| ty_.as_str() == #iri) {
+					#mismatch
+				}
+			}
+		}
+		_ => quote!(),
+	};
+
 	let vocabulary_bounds = VocabularyBounds::default();
 	let ld_generics = extend_generics(&generics, vocabulary_bounds, interpretation_bounds, bounds);
 	let (_, ty_generics, _) = generics.split_for_impl();
 	let (impl_generics, _, where_clause) = ld_generics.split_for_impl();
+	let deserialize_graph = deserialize_graph(&ident, &generics, &ld_generics);
 
 	Ok(quote! {
+		#deserialize_graph
+
 		impl #impl_generics ::linked_data_next::LinkedDataDeserializeSubject<I_, V_> for #ident #ty_generics #where_clause {
 			fn deserialize_subject_in<D_>(
 				vocabulary_: &V_,
@@ -39,6 +60,7 @@ pub fn generate(
 				D_: ::linked_data_next::rdf_types::dataset::PatternMatchingDataset<Resource = I_::Resource>
 			{
 				let context_ = context_.with_subject(resource_);
+				#check_type
 				#(#deserialize_fields)*
 				Ok(Self #constructor)
 			}
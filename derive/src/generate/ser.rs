@@ -2,17 +2,56 @@ use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use syn::{DeriveInput, spanned::Spanned};
 
-use super::{Error, TypeAttributes, VocabularyBounds, read_field_attributes, read_type_attributes};
+use super::{
+	Error, FieldsUsage, Multiplicity, TypeAttributes, VocabularyBounds, read_field_attributes_in,
+	read_type_attributes,
+};
 
+mod dataset;
 mod r#enum;
 mod r#struct;
 
 pub fn subject(input: DeriveInput) -> Result<TokenStream, Error> {
 	let attrs = read_type_attributes(input.attrs)?;
-	match input.data {
-		syn::Data::Struct(s) => r#struct::generate(&attrs, input.ident, input.generics, s),
-		syn::Data::Enum(e) => r#enum::generate(&attrs, input.ident, input.generics, e),
-		syn::Data::Union(u) => Err(Error::UnionType(u.union_token.span())),
+	let prefixes = prefixes(&attrs, &input.ident, &input.generics);
+	let visit = match input.data {
+		syn::Data::Struct(s) if attrs.dataset => {
+			dataset::generate(&attrs, input.ident, input.generics, s)?
+		}
+		syn::Data::Struct(s) => r#struct::generate(&attrs, input.ident, input.generics, s)?,
+		syn::Data::Enum(e) if attrs.dataset => {
+			return Err(Error::InvalidDatasetType(e.enum_token.span()));
+		}
+		syn::Data::Enum(e) => r#enum::generate(&attrs, input.ident, input.generics, e)?,
+		syn::Data::Union(u) => return Err(Error::UnionType(u.union_token.span())),
+	};
+
+	Ok(quote! {
+		#visit
+		#prefixes
+	})
+}
+
+/// Exposes the type prefix bindings through `LinkedDataPrefixes`.
+///
+/// Nothing is generated for types without prefix bindings.
+fn prefixes(attrs: &TypeAttributes, ident: &Ident, generics: &syn::Generics) -> TokenStream {
+	if attrs.prefixes.is_empty() {
+		return TokenStream::new();
+	}
+
+	let mut bindings: Vec<_> = attrs.prefixes.iter().collect();
+	bindings.sort();
+	let (prefixes, namespaces): (Vec<_>, Vec<_>) = bindings.into_iter().unzip();
+
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	quote! {
+		impl #impl_generics ::linked_data_next::LinkedDataPrefixes for #ident #ty_generics #where_clause {
+			const PREFIXES: &'static [(&'static str, &'static str)] = &[
+				#((#prefixes, #namespaces)),*
+			];
+		}
 	}
 }
 
@@ -31,6 +70,8 @@ pub struct CompoundFields {
 
 fn variant_compound_fields(
 	attrs: &TypeAttributes,
+	ident: &Ident,
+	generics: &syn::Generics,
 	fields: syn::Fields,
 	named_accessor: impl Fn(Ident) -> TokenStream,
 	unnamed_accessor: impl Fn(u32) -> TokenStream,
@@ -40,10 +81,12 @@ fn variant_compound_fields(
 	let mut visit = FieldsVisitor::default();
 
 	let mut visit_fields = Vec::new();
+	let mut usage = FieldsUsage::default();
 
 	for (i, field) in fields.into_iter().enumerate() {
 		let span = field.span();
-		let field_attrs = read_field_attributes(field.attrs)?;
+		let field_attrs = read_field_attributes_in(attrs, field.ident.as_ref(), field.attrs)?;
+		usage.check(&attrs.prefixes, &field_attrs, span)?;
 
 		if !field_attrs.ignore {
 			let field_access = match field.ident {
@@ -59,6 +102,7 @@ fn variant_compound_fields(
 			}
 
 			let field_ref = by_ref(field_access);
+			let skipped_ref = field_ref.clone();
 			let visit_field = if field_attrs.flatten {
 				visit.bounds.push(
 					syn::parse2(quote!(
@@ -70,6 +114,35 @@ fn variant_compound_fields(
 				quote! {
 					<#ty as ::linked_data_next::LinkedDataSubject<I_, V_>>::visit_subject(#field_ref, &mut visitor)?;
 				}
+			} else if field_attrs.include {
+				match Multiplicity::of(&ty) {
+					Multiplicity::One => {
+						visit.bounds.push(
+							syn::parse2(quote!(
+								#ty: ::linked_data_next::LinkedDataSubject<I_, V_> + ::linked_data_next::LinkedDataResource<I_, V_>
+							))
+							.unwrap(),
+						);
+
+						quote! {
+							visitor.include(#field_ref)?;
+						}
+					}
+					Multiplicity::Optional(item) | Multiplicity::Many(item) => {
+						visit.bounds.push(
+							syn::parse2(quote!(
+								#item: ::linked_data_next::LinkedDataSubject<I_, V_> + ::linked_data_next::LinkedDataResource<I_, V_>
+							))
+							.unwrap(),
+						);
+
+						quote! {
+							for item_ in (#field_ref).iter() {
+								visitor.include(item_)?;
+							}
+						}
+					}
+				}
 			} else {
 				match field_attrs.iri {
 					Some(compact_iri) => {
@@ -90,26 +163,109 @@ fn variant_compound_fields(
 									&Some(::linked_data_next::AnonymousGraph(#field_ref))
 								)?;
 							}
-						} else {
-							visit.bounds.push(
-								syn::parse2(quote!(
-									#ty: ::linked_data_next::LinkedDataPredicateObjects<I_, V_>
-								))
-								.unwrap(),
-							);
+						} else if let Some(datatype) = field_attrs.datatype {
+							let datatype = datatype.expand(&attrs.prefixes)?.into_string();
+							let (item, values) = match Multiplicity::of(&ty) {
+								Multiplicity::One => (&ty, quote!(::std::iter::once(#field_ref))),
+								Multiplicity::Optional(item) | Multiplicity::Many(item) => {
+									(item, quote!((#field_ref).iter()))
+								}
+							};
+
+							visit
+								.bounds
+								.push(syn::parse2(quote!(#item: ::std::fmt::Display)).unwrap());
 
 							quote! {
 								visitor.predicate(
 									::linked_data_next::iref::Iri::new(#iri).unwrap(),
-									#field_ref
+									&::linked_data_next::TypedLiterals::new(
+										#values,
+										::linked_data_next::iref::Iri::new(#datatype).unwrap()
+									)
 								)?;
 							}
+						} else {
+							let container = if field_attrs.set {
+								Some(quote!(::linked_data_next::AsSet))
+							} else if field_attrs.list {
+								Some(quote!(::linked_data_next::AsList))
+							} else {
+								None
+							};
+
+							let objects = match field_attrs.serialize_with {
+								Some(path) => {
+									visit.bounds.push(
+										syn::parse2(quote!(
+											V_: ::linked_data_next::rdf_types::vocabulary::LiteralVocabularyMut
+										))
+										.unwrap(),
+									);
+
+									serialize_with(ident, generics, &ty, &path, field_ref)
+								}
+								None => match container {
+									Some(container) => {
+										visit.bounds.push(
+											syn::parse2(quote!(
+												#ty: ::linked_data_next::Sequence
+											))
+											.unwrap(),
+										);
+
+										visit.bounds.push(
+										syn::parse2(quote!(
+											<#ty as ::linked_data_next::Sequence>::Item: ::linked_data_next::LinkedDataSubject<I_, V_> + ::linked_data_next::LinkedDataResource<I_, V_>
+										))
+										.unwrap(),
+									);
+
+										quote!(&#container(#field_ref))
+									}
+									None => {
+										visit.bounds.push(
+											syn::parse2(quote!(
+												#ty: ::linked_data_next::LinkedDataPredicateObjects<I_, V_>
+											))
+											.unwrap(),
+										);
+
+										quote!(#field_ref)
+									}
+								},
+							};
+
+							if field_attrs.reverse {
+								quote! {
+									visitor.reverse_predicate(
+										::linked_data_next::iref::Iri::new(#iri).unwrap(),
+										#objects
+									)?;
+								}
+							} else {
+								quote! {
+									visitor.predicate(
+										::linked_data_next::iref::Iri::new(#iri).unwrap(),
+										#objects
+									)?;
+								}
+							}
 						}
 					}
 					None => return Err(Error::UnknownFieldSerializationMethod(span)),
 				}
 			};
 
+			let visit_field = match &field_attrs.skip_serializing_if {
+				Some(path) => quote! {
+					if !#path(#skipped_ref) {
+						#visit_field
+					}
+				},
+				None => visit_field,
+			};
+
 			visit_fields.push(visit_field)
 		}
 	}
@@ -121,3 +277,96 @@ fn variant_compound_fields(
 
 	Ok(CompoundFields { id_field, visit })
 }
+
+/// Generates an expression wrapping the field reference into a local type
+/// whose `LinkedDataPredicateObjects` implementation calls the given
+/// `serialize_with` function.
+///
+/// The wrapper replicates the generic parameters of the serialized type so
+/// that the field type may depend on them.
+fn serialize_with(
+	ident: &Ident,
+	generics: &syn::Generics,
+	ty: &syn::Type,
+	path: &syn::Path,
+	field_ref: TokenStream,
+) -> TokenStream {
+	let mut params = generics.params.clone();
+	for param in &mut params {
+		match param {
+			syn::GenericParam::Type(p) => {
+				p.eq_token = None;
+				p.default = None;
+			}
+			syn::GenericParam::Const(p) => {
+				p.eq_token = None;
+				p.default = None;
+			}
+			syn::GenericParam::Lifetime(_) => (),
+		}
+	}
+
+	let params: Vec<_> = params.iter().collect();
+	let args: Vec<_> = generics
+		.params
+		.iter()
+		.map(|p| match p {
+			syn::GenericParam::Type(p) => {
+				let id = &p.ident;
+				quote!(#id)
+			}
+			syn::GenericParam::Const(p) => {
+				let id = &p.ident;
+				quote!(#id)
+			}
+			syn::GenericParam::Lifetime(p) => {
+				let l = &p.lifetime;
+				quote!(#l)
+			}
+		})
+		.collect();
+	let inferred_args: Vec<_> = generics
+		.params
+		.iter()
+		.map(|p| match p {
+			syn::GenericParam::Type(p) => {
+				let id = &p.ident;
+				quote!(#id)
+			}
+			syn::GenericParam::Const(p) => {
+				let id = &p.ident;
+				quote!(#id)
+			}
+			syn::GenericParam::Lifetime(_) => quote!('_),
+		})
+		.collect();
+	let (_, ty_generics, where_clause) = generics.split_for_impl();
+	let where_predicates = where_clause.map(|w| &w.predicates);
+
+	quote! {
+		&{
+			struct SerializeWith_<'a_, #(#params),*>(
+				&'a_ #ty,
+				::std::marker::PhantomData<fn() -> #ident #ty_generics>
+			) #where_clause;
+
+			impl<'a_, #(#params,)* I_, V_> ::linked_data_next::LinkedDataPredicateObjects<I_, V_> for SerializeWith_<'a_, #(#args),*>
+			where
+				I_: ::linked_data_next::rdf_types::Interpretation,
+				V_: ::linked_data_next::rdf_types::Vocabulary
+					+ ::linked_data_next::rdf_types::vocabulary::IriVocabularyMut
+					+ ::linked_data_next::rdf_types::vocabulary::LiteralVocabularyMut,
+				#where_predicates
+			{
+				fn visit_objects<S_>(&self, visitor: S_) -> Result<S_::Ok, S_::Error>
+				where
+					S_: ::linked_data_next::PredicateObjectsVisitor<I_, V_>
+				{
+					#path(self.0, visitor)
+				}
+			}
+
+			SerializeWith_::<'_, #(#inferred_args),*>(#field_ref, ::std::marker::PhantomData)
+		}
+	}
+}
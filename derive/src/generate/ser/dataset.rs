@@ -0,0 +1,92 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::spanned::Spanned;
+
+use crate::generate::{
+	FieldsUsage, InterpretationBounds, TypeAttributes, VocabularyBounds, extend_generics,
+	read_field_attributes,
+};
+
+use super::Error;
+
+pub fn generate(
+	attrs: &TypeAttributes,
+	ident: Ident,
+	generics: syn::Generics,
+	s: syn::DataStruct,
+) -> Result<TokenStream, Error> {
+	let mut bounds: Vec<syn::WherePredicate> = Vec::new();
+	let mut vocabulary_bounds = VocabularyBounds::default();
+	let mut visit_graphs = Vec::new();
+
+	let mut usage = FieldsUsage::default();
+	for (i, field) in s.fields.into_iter().enumerate() {
+		let span = field.span();
+		let field_attrs = read_field_attributes(field.attrs)?;
+		field_attrs.check_dataset_field(span)?;
+		usage.check(&attrs.prefixes, &field_attrs, span)?;
+
+		if field_attrs.ignore {
+			continue;
+		}
+
+		let field_access = match field.ident {
+			Some(id) => quote!(self.#id),
+			None => {
+				let index = syn::Index {
+					index: i as u32,
+					span: Span::call_site(),
+				};
+
+				quote!(self.#index)
+			}
+		};
+
+		let ty = field.ty;
+		bounds.push(
+			syn::parse2(quote! {
+				#ty: ::linked_data_next::LinkedDataGraph<I_, V_>
+			})
+			.unwrap(),
+		);
+
+		if field_attrs.default_graph {
+			visit_graphs.push(quote! {
+				visitor.default_graph(&#field_access)?;
+			})
+		} else if let Some(compact_iri) = field_attrs.named_graph {
+			let iri = compact_iri.expand(&attrs.prefixes)?.into_string();
+			vocabulary_bounds.iri_mut = true;
+
+			visit_graphs.push(quote! {
+				visitor.named_graph(&::linked_data_next::NamedGraph(
+					::linked_data_next::iref::Iri::new(#iri).unwrap(),
+					&#field_access
+				))?;
+			})
+		} else {
+			return Err(Error::MissingFieldGraph(span));
+		}
+	}
+
+	let ld_generics = extend_generics(
+		&generics,
+		vocabulary_bounds,
+		InterpretationBounds::default(),
+		bounds,
+	);
+	let (_, ty_generics, _) = generics.split_for_impl();
+	let (impl_generics, _, where_clause) = ld_generics.split_for_impl();
+
+	Ok(quote! {
+		impl #impl_generics ::linked_data_next::LinkedData<I_, V_> for #ident #ty_generics #where_clause {
+			fn visit<S_>(&self, mut visitor: S_) -> Result<S_::Ok, S_::Error>
+			where
+				S_: ::linked_data_next::Visitor<I_, V_>
+			{
+				#(#visit_graphs)*
+				visitor.end()
+			}
+		}
+	})
+}
@@ -5,8 +5,8 @@ use syn::{punctuated::Punctuated, spanned::Spanned};
 
 use crate::{
 	generate::{
-		InterpretationBounds, TypeAttributes, VariantAttributes, VocabularyBounds, extend_generics,
-		read_variant_attributes,
+		InterpretationBounds, RDF_TYPE, TypeAttributes, VariantAttributes, VocabularyBounds,
+		extend_generics, read_variant_attributes,
 	},
 	utils::UsesGenericParam,
 };
@@ -51,6 +51,7 @@ pub fn generate(
 		let variant = StrippedVariant::new(variant.ident, variant.fields);
 
 		let nest = variant_nest(attrs, &variant_attrs)?;
+		let class = variant_class(attrs, &variant_attrs)?;
 		let shape = variant_shape(attrs, &ident, &generics, &variant)?;
 
 		if nest.is_some() {
@@ -67,6 +68,10 @@ pub fn generate(
 
 		let variant_id = &variant.ident;
 		let input = &variant.input;
+		let self_input = match class {
+			Some(_) => variant.wildcard_input(),
+			None => input.clone(),
+		};
 
 		let lexical_repr_case = variant_interpret(
 			&variant,
@@ -85,6 +90,7 @@ pub fn generate(
 		let visit_subject_case = variant_visit_subject(
 			&variant,
 			nest.as_deref(),
+			class.as_deref(),
 			&shape,
 			&mut visit_subject_bounds,
 			&mut visit_subject_vocabulary_bounds,
@@ -96,44 +102,86 @@ pub fn generate(
 			}
 		});
 
-		let visit_predicate_case = variant_visit_predicate(
-			&variant,
-			nest.as_deref(),
-			&shape,
-			&mut visit_predicate_bounds,
-			&mut visit_predicate_vocabulary_bounds,
-		);
+		let visit_predicate_case =
+			match class {
+				Some(_) => {
+					visit_predicate_bounds.push(self_bound(&ident, &generics, quote! {
+					::linked_data_next::LinkedDataResource<I_, V_> + ::linked_data_next::LinkedDataSubject<I_, V_>
+				}));
+
+					quote! {
+						visitor.object(self)?;
+						visitor.end()
+					}
+				}
+				None => variant_visit_predicate(
+					&variant,
+					nest.as_deref(),
+					&shape,
+					&mut visit_predicate_bounds,
+					&mut visit_predicate_vocabulary_bounds,
+				),
+			};
 
 		visit_predicate_cases.push(quote! {
-			Self::#variant_id #input => {
+			Self::#variant_id #self_input => {
 				#visit_predicate_case
 			}
 		});
 
-		let visit_graph_case = variant_visit_graph(
-			&variant,
-			nest.as_deref(),
-			&shape,
-			&mut visit_graph_bounds,
-			&mut visit_graph_vocabulary_bounds,
-		);
+		let visit_graph_case =
+			match class {
+				Some(_) => {
+					visit_graph_bounds.push(self_bound(&ident, &generics, quote! {
+					::linked_data_next::LinkedDataResource<I_, V_> + ::linked_data_next::LinkedDataSubject<I_, V_>
+				}));
+
+					quote! {
+						visitor.subject(self)?;
+						visitor.end()
+					}
+				}
+				None => variant_visit_graph(
+					&variant,
+					nest.as_deref(),
+					&shape,
+					&mut visit_graph_bounds,
+					&mut visit_graph_vocabulary_bounds,
+				),
+			};
 
 		visit_graph_cases.push(quote! {
-			Self::#variant_id #input => {
+			Self::#variant_id #self_input => {
 				#visit_graph_case
 			}
 		});
 
-		let visit_ld_case = variant_serialize(
-			&variant,
-			nest.as_deref(),
-			&shape,
-			&mut visit_ld_bounds,
-			&mut visit_ld_vocabulary_bounds,
-		);
+		let visit_ld_case = match class {
+			Some(_) => {
+				visit_ld_bounds.push(self_bound(
+					&ident,
+					&generics,
+					quote! {
+						::linked_data_next::LinkedDataGraph<I_, V_>
+					},
+				));
+
+				quote! {
+					visitor.default_graph(self)?;
+					visitor.end()
+				}
+			}
+			None => variant_serialize(
+				&variant,
+				nest.as_deref(),
+				&shape,
+				&mut visit_ld_bounds,
+				&mut visit_ld_vocabulary_bounds,
+			),
+		};
 
 		visit_ld_cases.push(quote! {
-			Self::#variant_id #input => {
+			Self::#variant_id #self_input => {
 				#visit_ld_case
 			}
 		});
@@ -289,6 +337,17 @@ impl StrippedVariant {
 	}
 }
 
+impl StrippedVariant {
+	/// Pattern matching the variant without binding its fields.
+	fn wildcard_input(&self) -> TokenStream {
+		match &self.fields {
+			syn::Fields::Named(_) => quote!({ .. }),
+			syn::Fields::Unnamed(_) => quote!((..)),
+			syn::Fields::Unit => TokenStream::new(),
+		}
+	}
+}
+
 impl UsesGenericParam for StrippedVariant {
 	fn uses_generic_param(&self, p: &syn::GenericParam) -> bool {
 		self.fields.iter().any(|f| f.ty.uses_generic_param(p))
@@ -374,6 +433,7 @@ fn variant_interpret(
 fn variant_visit_subject(
 	variant: &StrippedVariant,
 	nest: Option<&Iri>,
+	class: Option<&Iri>,
 	shape: &VariantShape,
 	bounds: &mut Vec<syn::WherePredicate>,
 	vocabulary_bounds: &mut VocabularyBounds,
@@ -423,36 +483,66 @@ fn variant_visit_subject(
 				}
 			}
 		}
-		None => match shape {
-			VariantShape::Simple(id, ty) => {
-				bounds.push(
-					syn::parse2(quote! {
-						#ty: ::linked_data_next::LinkedDataSubject<I_, V_>
-					})
-					.unwrap(),
-				);
+		None => {
+			let visit_class = class.map(|class| {
+				vocabulary_bounds.iri_mut = true;
+				let rdf_type = RDF_TYPE.as_str();
+				let class = class.as_str();
 
 				quote! {
-					<#ty as ::linked_data_next::LinkedDataSubject<I_, V_>>::visit_subject(#id, visitor)
+					visitor.predicate(
+						::linked_data_next::iref::Iri::new(#rdf_type).unwrap(),
+						::linked_data_next::iref::Iri::new(#class).unwrap()
+					)?;
 				}
-			}
-			VariantShape::Compound(inner_ty) => {
-				let inner_id = &inner_ty.ident;
-				let input = &variant.input;
+			});
 
-				bounds.extend(inner_ty.visit_bounds.iter().cloned());
-				vocabulary_bounds.add(inner_ty.visit_vocabulary_bounds);
+			match shape {
+				VariantShape::Simple(id, ty) => {
+					bounds.push(
+						syn::parse2(quote! {
+							#ty: ::linked_data_next::LinkedDataSubject<I_, V_>
+						})
+						.unwrap(),
+					);
 
-				quote! {
-					#inner_id #input .visit_subject(visitor)
+					match visit_class {
+						Some(visit_class) => quote! {
+							#visit_class
+							<#ty as ::linked_data_next::LinkedDataSubject<I_, V_>>::visit_subject(#id, &mut visitor)?;
+							visitor.end()
+						},
+						None => quote! {
+							<#ty as ::linked_data_next::LinkedDataSubject<I_, V_>>::visit_subject(#id, visitor)
+						},
+					}
 				}
-			}
-			VariantShape::Unit => {
-				quote! {
-					visitor.end()
+				VariantShape::Compound(inner_ty) => {
+					let inner_id = &inner_ty.ident;
+					let input = &variant.input;
+
+					bounds.extend(inner_ty.visit_bounds.iter().cloned());
+					vocabulary_bounds.add(inner_ty.visit_vocabulary_bounds);
+
+					match visit_class {
+						Some(visit_class) => quote! {
+							#visit_class
+							#inner_id #input .visit_subject(&mut visitor)?;
+							visitor.end()
+						},
+						None => quote! {
+							#inner_id #input .visit_subject(visitor)
+						},
+					}
+				}
+				VariantShape::Unit => {
+					quote! {
+						#visit_class
+						visitor.end()
+					}
 				}
 			}
-		},
+		}
 	}
 }
 
@@ -721,6 +811,8 @@ fn variant_subject_type(
 ) -> Result<VariantSubjectType, Error> {
 	let compound_fields = variant_compound_fields(
 		attrs,
+		ident,
+		generics,
 		variant.fields.clone(),
 		|f| quote!(#f),
 		|i| {
@@ -749,10 +841,10 @@ fn variant_subject_type(
 			});
 
 			quote! {
-				( #(#fields),* )
+				( #(#fields),* );
 			}
 		}
-		syn::Fields::Unit => quote!(),
+		syn::Fields::Unit => quote!(;),
 	};
 
 	let mut lexical_repr_bounds = Vec::new();
@@ -829,7 +921,7 @@ fn variant_subject_type(
 
 	let definition = quote! {
 		#[allow(non_camel_case_types)]
-		struct #subject_id #def_ty_generics #borrowed_fields;
+		struct #subject_id #def_ty_generics #borrowed_fields
 
 		impl #repr_impl_generics ::linked_data_next::LinkedDataResource<I_, V_> for #subject_id #ty_generics #repr_where_clauses {
 			fn interpretation(
@@ -837,6 +929,7 @@ fn variant_subject_type(
 				vocabulary: &mut V_,
 				interpretation: &mut I_,
 			) -> ::linked_data_next::ResourceInterpretation<I_, V_> {
+				#[allow(unused_variables)]
 				let #subject_id #input = self;
 				#term
 			}
@@ -848,6 +941,7 @@ fn variant_subject_type(
 				vocabulary: &mut V_,
 				interpretation: &mut I_,
 			) -> ::linked_data_next::ResourceInterpretation<'_nest, I_, V_> {
+				#[allow(unused_variables)]
 				let #subject_id #input = self;
 				#term
 			}
@@ -858,6 +952,7 @@ fn variant_subject_type(
 			where
 				S_: ::linked_data_next::SubjectVisitor<I_, V_>
 			{
+				#[allow(unused_variables)]
 				let #subject_id #input = self;
 				#visit_body
 			}
@@ -943,6 +1038,28 @@ fn variant_shape(
 	}
 }
 
+/// Bound requiring the enum type itself to implement the given traits.
+fn self_bound(ident: &Ident, generics: &syn::Generics, traits: TokenStream) -> syn::WherePredicate {
+	let (_, ty_generics, _) = generics.split_for_impl();
+	syn::parse2(quote! {
+		#ident #ty_generics: #traits
+	})
+	.unwrap()
+}
+
+fn variant_class(
+	attrs: &TypeAttributes,
+	variant_attrs: &VariantAttributes,
+) -> Result<Option<IriBuf>, Error> {
+	match &variant_attrs.class {
+		Some(compact_iri) => {
+			let iri = compact_iri.expand(&attrs.prefixes)?;
+			Ok(Some(iri))
+		}
+		None => Ok(None),
+	}
+}
+
 fn variant_nest(
 	attrs: &TypeAttributes,
 	variant_attrs: &VariantAttributes,
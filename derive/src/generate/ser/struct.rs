@@ -13,6 +13,8 @@ pub fn generate(
 ) -> Result<TokenStream, Error> {
 	let fields = variant_compound_fields(
 		attrs,
+		&ident,
+		&generics,
 		s.fields,
 		|f| quote!(self.#f),
 		|i| {
@@ -45,7 +47,8 @@ pub fn generate(
 		.transpose()?;
 
 	let visit = fields.visit.body;
-	let vocabulary_bounds = fields.visit.vocabulary_bounds;
+	let mut vocabulary_bounds = fields.visit.vocabulary_bounds;
+	vocabulary_bounds.iri_mut |= visit_type.is_some();
 
 	let term = match fields.id_field {
 		Some((field_access, ty)) => {
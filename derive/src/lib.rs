@@ -8,6 +8,30 @@ use syn::DeriveInput;
 mod generate;
 pub(crate) mod utils;
 
+/// Reports the given error, along with its suggested correction.
+fn report(e: generate::Error) -> ! {
+	match e.help() {
+		Some(help) => abort!(e.span(), e; help = help),
+		None => abort!(e.span(), e),
+	}
+}
+
+/// Derives `LinkedDataSubject` and its companion traits.
+///
+/// # Compact IRIs
+///
+/// IRIs in `ld` attributes are written `prefix:suffix`, where `prefix` is
+/// declared on the type with `#[ld(prefix("prefix" = "..."))]`. An undeclared
+/// prefix is read as the scheme of an absolute IRI (`urn:isbn:...`), except
+/// when it is most likely a mistake, which is a compile error:
+///
+/// - a well-known prefix (`rdf`, `rdfs`, `xsd`, `owl` or `schema`), or
+/// - a prefix one edit away from a declared or well-known one (two edits for
+///   prefixes of five characters or more), such as `sip` next to `sio`.
+///
+/// IRIs whose suffix starts with `//` (`http://...`) are always absolute. To
+/// use another scheme caught by this rule, declare it as a prefix expanding
+/// to itself, as in `#[ld(prefix("sip" = "sip:"))]`.
 #[proc_macro_derive(Serialize, attributes(ld))]
 #[proc_macro_error]
 pub fn derive_serialize(item: TokenStream) -> TokenStream {
@@ -16,14 +40,21 @@ pub fn derive_serialize(item: TokenStream) -> TokenStream {
 
 	match generate::ser::subject(input) {
 		Ok(tokens) => output.extend(tokens),
-		Err(e) => {
-			abort!(e.span(), e)
-		}
+		Err(e) => report(e),
 	}
 
 	output.into()
 }
 
+/// Derives `LinkedDataDeserializeSubject` and its companion traits.
+///
+/// # Type checking
+///
+/// A struct declaring its class with `#[ld(type = "...")]` only
+/// deserializes from nodes carrying that `rdf:type`, among any others. Nodes
+/// without it, including untyped nodes, are rejected with
+/// `FromLinkedDataError::TypeMismatch`. Add `#[ld(skip_type_check)]` to the
+/// type to accept any node while still serializing the type.
 #[proc_macro_derive(Deserialize, attributes(ld))]
 #[proc_macro_error]
 pub fn derive_deserialize(item: TokenStream) -> TokenStream {
@@ -32,9 +63,21 @@ pub fn derive_deserialize(item: TokenStream) -> TokenStream {
 
 	match generate::de::subject(input) {
 		Ok(tokens) => output.extend(tokens),
-		Err(e) => {
-			abort!(e.span(), e)
-		}
+		Err(e) => report(e),
+	}
+
+	output.into()
+}
+
+#[proc_macro_derive(JsonLdContext, attributes(ld))]
+#[proc_macro_error]
+pub fn derive_json_ld_context(item: TokenStream) -> TokenStream {
+	let input = syn::parse_macro_input!(item as DeriveInput);
+	let mut output = proc_macro2::TokenStream::new();
+
+	match generate::context::generate(input) {
+		Ok(tokens) => output.extend(tokens),
+		Err(e) => report(e),
 	}
 
 	output.into()
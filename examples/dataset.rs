@@ -0,0 +1,62 @@
+use iref::IriBuf;
+use linked_data_next::{Deserialize, LinkedDataDeserialize, Serialize, to_dataset, turtle};
+use rdf_types::generator;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+#[ld(dataset)]
+struct Document {
+	#[ld(default_graph)]
+	index: Index,
+
+	#[ld(named_graph = "ex:people")]
+	people: Vec<Person>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Index {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:title")]
+	title: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+#[ld(type = "ex:Person")]
+struct Person {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:name")]
+	name: String,
+}
+
+fn main() {
+	let value = Document {
+		index: Index {
+			id: IriBuf::new("http://example.org/".to_owned()).unwrap(),
+			title: "People".to_owned(),
+		},
+		people: vec![
+			Person {
+				id: IriBuf::new("http://example.org/Alice".to_owned()).unwrap(),
+				name: "Alice".to_owned(),
+			},
+			Person {
+				id: IriBuf::new("http://example.org/Bob".to_owned()).unwrap(),
+				name: "Bob".to_owned(),
+			},
+		],
+	};
+
+	let trig =
+		turtle::to_string(generator::Blank::new(), &value).expect("RDF serialization failed");
+	println!("{trig}");
+
+	let dataset = to_dataset(generator::Blank::new(), &value).expect("RDF serialization failed");
+	let result = Document::deserialize_dataset(&(), &(), &dataset).expect("deserialization failed");
+	assert_eq!(result, value)
+}
@@ -1,6 +1,6 @@
 use iref::IriBuf;
-use linked_data_next::{Deserialize, Serialize, to_quads};
-use rdf_types::{RdfDisplay, generator};
+use linked_data_next::{Deserialize, Serialize, nquads};
+use rdf_types::generator;
 
 #[derive(Serialize, Deserialize)]
 #[ld(prefix("ex" = "http://example.org/"))]
@@ -45,9 +45,6 @@ fn main() {
 		},
 	};
 
-	let quads = to_quads(generator::Blank::new(), &value).expect("RDF serialization failed");
-
-	for quad in quads {
-		println!("{} .", quad.rdf_display())
-	}
+	nquads::to_writer(std::io::stdout(), generator::Blank::new(), &value)
+		.expect("RDF serialization failed");
 }
@@ -34,6 +34,22 @@ enum Ids {
 	A,
 }
 
+#[derive(Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+enum Agent {
+	#[ld(class = "ex:Person")]
+	Person {
+		#[ld("ex:name")]
+		name: String,
+	},
+
+	#[ld(class = "ex:Organization")]
+	Organization {
+		#[ld("ex:legalName")]
+		legal_name: String,
+	},
+}
+
 fn main() {
 	let a = Subject::Name("A".to_string());
 	println!("{:?}", a.interpretation(&mut (), &mut ()));
@@ -62,4 +78,13 @@ fn main() {
 	println!();
 	let e = Ids::A;
 	println!("{:?}", e.interpretation(&mut (), &mut ()));
+
+	println!();
+	let f = Agent::Organization {
+		legal_name: "ACME".to_string(),
+	};
+	let quads = to_quads(generator::Blank::new(), &f).expect("RDF serialization failed");
+	for quad in quads {
+		println!("{} .", quad.rdf_display())
+	}
 }
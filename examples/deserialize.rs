@@ -1,7 +1,7 @@
 use iref::IriBuf;
-use linked_data_next::{LinkedDataDeserializeSubject, to_quads};
+use linked_data_next::{LinkedDataDeserializeSubject, nquads};
 use linked_data_next_derive::{Deserialize, Serialize};
-use rdf_types::dataset::{IndexedBTreeDataset, TraversableDataset};
+use rdf_types::dataset::TraversableDataset;
 use rdf_types::{RdfDisplay, Term, generator};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -16,8 +16,6 @@ struct Foo {
 }
 
 fn main() {
-	let mut dataset = IndexedBTreeDataset::new();
-
 	let id_1 = IriBuf::new("http://example.org/john".to_string()).unwrap();
 
 	let value_1 = Foo {
@@ -26,23 +24,6 @@ fn main() {
 		emails: vec!["john.smith@mail.me".to_string()],
 	};
 
-	to_quads(
-		generator::Blank::new_with_prefix("john-".to_string()),
-		&value_1,
-	)
-	.expect("RDF serialization failed")
-	.into_iter()
-	.for_each(|rdf_quad| {
-		let quad = rdf_types::Quad(
-			Term::Id(rdf_quad.0),
-			Term::iri(rdf_quad.1),
-			rdf_quad.2,
-			rdf_quad.3.map(Term::Id),
-		);
-
-		dataset.insert(quad);
-	});
-
 	let id_2 = IriBuf::new("http://example.org/joe".to_string()).unwrap();
 
 	let value_2 = Foo {
@@ -51,22 +32,23 @@ fn main() {
 		emails: vec!["joe.dalton@mail.me".to_string()],
 	};
 
-	to_quads(
+	let mut document = Vec::new();
+
+	nquads::to_writer(
+		&mut document,
+		generator::Blank::new_with_prefix("john-".to_string()),
+		&value_1,
+	)
+	.expect("RDF serialization failed");
+
+	nquads::to_writer(
+		&mut document,
 		generator::Blank::new_with_prefix("joe-".to_string()),
 		&value_2,
 	)
-	.expect("RDF serialization failed")
-	.into_iter()
-	.for_each(|rdf_quad| {
-		let quad = rdf_types::Quad(
-			Term::Id(rdf_quad.0),
-			Term::iri(rdf_quad.1),
-			rdf_quad.2,
-			rdf_quad.3.map(Term::Id),
-		);
+	.expect("RDF serialization failed");
 
-		dataset.insert(quad);
-	});
+	let dataset = nquads::parse_reader(document.as_slice()).expect("invalid N-Quads");
 
 	let resources = [Term::iri(id_1), Term::iri(id_2)];
 
@@ -1,15 +1,15 @@
 use iref::IriBuf;
-use linked_data_next::{Deserialize, Serialize, to_quads};
+use linked_data_next::{Deserialize, Serialize, roundtrip, to_quads};
 use rdf_types::{RdfDisplay, generator};
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[ld(prefix("ex" = "http://example.org/"))]
 struct Foo {
 	#[ld("ex:graph", graph)]
 	graph: GraphItem,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[ld(prefix("ex" = "http://example.org/"))]
 #[ld(type = "http://example.org/Bar")]
 struct GraphItem {
@@ -33,4 +33,7 @@ fn main() {
 	for quad in quads {
 		println!("{} .", quad.rdf_display())
 	}
+
+	let result = roundtrip(generator::Blank::new(), &value).expect("round-trip failed");
+	assert_eq!(result, value)
 }
@@ -0,0 +1,41 @@
+use iref::IriBuf;
+use linked_data_next::{Serialize, turtle};
+use rdf_types::generator;
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+#[ld(type = "ex:Person")]
+struct Person {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:name")]
+	name: String,
+
+	#[ld("ex:nicknames")]
+	nicknames: Vec<String>,
+
+	#[ld("ex:address")]
+	address: Address,
+}
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Address {
+	#[ld("ex:city")]
+	city: String,
+}
+
+fn main() {
+	let value = Person {
+		id: IriBuf::new("http://example.org/JohnSmith".to_string()).unwrap(),
+		name: "John Smith".to_string(),
+		nicknames: vec!["Johnny".to_string(), "J.".to_string()],
+		address: Address {
+			city: "Paris".to_string(),
+		},
+	};
+
+	turtle::to_writer(std::io::stdout(), generator::Blank::new(), &value)
+		.expect("RDF serialization failed");
+}
@@ -0,0 +1,95 @@
+use std::net::IpAddr;
+
+use iref::IriBuf;
+use linked_data_next::{Deserialize, Serialize, nquads};
+use rdf_types::Term;
+
+/// Maps `IpAddr`, which does not implement the Linked-Data traits, to a
+/// plain string literal.
+mod ip_addr {
+	use std::net::IpAddr;
+
+	use linked_data_next::{
+		Context, FromLinkedDataError, LinkedDataDeserializePredicateObjects,
+		PredicateObjectsVisitor,
+	};
+	use rdf_types::{
+		Interpretation, Vocabulary,
+		dataset::PatternMatchingDataset,
+		interpretation::{ReverseIriInterpretation, ReverseLiteralInterpretation},
+		vocabulary::{IriVocabularyMut, LiteralVocabulary, LiteralVocabularyMut},
+	};
+
+	pub fn serialize<I, V, S>(value: &IpAddr, mut visitor: S) -> Result<S::Ok, S::Error>
+	where
+		I: Interpretation,
+		V: Vocabulary + IriVocabularyMut + LiteralVocabularyMut,
+		S: PredicateObjectsVisitor<I, V>,
+	{
+		visitor.object(&value.to_string())?;
+		visitor.end()
+	}
+
+	pub fn deserialize<'a, I, V, D>(
+		vocabulary: &V,
+		interpretation: &I,
+		dataset: &D,
+		graph: Option<&I::Resource>,
+		objects: impl IntoIterator<Item = &'a I::Resource>,
+		context: Context<I>,
+	) -> Result<IpAddr, FromLinkedDataError>
+	where
+		I: Interpretation
+			+ ReverseIriInterpretation<Iri = V::Iri>
+			+ ReverseLiteralInterpretation<Literal = V::Literal>,
+		V: Vocabulary + LiteralVocabulary,
+		I::Resource: 'a,
+		D: PatternMatchingDataset<Resource = I::Resource>,
+	{
+		let value = String::deserialize_objects_in(
+			vocabulary,
+			interpretation,
+			dataset,
+			graph,
+			objects,
+			context,
+		)?;
+
+		value.parse().map_err(|_| {
+			FromLinkedDataError::InvalidLiteral(context.into_iris(vocabulary, interpretation))
+		})
+	}
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Host {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:address", with = "ip_addr")]
+	address: IpAddr,
+
+	#[ld(
+		"ex:gateway",
+		serialize_with = "ip_addr::serialize",
+		deserialize_with = "ip_addr::deserialize"
+	)]
+	gateway: IpAddr,
+}
+
+fn main() {
+	let value = Host {
+		id: IriBuf::new("http://example.org/host".to_owned()).unwrap(),
+		address: "192.168.1.12".parse().unwrap(),
+		gateway: "192.168.1.1".parse().unwrap(),
+	};
+
+	let document = nquads::to_string_sorted(rdf_types::generator::Blank::new(), &value)
+		.expect("RDF serialization failed");
+	println!("{document}");
+
+	let result: Host = linked_data_next::from_nquads_str(&document, &Term::iri(value.id.clone()))
+		.expect("deserialization failed");
+	assert_eq!(result, value)
+}
@@ -0,0 +1,127 @@
+//! Literal coercion policies.
+use iref::Iri;
+use xsd_types::{
+	XSD_BYTE, XSD_DATE_TIME, XSD_DATE_TIME_STAMP, XSD_DAY_TIME_DURATION, XSD_DECIMAL, XSD_DURATION,
+	XSD_ENTITY, XSD_ID, XSD_IDREF, XSD_INT, XSD_INTEGER, XSD_LANGUAGE, XSD_LONG, XSD_NAME,
+	XSD_NC_NAME, XSD_NEGATIVE_INTEGER, XSD_NMTOKEN, XSD_NON_NEGATIVE_INTEGER,
+	XSD_NON_POSITIVE_INTEGER, XSD_NORMALIZED_STRING, XSD_POSITIVE_INTEGER, XSD_SHORT, XSD_STRING,
+	XSD_TOKEN, XSD_UNSIGNED_BYTE, XSD_UNSIGNED_INT, XSD_UNSIGNED_LONG, XSD_UNSIGNED_SHORT,
+	XSD_YEAR_MONTH_DURATION,
+};
+
+/// Policy deciding which literals may be deserialized as a given datatype.
+///
+/// The policy is carried by the deserialization [`Context`](crate::Context)
+/// and applies to every nested value:
+///
+/// ```
+/// # use linked_data_next::{Coercion, Context, Deserialize, LinkedDataDeserializeSubject};
+/// # use linked_data_next::{iref::IriBuf, nquads, rdf_types::Term};
+/// #[derive(Deserialize)]
+/// #[ld(prefix("ex" = "http://example.org/"))]
+/// struct Item {
+///   #[ld("ex:count")]
+///   count: u32,
+/// }
+///
+/// let dataset = nquads::parse_str(r#"<http://example.org/a> <http://example.org/count> "42" ."#).unwrap();
+/// let subject = Term::iri(IriBuf::new("http://example.org/a".to_owned()).unwrap());
+///
+/// // Plain strings are not `xsd:unsignedInt` literals.
+/// assert!(Item::deserialize_subject(&(), &(), &dataset, None, &subject).is_err());
+///
+/// let context = Context::default().with_coercion(Coercion::Lenient);
+/// let item = Item::deserialize_subject_in(&(), &(), &dataset, None, &subject, context).unwrap();
+/// assert_eq!(item.count, 42);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Coercion {
+	/// Only accepts literals of exactly the expected datatype, or of one of
+	/// its compatible datatypes (e.g. `xsd:integer` for `u8`), but not of
+	/// their subtypes.
+	Strict,
+
+	/// Also accepts literals whose datatype derives from the expected
+	/// datatype, or from a compatible one (e.g. any `xsd:integer` subtype
+	/// for `i64`), provided the value fits in the target type.
+	#[default]
+	Derived,
+
+	/// Parses the lexical form of any literal, whatever its datatype.
+	Lenient,
+}
+
+impl Coercion {
+	/// Checks if a literal of datatype `ty` may be read as a value of
+	/// datatype `expected`.
+	///
+	/// The `compatible` datatypes are also accepted, along with their
+	/// subtypes under the [`Coercion::Derived`] policy. Range checks are left
+	/// to the parser of the lexical form.
+	pub fn accepts(&self, ty: &Iri, expected: &Iri, compatible: &[&Iri]) -> bool {
+		match self {
+			Self::Strict => ty == expected || compatible.contains(&ty),
+			Self::Derived => {
+				derives_from(ty, expected) || compatible.iter().any(|c| derives_from(ty, c))
+			}
+			Self::Lenient => true,
+		}
+	}
+
+	/// Checks if language-tagged strings may be read as a value of another
+	/// datatype.
+	pub fn accepts_lang_string(&self) -> bool {
+		matches!(self, Self::Lenient)
+	}
+}
+
+/// Checks if the datatype `ty` is, or derives by restriction from, `base`.
+fn derives_from(mut ty: &Iri, base: &Iri) -> bool {
+	loop {
+		if ty == base {
+			break true;
+		}
+
+		match base_datatype(ty) {
+			Some(parent) => ty = parent,
+			None => break false,
+		}
+	}
+}
+
+/// Returns the datatype from which the given built-in XSD datatype is
+/// derived by restriction, if any.
+fn base_datatype(ty: &Iri) -> Option<&'static Iri> {
+	const HIERARCHY: &[(&Iri, &Iri)] = &[
+		(XSD_INTEGER, XSD_DECIMAL),
+		(XSD_NON_POSITIVE_INTEGER, XSD_INTEGER),
+		(XSD_NEGATIVE_INTEGER, XSD_NON_POSITIVE_INTEGER),
+		(XSD_LONG, XSD_INTEGER),
+		(XSD_INT, XSD_LONG),
+		(XSD_SHORT, XSD_INT),
+		(XSD_BYTE, XSD_SHORT),
+		(XSD_NON_NEGATIVE_INTEGER, XSD_INTEGER),
+		(XSD_UNSIGNED_LONG, XSD_NON_NEGATIVE_INTEGER),
+		(XSD_UNSIGNED_INT, XSD_UNSIGNED_LONG),
+		(XSD_UNSIGNED_SHORT, XSD_UNSIGNED_INT),
+		(XSD_UNSIGNED_BYTE, XSD_UNSIGNED_SHORT),
+		(XSD_POSITIVE_INTEGER, XSD_NON_NEGATIVE_INTEGER),
+		(XSD_NORMALIZED_STRING, XSD_STRING),
+		(XSD_TOKEN, XSD_NORMALIZED_STRING),
+		(XSD_LANGUAGE, XSD_TOKEN),
+		(XSD_NAME, XSD_TOKEN),
+		(XSD_NC_NAME, XSD_NAME),
+		(XSD_ID, XSD_NC_NAME),
+		(XSD_IDREF, XSD_NC_NAME),
+		(XSD_ENTITY, XSD_NC_NAME),
+		(XSD_NMTOKEN, XSD_TOKEN),
+		(XSD_DAY_TIME_DURATION, XSD_DURATION),
+		(XSD_YEAR_MONTH_DURATION, XSD_DURATION),
+		(XSD_DATE_TIME_STAMP, XSD_DATE_TIME),
+	];
+
+	HIERARCHY
+		.iter()
+		.find(|(derived, _)| *derived == ty)
+		.map(|(_, base)| *base)
+}
@@ -0,0 +1,245 @@
+//! Set and list representations of sequences.
+use rdf_types::{
+	Interpretation, RDF_FIRST, RDF_NIL, Vocabulary,
+	dataset::PatternMatchingDataset,
+	interpretation::{IriInterpretation, ReverseIriInterpretation},
+	vocabulary::IriVocabularyMut,
+};
+use std::collections::VecDeque;
+
+use crate::{
+	Context, FromLinkedDataError, LinkedDataDeserializePredicateObjects,
+	LinkedDataDeserializeSubject, LinkedDataPredicateObjects, LinkedDataResource,
+	LinkedDataSubject, PredicateObjectsVisitor, rdf_list::RdfList,
+};
+
+/// Ordered sequence of items.
+///
+/// Sequences can be represented either as an `rdf:List`, using [`AsList`], or
+/// as a set of objects, using [`AsSet`].
+pub trait Sequence {
+	type Item;
+
+	/// Iterates over the items of the sequence, in order.
+	fn items(&self) -> impl Clone + Iterator<Item = &Self::Item>;
+}
+
+impl<S: ?Sized + Sequence> Sequence for &S {
+	type Item = S::Item;
+
+	fn items(&self) -> impl Clone + Iterator<Item = &Self::Item> {
+		S::items(self)
+	}
+}
+
+impl<T> Sequence for [T] {
+	type Item = T;
+
+	fn items(&self) -> impl Clone + Iterator<Item = &Self::Item> {
+		self.iter()
+	}
+}
+
+impl<T> Sequence for Vec<T> {
+	type Item = T;
+
+	fn items(&self) -> impl Clone + Iterator<Item = &Self::Item> {
+		self.iter()
+	}
+}
+
+impl<T> Sequence for VecDeque<T> {
+	type Item = T;
+
+	fn items(&self) -> impl Clone + Iterator<Item = &Self::Item> {
+		self.iter()
+	}
+}
+
+/// Sequence represented as a set of objects.
+///
+/// Each item is bound to the predicate, so the items order is lost.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AsSet<S>(pub S);
+
+impl<I: Interpretation, V: Vocabulary, S: Sequence> LinkedDataPredicateObjects<I, V> for AsSet<S>
+where
+	S::Item: LinkedDataSubject<I, V> + LinkedDataResource<I, V>,
+{
+	fn visit_objects<W>(&self, mut visitor: W) -> Result<W::Ok, W::Error>
+	where
+		W: PredicateObjectsVisitor<I, V>,
+	{
+		for item in self.0.items() {
+			visitor.object(item)?;
+		}
+
+		visitor.end()
+	}
+}
+
+impl<I: Interpretation, V: Vocabulary, S> LinkedDataDeserializePredicateObjects<I, V> for AsSet<S>
+where
+	S: Sequence + FromIterator<S::Item>,
+	S::Item: LinkedDataDeserializeSubject<I, V>,
+{
+	fn deserialize_objects_in<'a, D>(
+		vocabulary: &V,
+		interpretation: &I,
+		dataset: &D,
+		graph: Option<&I::Resource>,
+		objects: impl IntoIterator<Item = &'a I::Resource>,
+		context: Context<I>,
+	) -> Result<Self, FromLinkedDataError>
+	where
+		I::Resource: 'a,
+		D: PatternMatchingDataset<Resource = I::Resource>,
+	{
+		objects
+			.into_iter()
+			.map(|object| {
+				S::Item::deserialize_subject_in(
+					vocabulary,
+					interpretation,
+					dataset,
+					graph,
+					object,
+					context,
+				)
+			})
+			.collect::<Result<_, _>>()
+			.map(AsSet)
+	}
+}
+
+/// Sequence represented as an `rdf:List`.
+///
+/// This is the default representation of `Vec<T>` and `VecDeque<T>`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AsList<S>(pub S);
+
+impl<I: Interpretation, V: Vocabulary + IriVocabularyMut, S: Sequence>
+	LinkedDataPredicateObjects<I, V> for AsList<S>
+where
+	S::Item: LinkedDataSubject<I, V> + LinkedDataResource<I, V>,
+{
+	fn visit_objects<W>(&self, mut visitor: W) -> Result<W::Ok, W::Error>
+	where
+		W: PredicateObjectsVisitor<I, V>,
+	{
+		visitor.object(&RdfList(self.0.items()))?;
+		visitor.end()
+	}
+}
+
+impl<I: Interpretation, V: Vocabulary, S> LinkedDataDeserializePredicateObjects<I, V> for AsList<S>
+where
+	I: ReverseIriInterpretation<Iri = V::Iri> + IriInterpretation<V::Iri>,
+	I::Resource: PartialEq,
+	S: Sequence + FromIterator<S::Item>,
+	S::Item: LinkedDataDeserializeSubject<I, V>,
+{
+	fn deserialize_objects_in<'a, D>(
+		vocabulary: &V,
+		interpretation: &I,
+		dataset: &D,
+		graph: Option<&I::Resource>,
+		objects: impl IntoIterator<Item = &'a I::Resource>,
+		context: Context<I>,
+	) -> Result<Self, FromLinkedDataError>
+	where
+		I::Resource: 'a,
+		D: PatternMatchingDataset<Resource = I::Resource>,
+	{
+		Vec::<S::Item>::deserialize_objects_in(
+			vocabulary,
+			interpretation,
+			dataset,
+			graph,
+			objects,
+			context,
+		)
+		.map(|items| AsList(items.into_iter().collect()))
+	}
+}
+
+/// Sequence deserialized from either an `rdf:List` or a set of objects.
+///
+/// A single object that is `rdf:nil` or has an `rdf:first` value is read as
+/// an `rdf:List`, any other objects are read as a set. It is serialized as
+/// an `rdf:List`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AsSetOrList<S>(pub S);
+
+impl<I: Interpretation, V: Vocabulary + IriVocabularyMut, S: Sequence>
+	LinkedDataPredicateObjects<I, V> for AsSetOrList<S>
+where
+	S::Item: LinkedDataSubject<I, V> + LinkedDataResource<I, V>,
+{
+	fn visit_objects<W>(&self, visitor: W) -> Result<W::Ok, W::Error>
+	where
+		W: PredicateObjectsVisitor<I, V>,
+	{
+		AsList(&self.0).visit_objects(visitor)
+	}
+}
+
+impl<I: Interpretation, V: Vocabulary, S> LinkedDataDeserializePredicateObjects<I, V>
+	for AsSetOrList<S>
+where
+	I: ReverseIriInterpretation<Iri = V::Iri> + IriInterpretation<V::Iri>,
+	I::Resource: PartialEq,
+	S: Sequence + FromIterator<S::Item>,
+	S::Item: LinkedDataDeserializeSubject<I, V>,
+{
+	fn deserialize_objects_in<'a, D>(
+		vocabulary: &V,
+		interpretation: &I,
+		dataset: &D,
+		graph: Option<&I::Resource>,
+		objects: impl IntoIterator<Item = &'a I::Resource>,
+		context: Context<I>,
+	) -> Result<Self, FromLinkedDataError>
+	where
+		I::Resource: 'a,
+		D: PatternMatchingDataset<Resource = I::Resource>,
+	{
+		let objects: Vec<_> = objects.into_iter().collect();
+
+		let is_list = match objects.as_slice() {
+			[object] => {
+				interpretation
+					.iris_of(object)
+					.any(|i| vocabulary.iri(i).unwrap() == RDF_NIL)
+					|| interpretation
+						.lexical_iri_interpretation(vocabulary, RDF_FIRST)
+						.is_some_and(|first| {
+							dataset.quad_objects(graph, object, &first).next().is_some()
+						})
+			}
+			_ => false,
+		};
+
+		if is_list {
+			AsList::deserialize_objects_in(
+				vocabulary,
+				interpretation,
+				dataset,
+				graph,
+				objects,
+				context,
+			)
+			.map(|AsList(items)| AsSetOrList(items))
+		} else {
+			AsSet::deserialize_objects_in(
+				vocabulary,
+				interpretation,
+				dataset,
+				graph,
+				objects,
+				context,
+			)
+			.map(|AsSet(items)| AsSetOrList(items))
+		}
+	}
+}
@@ -0,0 +1,85 @@
+use rdf_types::{
+	Generator, Id, Term,
+	dataset::IndexedBTreeDataset,
+	interpretation::{self, WithGenerator},
+};
+
+use crate::{
+	Context, FromLinkedDataError, IntoQuadsError, LinkedData, LinkedDataDeserializeSubject,
+	LinkedDataResource, LinkedDataSubject, QuadSink, to_lexical_subject_quads_with, to_quads_into,
+};
+
+/// Serializes the given value into a new dataset.
+pub fn to_dataset<G: Generator>(
+	generator: G,
+	value: &impl LinkedData<WithGenerator<G>>,
+) -> Result<IndexedBTreeDataset, IntoQuadsError> {
+	let mut dataset = IndexedBTreeDataset::new();
+	to_quads_into(generator, &mut dataset, value)?;
+	Ok(dataset)
+}
+
+/// Deserializes the given subject from the default graph of `dataset`.
+pub fn from_dataset<T: LinkedDataDeserializeSubject>(
+	dataset: &IndexedBTreeDataset,
+	subject: &Id,
+) -> Result<T, FromLinkedDataError> {
+	from_dataset_in(dataset, subject, Context::default())
+}
+
+/// Deserializes the given subject from the default graph of `dataset`,
+/// using the given deserialization context.
+pub fn from_dataset_in<T: LinkedDataDeserializeSubject>(
+	dataset: &IndexedBTreeDataset,
+	subject: &Id,
+	context: Context<()>,
+) -> Result<T, FromLinkedDataError> {
+	T::deserialize_subject_in(&(), &(), dataset, None, &Term::Id(subject.clone()), context)
+}
+
+/// Round-trip error.
+#[derive(Debug, thiserror::Error)]
+pub enum RoundtripError {
+	#[error("serialization failed: {0}")]
+	Serialize(#[from] IntoQuadsError),
+
+	#[error("deserialization failed: {0}")]
+	Deserialize(#[from] FromLinkedDataError),
+}
+
+/// Serializes the given subject into a dataset, then deserializes it back.
+///
+/// This is mostly useful to test that the serialization and deserialization
+/// of a type agree with each other.
+///
+/// ```
+/// # use linked_data_next::{Serialize, Deserialize};
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// #[ld(prefix("ex" = "http://example.org/"))]
+/// struct Foo {
+///   #[ld("ex:name")]
+///   name: String,
+/// }
+///
+/// let value = Foo { name: "John Smith".to_owned() };
+/// let result = linked_data_next::roundtrip(rdf_types::generator::Blank::new(), &value).unwrap();
+/// assert_eq!(value, result)
+/// ```
+pub fn roundtrip<G, T>(generator: G, value: &T) -> Result<T, RoundtripError>
+where
+	G: Generator,
+	T: LinkedDataSubject<WithGenerator<G>>
+		+ LinkedDataResource<WithGenerator<G>>
+		+ LinkedDataDeserializeSubject,
+{
+	let mut interpretation = interpretation::WithGenerator::new((), generator);
+	let (subject, quads) =
+		to_lexical_subject_quads_with(&mut (), &mut interpretation, None, value)?;
+
+	let mut dataset = IndexedBTreeDataset::new();
+	for quad in quads {
+		dataset.push(quad)?;
+	}
+
+	Ok(from_dataset(&dataset, &subject)?)
+}
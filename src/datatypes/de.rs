@@ -1,3 +1,8 @@
+use std::num::{
+	NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroU8, NonZeroU16, NonZeroU32,
+	NonZeroU64, NonZeroU128,
+};
+
 use rdf_types::{
 	Interpretation, RDF_LANG_STRING, Vocabulary,
 	interpretation::{ReverseIriInterpretation, ReverseLiteralInterpretation},
@@ -11,6 +16,15 @@ use crate::{
 
 macro_rules! deserialize_datatype {
 	($ty:ty, $iri:ident, $($extra_type:expr)*) => {
+		deserialize_datatype!(@impl $ty, $iri, str::parse, no_selection, $($extra_type)*);
+	};
+	(xsd $ty:ty, $iri:ident, $($extra_type:expr)*) => {
+		deserialize_datatype!(@impl $ty, $iri, xsd_types::ParseXsd::parse_xsd, no_selection, $($extra_type)*);
+	};
+	(lang $ty:ty, $iri:ident, $($extra_type:expr)*) => {
+		deserialize_datatype!(@impl $ty, $iri, str::parse, select_language, $($extra_type)*);
+	};
+	(@impl $ty:ty, $iri:ident, $parse:path, $select:path, $($extra_type:expr)*) => {
 		impl<V: Vocabulary, I: Interpretation> LinkedDataDeserializeSubject<I, V> for $ty
 		where
 			V: LiteralVocabulary,
@@ -30,25 +44,27 @@ macro_rules! deserialize_datatype {
 				let mut literal_ty = None;
 				for l in interpretation.literals_of(resource) {
 					let l = vocabulary.literal(l).unwrap();
-					match l.type_ {
+					let accepted = match l.type_ {
 						rdf_types::LiteralTypeRef::Any(ty_iri) => {
 							let ty_iri = vocabulary.iri(ty_iri).unwrap();
-							if ty_iri == xsd_types::$iri $(|| ty_iri == $extra_type)* {
-								return match l.value.parse() {
-									Ok(value) => Ok(value),
-									Err(_) => Err(FromLinkedDataError::InvalidLiteral(
-										context.into_iris(
-											vocabulary,
-											interpretation
-										)
-									))
-								}
-							}
-
-							literal_ty = Some(ty_iri)
+							literal_ty = Some(ty_iri);
+							context.coercion.accepts(ty_iri, xsd_types::$iri, &[$($extra_type),*])
 						}
 						rdf_types::LiteralTypeRef::LangString(_) => {
-							literal_ty = Some(RDF_LANG_STRING)
+							literal_ty = Some(RDF_LANG_STRING);
+							context.coercion.accepts_lang_string()
+						}
+					};
+
+					if accepted {
+						return match $parse(l.value) {
+							Ok(value) => Ok(value),
+							Err(_) => Err(FromLinkedDataError::InvalidLiteral(
+								context.into_iris(
+									vocabulary,
+									interpretation
+								)
+							))
 						}
 					}
 				}
@@ -87,6 +103,11 @@ macro_rules! deserialize_datatype {
 				<I as Interpretation>::Resource: 'a,
 				D: rdf_types::dataset::PatternMatchingDataset<Resource = I::Resource>
 			{
+				let mut objects = objects.into_iter();
+				if let Some(result) = $select(vocabulary, interpretation, &mut objects, context) {
+					return result;
+				}
+
 				let mut error = None;
 
 				for o in objects {
@@ -106,6 +127,39 @@ macro_rules! deserialize_datatype {
 	};
 }
 
+/// Default objects selection, taking the first accepted object.
+fn no_selection<'a, T, I: Interpretation, V>(
+	_vocabulary: &V,
+	_interpretation: &I,
+	_objects: impl IntoIterator<Item = &'a I::Resource>,
+	_context: Context<I>,
+) -> Option<Result<T, FromLinkedDataError>>
+where
+	I::Resource: 'a,
+{
+	None
+}
+
+/// Selects a string according to the language preference of the context, if
+/// any.
+fn select_language<'a, I, V>(
+	vocabulary: &V,
+	interpretation: &I,
+	objects: impl IntoIterator<Item = &'a I::Resource>,
+	context: Context<I>,
+) -> Option<Result<String, FromLinkedDataError>>
+where
+	I: Interpretation
+		+ ReverseIriInterpretation<Iri = V::Iri>
+		+ ReverseLiteralInterpretation<Literal = V::Literal>,
+	I::Resource: 'a,
+	V: Vocabulary + LiteralVocabulary,
+{
+	context.language.map(|language| {
+		language.deserialize_objects_in(vocabulary, interpretation, objects, context)
+	})
+}
+
 deserialize_datatype!(bool, XSD_BOOLEAN,);
 deserialize_datatype!(u8, XSD_UNSIGNED_BYTE, xsd_types::XSD_INTEGER);
 deserialize_datatype!(u16, XSD_UNSIGNED_SHORT, xsd_types::XSD_INTEGER);
@@ -115,7 +169,40 @@ deserialize_datatype!(i8, XSD_BYTE, xsd_types::XSD_INTEGER);
 deserialize_datatype!(i16, XSD_SHORT, xsd_types::XSD_INTEGER);
 deserialize_datatype!(i32, XSD_INT, xsd_types::XSD_INTEGER);
 deserialize_datatype!(i64, XSD_LONG, xsd_types::XSD_INTEGER);
-deserialize_datatype!(f32, XSD_FLOAT, xsd_types::XSD_DECIMAL);
-deserialize_datatype!(f64, XSD_DOUBLE, xsd_types::XSD_DECIMAL);
-deserialize_datatype!(String, XSD_STRING,);
+deserialize_datatype!(f32, XSD_FLOAT, xsd_types::XSD_DOUBLE xsd_types::XSD_DECIMAL);
+deserialize_datatype!(f64, XSD_DOUBLE, xsd_types::XSD_FLOAT xsd_types::XSD_DECIMAL);
+deserialize_datatype!(lang String, XSD_STRING,);
 deserialize_datatype!(xsd_types::DateTime, XSD_DATE_TIME,);
+deserialize_datatype!(xsd xsd_types::Date, XSD_DATE,);
+deserialize_datatype!(xsd xsd_types::Time, XSD_TIME,);
+deserialize_datatype!(xsd xsd_types::Duration, XSD_DURATION,);
+deserialize_datatype!(xsd xsd_types::Decimal, XSD_DECIMAL,);
+deserialize_datatype!(xsd xsd_types::Integer, XSD_INTEGER,);
+deserialize_datatype!(xsd xsd_types::NonNegativeInteger, XSD_NON_NEGATIVE_INTEGER, xsd_types::XSD_INTEGER);
+deserialize_datatype!(xsd xsd_types::GYear, XSD_G_YEAR,);
+deserialize_datatype!(xsd xsd_types::GYearMonth, XSD_G_YEAR_MONTH,);
+deserialize_datatype!(xsd xsd_types::GMonthDay, XSD_G_MONTH_DAY,);
+deserialize_datatype!(xsd xsd_types::GMonth, XSD_G_MONTH,);
+deserialize_datatype!(xsd xsd_types::GDay, XSD_G_DAY,);
+deserialize_datatype!(xsd xsd_types::Base64BinaryBuf, XSD_BASE64_BINARY,);
+deserialize_datatype!(xsd xsd_types::HexBinaryBuf, XSD_HEX_BINARY,);
+deserialize_datatype!(xsd xsd_types::LanguageBuf, XSD_LANGUAGE,);
+deserialize_datatype!(xsd xsd_types::TokenBuf, XSD_TOKEN,);
+deserialize_datatype!(xsd xsd_types::AnyUriBuf, XSD_ANY_URI,);
+deserialize_datatype!(u128, XSD_NON_NEGATIVE_INTEGER, xsd_types::XSD_INTEGER);
+deserialize_datatype!(i128, XSD_INTEGER,);
+deserialize_datatype!(NonZeroU8, XSD_UNSIGNED_BYTE, xsd_types::XSD_INTEGER);
+deserialize_datatype!(NonZeroU16, XSD_UNSIGNED_SHORT, xsd_types::XSD_INTEGER);
+deserialize_datatype!(NonZeroU32, XSD_UNSIGNED_INT, xsd_types::XSD_INTEGER);
+deserialize_datatype!(NonZeroU64, XSD_UNSIGNED_LONG, xsd_types::XSD_INTEGER);
+deserialize_datatype!(
+	NonZeroU128,
+	XSD_NON_NEGATIVE_INTEGER,
+	xsd_types::XSD_INTEGER
+);
+deserialize_datatype!(NonZeroI8, XSD_BYTE, xsd_types::XSD_INTEGER);
+deserialize_datatype!(NonZeroI16, XSD_SHORT, xsd_types::XSD_INTEGER);
+deserialize_datatype!(NonZeroI32, XSD_INT, xsd_types::XSD_INTEGER);
+deserialize_datatype!(NonZeroI64, XSD_LONG, xsd_types::XSD_INTEGER);
+deserialize_datatype!(NonZeroI128, XSD_INTEGER,);
+deserialize_datatype!(char, XSD_STRING,);
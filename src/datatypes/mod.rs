@@ -1,2 +1,5 @@
 mod de;
 mod ser;
+mod typed;
+
+pub use typed::*;
@@ -1,3 +1,8 @@
+use std::num::{
+	NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroU8, NonZeroU16, NonZeroU32,
+	NonZeroU64, NonZeroU128,
+};
+
 use rdf_types::{
 	Id, Interpretation, Term, Vocabulary,
 	vocabulary::{IriVocabularyMut, LiteralVocabularyMut},
@@ -10,6 +15,9 @@ use crate::{
 
 macro_rules! datatype {
 	($ty:ty, $variant:ident) => {
+		datatype!($ty, $variant, |value: &$ty| value.clone().into());
+	};
+	($ty:ty, $variant:ident, $into:expr) => {
 		impl<V: Vocabulary + IriVocabularyMut + LiteralVocabularyMut, I: Interpretation>
 			LinkedDataResource<I, V> for $ty
 		{
@@ -19,7 +27,7 @@ macro_rules! datatype {
 				_interpretation: &mut I,
 			) -> ResourceInterpretation<'_, I, V> {
 				ResourceInterpretation::Uninterpreted(Some(CowRdfTerm::Owned(Term::Literal(
-					RdfLiteral::Xsd(xsd_types::Value::$variant(self.clone().into())),
+					RdfLiteral::Xsd(xsd_types::Value::$variant(($into)(self))),
 				))))
 			}
 		}
@@ -99,6 +107,50 @@ datatype!(f32, Float);
 datatype!(f64, Double);
 datatype!(String, String);
 datatype!(xsd_types::DateTime, DateTime);
+datatype!(xsd_types::Date, Date);
+datatype!(xsd_types::Time, Time);
+datatype!(xsd_types::Duration, Duration);
+datatype!(xsd_types::Decimal, Decimal);
+datatype!(xsd_types::Integer, Integer);
+datatype!(xsd_types::NonNegativeInteger, NonNegativeInteger);
+datatype!(xsd_types::GYear, GYear);
+datatype!(xsd_types::GYearMonth, GYearMonth);
+datatype!(xsd_types::GMonthDay, GMonthDay);
+datatype!(xsd_types::GMonth, GMonth);
+datatype!(xsd_types::GDay, GDay);
+datatype!(xsd_types::Base64BinaryBuf, Base64Binary);
+datatype!(xsd_types::HexBinaryBuf, HexBinary);
+datatype!(xsd_types::LanguageBuf, Language);
+datatype!(xsd_types::TokenBuf, Token);
+
+// `xsd_types` has no conversion from 128-bit integers, so they go through
+// their lexical representation.
+datatype!(u128, NonNegativeInteger, |value: &u128| value
+	.to_string()
+	.parse()
+	.unwrap());
+datatype!(i128, Integer, |value: &i128| value
+	.to_string()
+	.parse()
+	.unwrap());
+
+datatype!(NonZeroU8, UnsignedByte, |value: &NonZeroU8| value.get());
+datatype!(NonZeroU16, UnsignedShort, |value: &NonZeroU16| value.get());
+datatype!(NonZeroU32, UnsignedInt, |value: &NonZeroU32| value.get());
+datatype!(NonZeroU64, UnsignedLong, |value: &NonZeroU64| value.get());
+datatype!(NonZeroI8, Byte, |value: &NonZeroI8| value.get());
+datatype!(NonZeroI16, Short, |value: &NonZeroI16| value.get());
+datatype!(NonZeroI32, Int, |value: &NonZeroI32| value.get());
+datatype!(NonZeroI64, Long, |value: &NonZeroI64| value.get());
+datatype!(NonZeroU128, NonNegativeInteger, |value: &NonZeroU128| value
+	.to_string()
+	.parse()
+	.unwrap());
+datatype!(NonZeroI128, Integer, |value: &NonZeroI128| value
+	.to_string()
+	.parse()
+	.unwrap());
+datatype!(char, String, |value: &char| value.to_string());
 
 unsized_datatype! {
 	str: String
@@ -0,0 +1,242 @@
+use std::{fmt, str::FromStr};
+
+use iref::Iri;
+use rdf_types::{
+	Interpretation, LiteralType, LiteralTypeRef, RDF_LANG_STRING, Term, Vocabulary,
+	interpretation::{ReverseIriInterpretation, ReverseLiteralInterpretation},
+	vocabulary::{IriVocabularyMut, LiteralVocabulary},
+};
+
+use crate::{
+	Context, CowRdfTerm, FromLinkedDataError, LinkedDataPredicateObjects, LinkedDataResource,
+	LinkedDataSubject, PredicateObjectsVisitor, RdfLiteral, ResourceInterpretation, SubjectVisitor,
+};
+
+/// Literal of an arbitrary datatype, whose lexical form is given by the
+/// [`Display`](fmt::Display) implementation of the value.
+pub struct TypedLiteral<'a, T: ?Sized> {
+	pub value: &'a T,
+	pub datatype: &'a Iri,
+}
+
+impl<'a, T: ?Sized> TypedLiteral<'a, T> {
+	pub fn new(value: &'a T, datatype: &'a Iri) -> Self {
+		Self { value, datatype }
+	}
+}
+
+impl<I: Interpretation, V: Vocabulary + IriVocabularyMut, T: ?Sized + fmt::Display>
+	LinkedDataResource<I, V> for TypedLiteral<'_, T>
+{
+	fn interpretation(
+		&self,
+		vocabulary: &mut V,
+		_interpretation: &mut I,
+	) -> ResourceInterpretation<'_, I, V> {
+		ResourceInterpretation::Uninterpreted(Some(CowRdfTerm::Owned(Term::Literal(
+			RdfLiteral::Any(
+				self.value.to_string(),
+				LiteralType::Any(vocabulary.insert(self.datatype)),
+			),
+		))))
+	}
+}
+
+impl<I: Interpretation, V: Vocabulary + IriVocabularyMut, T: ?Sized + fmt::Display>
+	LinkedDataSubject<I, V> for TypedLiteral<'_, T>
+{
+	fn visit_subject<S>(&self, visitor: S) -> Result<S::Ok, S::Error>
+	where
+		S: SubjectVisitor<I, V>,
+	{
+		visitor.end()
+	}
+}
+
+impl<I: Interpretation, V: Vocabulary + IriVocabularyMut, T: ?Sized + fmt::Display>
+	LinkedDataPredicateObjects<I, V> for TypedLiteral<'_, T>
+{
+	fn visit_objects<S>(&self, mut visitor: S) -> Result<S::Ok, S::Error>
+	where
+		S: PredicateObjectsVisitor<I, V>,
+	{
+		visitor.object(self)?;
+		visitor.end()
+	}
+}
+
+/// Literals of an arbitrary datatype, one object per item of the given
+/// iterator.
+pub struct TypedLiterals<'a, J> {
+	pub values: J,
+	pub datatype: &'a Iri,
+}
+
+impl<'a, J> TypedLiterals<'a, J> {
+	pub fn new(values: J, datatype: &'a Iri) -> Self {
+		Self { values, datatype }
+	}
+}
+
+impl<'a, I: Interpretation, V: Vocabulary + IriVocabularyMut, T: 'a + ?Sized + fmt::Display, J>
+	LinkedDataPredicateObjects<I, V> for TypedLiterals<'_, J>
+where
+	J: Clone + Iterator<Item = &'a T>,
+{
+	fn visit_objects<S>(&self, mut visitor: S) -> Result<S::Ok, S::Error>
+	where
+		S: PredicateObjectsVisitor<I, V>,
+	{
+		for value in self.values.clone() {
+			visitor.object(&TypedLiteral::new(value, self.datatype))?;
+		}
+
+		visitor.end()
+	}
+}
+
+/// Reads literals of an arbitrary datatype, parsing their lexical form with
+/// [`FromStr`].
+///
+/// The datatype of each literal is checked according to the
+/// [`Coercion`](crate::Coercion) policy of the deserialization context.
+#[derive(Debug, Clone, Copy)]
+pub struct TypedLiteralReader<'a> {
+	pub datatype: &'a Iri,
+}
+
+impl<'a> TypedLiteralReader<'a> {
+	pub fn new(datatype: &'a Iri) -> Self {
+		Self { datatype }
+	}
+
+	/// Reads the given resource as a literal of the expected datatype.
+	pub fn read<I, V, T>(
+		&self,
+		vocabulary: &V,
+		interpretation: &I,
+		resource: &I::Resource,
+		context: Context<I>,
+	) -> Result<T, FromLinkedDataError>
+	where
+		I: Interpretation
+			+ ReverseIriInterpretation<Iri = V::Iri>
+			+ ReverseLiteralInterpretation<Literal = V::Literal>,
+		V: Vocabulary + LiteralVocabulary,
+		T: FromStr,
+	{
+		let mut literal_ty = None;
+		for l in interpretation.literals_of(resource) {
+			let l = vocabulary.literal(l).unwrap();
+			let accepted = match l.type_ {
+				LiteralTypeRef::Any(ty) => {
+					let ty = vocabulary.iri(ty).unwrap();
+					literal_ty = Some(ty);
+					context.coercion.accepts(ty, self.datatype, &[])
+				}
+				LiteralTypeRef::LangString(_) => {
+					literal_ty = Some(RDF_LANG_STRING);
+					context.coercion.accepts_lang_string()
+				}
+			};
+
+			if accepted {
+				return l.value.parse().map_err(|_| {
+					FromLinkedDataError::InvalidLiteral(
+						context.into_iris(vocabulary, interpretation),
+					)
+				});
+			}
+		}
+
+		match literal_ty {
+			Some(ty) => Err(FromLinkedDataError::LiteralTypeMismatch {
+				context: context.into_iris(vocabulary, interpretation),
+				expected: Some(self.datatype.to_owned()),
+				found: ty.to_owned(),
+			}),
+			None => Err(FromLinkedDataError::ExpectedLiteral(
+				context.into_iris(vocabulary, interpretation),
+			)),
+		}
+	}
+
+	/// Reads the single literal among the given objects.
+	pub fn read_one<'r, I, V, T>(
+		&self,
+		vocabulary: &V,
+		interpretation: &I,
+		objects: impl IntoIterator<Item = &'r I::Resource>,
+		context: Context<I>,
+	) -> Result<T, FromLinkedDataError>
+	where
+		I: Interpretation
+			+ ReverseIriInterpretation<Iri = V::Iri>
+			+ ReverseLiteralInterpretation<Literal = V::Literal>,
+		I::Resource: 'r,
+		V: Vocabulary + LiteralVocabulary,
+		T: FromStr,
+	{
+		match self.read_optional(vocabulary, interpretation, objects, context)? {
+			Some(value) => Ok(value),
+			None => Err(FromLinkedDataError::MissingRequiredValue(
+				context.into_iris(vocabulary, interpretation),
+			)),
+		}
+	}
+
+	/// Reads the literal among the given objects, if any.
+	pub fn read_optional<'r, I, V, T>(
+		&self,
+		vocabulary: &V,
+		interpretation: &I,
+		objects: impl IntoIterator<Item = &'r I::Resource>,
+		context: Context<I>,
+	) -> Result<Option<T>, FromLinkedDataError>
+	where
+		I: Interpretation
+			+ ReverseIriInterpretation<Iri = V::Iri>
+			+ ReverseLiteralInterpretation<Literal = V::Literal>,
+		I::Resource: 'r,
+		V: Vocabulary + LiteralVocabulary,
+		T: FromStr,
+	{
+		let mut objects = objects.into_iter();
+		match objects.next() {
+			Some(object) => {
+				if objects.next().is_none() {
+					self.read(vocabulary, interpretation, object, context)
+						.map(Some)
+				} else {
+					Err(FromLinkedDataError::TooManyValues(
+						context.into_iris(vocabulary, interpretation),
+					))
+				}
+			}
+			None => Ok(None),
+		}
+	}
+
+	/// Reads every literal among the given objects.
+	pub fn read_all<'r, I, V, T, C>(
+		&self,
+		vocabulary: &V,
+		interpretation: &I,
+		objects: impl IntoIterator<Item = &'r I::Resource>,
+		context: Context<I>,
+	) -> Result<C, FromLinkedDataError>
+	where
+		I: Interpretation
+			+ ReverseIriInterpretation<Iri = V::Iri>
+			+ ReverseLiteralInterpretation<Literal = V::Literal>,
+		I::Resource: 'r,
+		V: Vocabulary + LiteralVocabulary,
+		T: FromStr,
+		C: FromIterator<T>,
+	{
+		objects
+			.into_iter()
+			.map(|object| self.read(vocabulary, interpretation, object, context))
+			.collect()
+	}
+}
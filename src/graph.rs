@@ -1,7 +1,15 @@
+use std::collections::BTreeSet;
+
 use iref::{Iri, IriBuf};
-use rdf_types::{Interpretation, Vocabulary};
+use rdf_types::{
+	Interpretation, Quad, Vocabulary, dataset::PatternMatchingDataset,
+	interpretation::ReverseIriInterpretation, pattern::CanonicalQuadPattern,
+};
 
-use crate::{LinkedData, LinkedDataPredicateObjects, LinkedDataResource, LinkedDataSubject};
+use crate::{
+	Context, FromLinkedDataError, LinkedData, LinkedDataDeserializeSubject,
+	LinkedDataPredicateObjects, LinkedDataResource, LinkedDataSubject,
+};
 
 // use crate::SerializeSubject;
 
@@ -116,6 +124,201 @@ impl<I: Interpretation, V: Vocabulary, S: GraphVisitor<I, V>> GraphVisitor<I, V>
 	}
 }
 
+/// Deserialize a Linked-Data graph.
+pub trait LinkedDataDeserializeGraph<I: Interpretation = (), V: Vocabulary = ()>: Sized {
+	fn deserialize_graph_in<D>(
+		vocabulary: &V,
+		interpretation: &I,
+		dataset: &D,
+		graph: Option<&I::Resource>,
+		context: Context<I>,
+	) -> Result<Self, FromLinkedDataError>
+	where
+		D: PatternMatchingDataset<Resource = I::Resource>;
+
+	fn deserialize_graph<D>(
+		vocabulary: &V,
+		interpretation: &I,
+		dataset: &D,
+		graph: Option<&I::Resource>,
+	) -> Result<Self, FromLinkedDataError>
+	where
+		D: PatternMatchingDataset<Resource = I::Resource>,
+	{
+		Self::deserialize_graph_in(
+			vocabulary,
+			interpretation,
+			dataset,
+			graph,
+			Context::default(),
+		)
+	}
+}
+
+/// Returns the distinct subjects and objects of the given graph.
+fn graph_nodes<'a, D>(
+	dataset: &'a D,
+	graph: Option<&'a D::Resource>,
+) -> (BTreeSet<&'a D::Resource>, BTreeSet<&'a D::Resource>)
+where
+	D: PatternMatchingDataset,
+	D::Resource: Ord,
+{
+	let mut subjects = BTreeSet::new();
+	let mut objects = BTreeSet::new();
+
+	let quads = dataset.quad_pattern_matching(CanonicalQuadPattern::from_option_quad(Quad(
+		None,
+		None,
+		None,
+		Some(graph),
+	)));
+
+	for Quad(subject, _, object, _) in quads {
+		subjects.insert(subject);
+		objects.insert(object);
+	}
+
+	(subjects, objects)
+}
+
+/// Deserializes a graph serialized from a single subject, from its root
+/// subject: the only subject of the graph that is not also an object in it.
+///
+/// This is how derived types implement [`LinkedDataDeserializeGraph`].
+pub fn deserialize_root_subject_in<I, V, D, T>(
+	vocabulary: &V,
+	interpretation: &I,
+	dataset: &D,
+	graph: Option<&I::Resource>,
+	context: Context<I>,
+) -> Result<T, FromLinkedDataError>
+where
+	I: Interpretation + ReverseIriInterpretation<Iri = V::Iri>,
+	I::Resource: Ord,
+	V: Vocabulary,
+	D: PatternMatchingDataset<Resource = I::Resource>,
+	T: LinkedDataDeserializeSubject<I, V>,
+{
+	let (subjects, objects) = graph_nodes(dataset, graph);
+	if subjects.is_empty() {
+		return Err(FromLinkedDataError::EmptyGraph(
+			context.into_iris(vocabulary, interpretation),
+		));
+	}
+
+	let mut roots = subjects.difference(&objects);
+	match (roots.next(), roots.next()) {
+		(Some(subject), None) => {
+			T::deserialize_subject_in(vocabulary, interpretation, dataset, graph, subject, context)
+		}
+		(None, _) => Err(FromLinkedDataError::NoRootSubject(
+			context.into_iris(vocabulary, interpretation),
+		)),
+		(Some(_), Some(_)) => Err(FromLinkedDataError::TooManyValues(
+			context.into_iris(vocabulary, interpretation),
+		)),
+	}
+}
+
+impl<I: Interpretation, V: Vocabulary, T: LinkedDataDeserializeGraph<I, V>>
+	LinkedDataDeserializeGraph<I, V> for Box<T>
+{
+	fn deserialize_graph_in<D>(
+		vocabulary: &V,
+		interpretation: &I,
+		dataset: &D,
+		graph: Option<&I::Resource>,
+		context: Context<I>,
+	) -> Result<Self, FromLinkedDataError>
+	where
+		D: PatternMatchingDataset<Resource = I::Resource>,
+	{
+		T::deserialize_graph_in(vocabulary, interpretation, dataset, graph, context).map(Box::new)
+	}
+}
+
+/// Every subject of the graph is deserialized as an item, except the
+/// resources without IRI that are the object of another quad: those are the
+/// anonymous values of other nodes. Items may reference each other. An empty
+/// graph gives an empty vector.
+impl<I, V: Vocabulary, T: LinkedDataDeserializeSubject<I, V>> LinkedDataDeserializeGraph<I, V>
+	for Vec<T>
+where
+	I: Interpretation + ReverseIriInterpretation<Iri = V::Iri>,
+	I::Resource: Ord,
+{
+	fn deserialize_graph_in<D>(
+		vocabulary: &V,
+		interpretation: &I,
+		dataset: &D,
+		graph: Option<&I::Resource>,
+		context: Context<I>,
+	) -> Result<Self, FromLinkedDataError>
+	where
+		D: PatternMatchingDataset<Resource = I::Resource>,
+	{
+		let (subjects, objects) = graph_nodes(dataset, graph);
+
+		subjects
+			.into_iter()
+			.filter(|subject| {
+				!objects.contains(subject) || interpretation.iris_of(subject).next().is_some()
+			})
+			.map(|subject| {
+				T::deserialize_subject_in(
+					vocabulary,
+					interpretation,
+					dataset,
+					graph,
+					subject,
+					context,
+				)
+			})
+			.collect()
+	}
+}
+
+/// Graph with a name.
+///
+/// The name `R` is the graph resource, and `T` its content.
+pub struct NamedGraph<R, T>(pub R, pub T);
+
+impl<I: Interpretation, V: Vocabulary, R: LinkedDataResource<I, V>, T> LinkedDataResource<I, V>
+	for NamedGraph<R, T>
+{
+	fn interpretation(
+		&self,
+		vocabulary: &mut V,
+		interpretation: &mut I,
+	) -> crate::ResourceInterpretation<'_, I, V> {
+		self.0.interpretation(vocabulary, interpretation)
+	}
+}
+
+impl<I: Interpretation, V: Vocabulary, R, T: LinkedDataGraph<I, V>> LinkedDataGraph<I, V>
+	for NamedGraph<R, T>
+{
+	fn visit_graph<S>(&self, visitor: S) -> Result<S::Ok, S::Error>
+	where
+		S: GraphVisitor<I, V>,
+	{
+		T::visit_graph(&self.1, visitor)
+	}
+}
+
+impl<I: Interpretation, V: Vocabulary, R: LinkedDataResource<I, V>, T: LinkedDataGraph<I, V>>
+	LinkedData<I, V> for NamedGraph<R, T>
+{
+	fn visit<S>(&self, mut visitor: S) -> Result<S::Ok, S::Error>
+	where
+		S: crate::Visitor<I, V>,
+	{
+		visitor.named_graph(self)?;
+		visitor.end()
+	}
+}
+
 pub struct AnonymousGraph<T>(pub T);
 
 impl<I: Interpretation, V: Vocabulary, T> LinkedDataResource<I, V> for AnonymousGraph<T> {
@@ -0,0 +1,457 @@
+//! JSON-LD serialization.
+use iref::IriBuf;
+use json_syntax::{Object, Value};
+use rdf_types::{Id, LiteralType, RDF_FIRST, RDF_NIL, RDF_REST, RDF_TYPE, Term, XSD_STRING};
+
+use crate::{
+	GraphVisitor, LinkedData, LinkedDataGraph, LinkedDataPredicateObjects, LinkedDataResource,
+	LinkedDataSubject, PredicateObjectsVisitor, RdfLiteral, RdfTerm, SubjectVisitor, Visitor,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum IntoJsonLdError {
+	#[error("invalid graph label")]
+	Graph,
+
+	#[error("invalid subject")]
+	Subject,
+
+	#[error("invalid predicate")]
+	Predicate,
+}
+
+/// Type with a JSON-LD context.
+///
+/// The context maps the type and field names to their IRIs, so that the
+/// expanded output of [`to_json_ld_expanded`] can be compacted back to
+/// something looking like the original type. It can be derived with
+/// `#[derive(JsonLdContext)]`, using the same `ld` attributes as
+/// `Serialize`:
+///
+/// - `#[ld(id)]` fields are mapped to `@id`,
+/// - `Vec<T>` fields use the `@list` container,
+/// - `HashSet<T>`, `BTreeSet<T>` and slice fields use the `@set` container,
+/// - `#[ld(graph)]` fields use the `@graph` container,
+/// - [`LangMap`](crate::LangMap) fields use the `@language` container,
+/// - `#[ld(reverse = "...")]` fields are mapped to `@reverse` definitions,
+/// - `#[ld(include)]` and `#[ld(include = "...")]` fields are mapped to
+///   `@included`,
+/// - `#[ld(datatype = "...")]` fields are given the matching `@type`,
+/// - `#[ld(flatten)]` fields merge the context of the flattened type,
+/// - a type-level `#[ld(vocab = "...")]` is exposed as `@vocab`.
+///
+/// Containers are guessed from the last segment of the field type path,
+/// looking through `Option`, `Box`, `Rc`, `Arc` and references. Type aliases
+/// and user types are not resolved, so a field whose type is not spelled
+/// like one of the above should use `#[ld(set)]` or `#[ld(list)]` to get a
+/// container. Fields with `with` or `serialize_with` have no container
+/// unless one of these is given.
+///
+/// ```
+/// # use linked_data_next::{JsonLdContext, Serialize};
+/// #[derive(Serialize, JsonLdContext)]
+/// #[ld(prefix("ex" = "http://example.org/"))]
+/// struct Foo {
+///   #[ld("ex:tags")]
+///   tags: Vec<String>,
+/// }
+///
+/// let context = Foo::json_ld_context();
+/// assert_eq!(
+///   context.as_object().unwrap().get_unique("tags").unwrap().unwrap(),
+///   &json_syntax::json!({ "@id": "http://example.org/tags", "@container": "@list" })
+/// );
+/// ```
+pub trait JsonLdContext {
+	/// Returns the JSON-LD `@context` value for this type.
+	fn json_ld_context() -> Value;
+}
+
+/// Serializes the given value into an expanded JSON-LD document.
+///
+/// Resources without lexical representation are written as node objects
+/// without `@id`, and `rdf:List` chains made of such nodes are written as
+/// `@list` objects, except under `@reverse`. Named graphs must have an
+/// identifier, and the values of reverse properties cannot be literals.
+pub fn to_json_ld_expanded(value: &impl LinkedData) -> Result<Value, IntoJsonLdError> {
+	value.visit(JsonLdSerializer { nodes: Vec::new() })
+}
+
+fn lexical_representation<T: ?Sized + LinkedDataResource>(value: &T) -> Option<RdfTerm<()>> {
+	value
+		.lexical_representation(&mut (), &mut ())
+		.map(|term| term.into_owned())
+}
+
+fn node_id(
+	term: Option<RdfTerm<()>>,
+	error: IntoJsonLdError,
+) -> Result<Option<Id>, IntoJsonLdError> {
+	match term {
+		Some(Term::Id(id)) => Ok(Some(id)),
+		Some(Term::Literal(_)) => Err(error),
+		None => Ok(None),
+	}
+}
+
+fn id_string(id: Id) -> String {
+	match id {
+		Id::Iri(iri) => iri.into_string(),
+		Id::Blank(b) => b.to_string(),
+	}
+}
+
+fn value_object(literal: RdfLiteral<()>) -> Value {
+	let mut object = Object::new();
+
+	match literal {
+		RdfLiteral::Json(value) => {
+			object.push("@value".into(), value);
+			object.push("@type".into(), Value::String("@json".into()));
+		}
+		literal => {
+			let literal = literal.into_lexical(&());
+			object.push("@value".into(), Value::String(literal.value.into()));
+			match literal.type_ {
+				LiteralType::Any(ty) if ty == XSD_STRING => (),
+				LiteralType::Any(ty) => {
+					object.push("@type".into(), Value::String(ty.as_str().into()));
+				}
+				LiteralType::LangString(tag) => {
+					object.push("@language".into(), Value::String(tag.as_str().into()));
+				}
+			}
+		}
+	}
+
+	Value::Object(object)
+}
+
+fn node_object<T>(value: &T) -> Result<Node, IntoJsonLdError>
+where
+	T: ?Sized + LinkedDataResource + LinkedDataSubject,
+{
+	let id = node_id(lexical_representation(value), IntoJsonLdError::Subject)?;
+	value.visit_subject(NodeSerializer {
+		node: Node::new(id),
+	})
+}
+
+fn push_values(entries: &mut Vec<(String, Vec<Value>)>, key: String, values: Vec<Value>) {
+	match entries.iter_mut().find(|(k, _)| *k == key) {
+		Some((_, entry)) => entry.extend(values),
+		None => entries.push((key, values)),
+	}
+}
+
+fn entries_object(entries: Vec<(String, Vec<Value>)>) -> Object {
+	entries
+		.into_iter()
+		.map(|(key, values)| (key.into(), Value::Array(values)))
+		.collect()
+}
+
+/// Expanded node object.
+struct Node {
+	id: Option<Id>,
+	types: Vec<String>,
+	properties: Vec<(String, Vec<Value>)>,
+	reverse_properties: Vec<(String, Vec<Value>)>,
+	graph: Option<Vec<Value>>,
+	included: Vec<Value>,
+}
+
+impl Node {
+	fn new(id: Option<Id>) -> Self {
+		Self {
+			id,
+			types: Vec::new(),
+			properties: Vec::new(),
+			reverse_properties: Vec::new(),
+			graph: None,
+			included: Vec::new(),
+		}
+	}
+
+	/// Turns this node into a `@list` object if it is an anonymous `rdf:List`
+	/// node.
+	fn as_list(&self) -> Option<Value> {
+		let is_list_node = self.id.is_none()
+			&& self.types.is_empty()
+			&& self.reverse_properties.is_empty()
+			&& self.graph.is_none()
+			&& self.included.is_empty()
+			&& self.properties.len() == 2;
+
+		if !is_list_node {
+			return None;
+		}
+
+		let first = self
+			.properties
+			.iter()
+			.find(|(key, values)| key == RDF_FIRST.as_str() && values.len() == 1);
+		let rest = self
+			.properties
+			.iter()
+			.find(|(key, values)| key == RDF_REST.as_str() && values.len() == 1)
+			.and_then(|(_, values)| values[0].as_object()?.get_unique("@list").ok()?)
+			.and_then(Value::as_array);
+
+		match (first, rest) {
+			(Some((_, first)), Some(rest)) => {
+				let mut items = first.clone();
+				items.extend(rest.iter().cloned());
+
+				let mut object = Object::new();
+				object.push("@list".into(), Value::Array(items));
+				Some(Value::Object(object))
+			}
+			_ => None,
+		}
+	}
+
+	fn into_json(self) -> Value {
+		let mut object = Object::new();
+
+		if let Some(id) = self.id {
+			object.push("@id".into(), Value::String(id_string(id).into()));
+		}
+
+		if !self.types.is_empty() {
+			object.push(
+				"@type".into(),
+				Value::Array(
+					self.types
+						.into_iter()
+						.map(|ty| Value::String(ty.into()))
+						.collect(),
+				),
+			);
+		}
+
+		for (key, values) in self.properties {
+			object.push(key.into(), Value::Array(values));
+		}
+
+		if !self.reverse_properties.is_empty() {
+			object.push(
+				"@reverse".into(),
+				Value::Object(entries_object(self.reverse_properties)),
+			);
+		}
+
+		if let Some(graph) = self.graph {
+			object.push("@graph".into(), Value::Array(graph));
+		}
+
+		if !self.included.is_empty() {
+			object.push("@included".into(), Value::Array(self.included));
+		}
+
+		Value::Object(object)
+	}
+}
+
+struct JsonLdSerializer {
+	nodes: Vec<Value>,
+}
+
+impl Visitor for JsonLdSerializer {
+	type Ok = Value;
+	type Error = IntoJsonLdError;
+
+	fn default_graph<T>(&mut self, value: &T) -> Result<(), Self::Error>
+	where
+		T: ?Sized + LinkedDataGraph<(), ()>,
+	{
+		value.visit_graph(GraphSerializer {
+			nodes: &mut self.nodes,
+		})
+	}
+
+	fn named_graph<T>(&mut self, value: &T) -> Result<(), Self::Error>
+	where
+		T: ?Sized + LinkedDataResource + LinkedDataGraph<(), ()>,
+	{
+		// A named graph without identifier would be read back as part of the
+		// default graph.
+		let id = node_id(lexical_representation(value), IntoJsonLdError::Graph)?
+			.ok_or(IntoJsonLdError::Graph)?;
+		let mut node = Node::new(Some(id));
+		let mut graph = Vec::new();
+		value.visit_graph(GraphSerializer { nodes: &mut graph })?;
+		node.graph = Some(graph);
+		self.nodes.push(node.into_json());
+		Ok(())
+	}
+
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		Ok(Value::Array(self.nodes))
+	}
+}
+
+struct GraphSerializer<'a> {
+	nodes: &'a mut Vec<Value>,
+}
+
+impl GraphVisitor<(), ()> for GraphSerializer<'_> {
+	type Ok = ();
+	type Error = IntoJsonLdError;
+
+	fn subject<T>(&mut self, value: &T) -> Result<(), Self::Error>
+	where
+		T: ?Sized + LinkedDataResource + LinkedDataSubject,
+	{
+		self.nodes.push(node_object(value)?.into_json());
+		Ok(())
+	}
+
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		Ok(())
+	}
+}
+
+struct NodeSerializer {
+	node: Node,
+}
+
+impl NodeSerializer {
+	fn predicate_key<L>(predicate: &L) -> Result<IriBuf, IntoJsonLdError>
+	where
+		L: ?Sized + LinkedDataResource,
+	{
+		match lexical_representation(predicate) {
+			Some(Term::Id(Id::Iri(iri))) => Ok(iri),
+			_ => Err(IntoJsonLdError::Predicate),
+		}
+	}
+}
+
+impl SubjectVisitor<(), ()> for NodeSerializer {
+	type Ok = Node;
+	type Error = IntoJsonLdError;
+
+	fn predicate<L, T>(&mut self, predicate: &L, objects: &T) -> Result<(), Self::Error>
+	where
+		L: ?Sized + LinkedDataResource,
+		T: ?Sized + LinkedDataPredicateObjects,
+	{
+		let key = Self::predicate_key(predicate)?;
+		let mut values = objects.visit_objects(ObjectsSerializer {
+			values: Vec::new(),
+			reverse: false,
+		})?;
+
+		if key == RDF_TYPE {
+			// Node references are types, other values remain properties.
+			values.retain(|value| match type_reference(value) {
+				Some(ty) => {
+					self.node.types.push(ty.to_owned());
+					false
+				}
+				None => true,
+			});
+
+			if values.is_empty() {
+				return Ok(());
+			}
+		}
+
+		push_values(&mut self.node.properties, key.into_string(), values);
+		Ok(())
+	}
+
+	fn reverse_predicate<L, T>(&mut self, predicate: &L, subjects: &T) -> Result<(), Self::Error>
+	where
+		L: ?Sized + LinkedDataResource,
+		T: ?Sized + LinkedDataPredicateObjects,
+	{
+		let key = Self::predicate_key(predicate)?;
+		let values = subjects.visit_objects(ObjectsSerializer {
+			values: Vec::new(),
+			reverse: true,
+		})?;
+
+		push_values(&mut self.node.reverse_properties, key.into_string(), values);
+		Ok(())
+	}
+
+	fn graph<T>(&mut self, value: &T) -> Result<(), Self::Error>
+	where
+		T: ?Sized + LinkedDataGraph<(), ()>,
+	{
+		value.visit_graph(GraphSerializer {
+			nodes: self.node.graph.get_or_insert_with(Vec::new),
+		})
+	}
+
+	fn include<T>(&mut self, value: &T) -> Result<(), Self::Error>
+	where
+		T: ?Sized + LinkedDataResource + LinkedDataSubject,
+	{
+		self.node.included.push(node_object(value)?.into_json());
+		Ok(())
+	}
+
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		Ok(self.node)
+	}
+}
+
+/// Returns the identifier of the given node reference, if it is one.
+fn type_reference(value: &Value) -> Option<&str> {
+	let object = value.as_object()?;
+	if object.len() == 1 {
+		object.get_unique("@id").ok()??.as_str()
+	} else {
+		None
+	}
+}
+
+struct ObjectsSerializer {
+	values: Vec<Value>,
+
+	/// Whether the values are the subjects of a reverse property, in which
+	/// case they cannot be literals nor `@list` objects.
+	reverse: bool,
+}
+
+impl PredicateObjectsVisitor<(), ()> for ObjectsSerializer {
+	type Ok = Vec<Value>;
+	type Error = IntoJsonLdError;
+
+	fn object<T>(&mut self, value: &T) -> Result<(), Self::Error>
+	where
+		T: ?Sized + LinkedDataResource + LinkedDataSubject,
+	{
+		let value = match lexical_representation(value) {
+			Some(Term::Literal(_)) if self.reverse => return Err(IntoJsonLdError::Subject),
+			Some(Term::Literal(literal)) => value_object(literal),
+			Some(Term::Id(Id::Iri(iri))) if iri == RDF_NIL && !self.reverse => {
+				let mut object = Object::new();
+				object.push("@list".into(), Value::Array(Vec::new()));
+				Value::Object(object)
+			}
+			id => {
+				let id = node_id(id, IntoJsonLdError::Subject)?;
+				let node = value.visit_subject(NodeSerializer {
+					node: Node::new(id),
+				})?;
+
+				// List objects cannot be the value of a reverse property.
+				match node.as_list() {
+					Some(list) if !self.reverse => list,
+					_ => node.into_json(),
+				}
+			}
+		};
+
+		self.values.push(value);
+		Ok(())
+	}
+
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		Ok(self.values)
+	}
+}
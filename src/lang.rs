@@ -0,0 +1,427 @@
+//! Language-tagged strings.
+use std::{
+	collections::BTreeMap,
+	ops::{Deref, DerefMut},
+};
+
+use langtag::{LangTag, LangTagBuf};
+use rdf_types::{
+	Interpretation, LiteralTypeRef, RDF_LANG_STRING, Term, Vocabulary,
+	dataset::PatternMatchingDataset,
+	interpretation::{ReverseIriInterpretation, ReverseLiteralInterpretation},
+	vocabulary::{IriVocabularyMut, LiteralVocabulary, LiteralVocabularyMut},
+};
+
+use crate::{
+	Context, CowRdfTerm, FromLinkedDataError, LinkedDataDeserializePredicateObjects,
+	LinkedDataDeserializeSubject, LinkedDataPredicateObjects, LinkedDataResource,
+	LinkedDataSubject, PredicateObjectsVisitor, RdfLiteralRef, ResourceInterpretation,
+	SubjectVisitor,
+};
+
+/// Language-tagged string, serialized as an `rdf:langString` literal.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LangString {
+	pub value: String,
+	pub lang: LangTagBuf,
+}
+
+impl LangString {
+	pub fn new(value: String, lang: LangTagBuf) -> Self {
+		Self { value, lang }
+	}
+}
+
+/// Borrowed language-tagged string.
+struct LangStr<'a> {
+	value: &'a str,
+	lang: &'a LangTag,
+}
+
+macro_rules! serialize_lang_string {
+	($ty:ty) => {
+		impl<V: Vocabulary + IriVocabularyMut + LiteralVocabularyMut, I: Interpretation>
+			LinkedDataResource<I, V> for $ty
+		{
+			fn interpretation(
+				&self,
+				_vocabulary: &mut V,
+				_interpretation: &mut I,
+			) -> ResourceInterpretation<'_, I, V> {
+				ResourceInterpretation::Uninterpreted(Some(CowRdfTerm::Borrowed(Term::Literal(
+					RdfLiteralRef::Any(&self.value, LiteralTypeRef::LangString(&self.lang)),
+				))))
+			}
+		}
+
+		impl<V: Vocabulary + IriVocabularyMut + LiteralVocabularyMut, I: Interpretation>
+			LinkedDataSubject<I, V> for $ty
+		{
+			fn visit_subject<S>(&self, visitor: S) -> Result<S::Ok, S::Error>
+			where
+				S: SubjectVisitor<I, V>,
+			{
+				visitor.end()
+			}
+		}
+
+		impl<V: Vocabulary + IriVocabularyMut + LiteralVocabularyMut, I: Interpretation>
+			LinkedDataPredicateObjects<I, V> for $ty
+		{
+			fn visit_objects<S>(&self, mut visitor: S) -> Result<S::Ok, S::Error>
+			where
+				S: PredicateObjectsVisitor<I, V>,
+			{
+				visitor.object(self)?;
+				visitor.end()
+			}
+		}
+	};
+}
+
+serialize_lang_string!(LangString);
+serialize_lang_string!(LangStr<'_>);
+
+impl<V: Vocabulary, I: Interpretation> LinkedDataDeserializeSubject<I, V> for LangString
+where
+	V: LiteralVocabulary,
+	I: ReverseIriInterpretation<Iri = V::Iri> + ReverseLiteralInterpretation<Literal = V::Literal>,
+{
+	fn deserialize_subject_in<D>(
+		vocabulary: &V,
+		interpretation: &I,
+		_dataset: &D,
+		_graph: Option<&I::Resource>,
+		resource: &I::Resource,
+		context: Context<I>,
+	) -> Result<Self, FromLinkedDataError>
+	where
+		D: PatternMatchingDataset<Resource = I::Resource>,
+	{
+		let mut literal_ty = None;
+		for l in interpretation.literals_of(resource) {
+			let l = vocabulary.literal(l).unwrap();
+			match l.type_ {
+				LiteralTypeRef::LangString(lang) => {
+					return Ok(Self::new(l.value.to_owned(), lang.to_owned()));
+				}
+				LiteralTypeRef::Any(ty) => literal_ty = Some(vocabulary.iri(ty).unwrap()),
+			}
+		}
+
+		match literal_ty {
+			Some(ty) => Err(FromLinkedDataError::LiteralTypeMismatch {
+				context: context.into_iris(vocabulary, interpretation),
+				expected: Some(RDF_LANG_STRING.to_owned()),
+				found: ty.to_owned(),
+			}),
+			None => Err(FromLinkedDataError::ExpectedLiteral(
+				context.into_iris(vocabulary, interpretation),
+			)),
+		}
+	}
+}
+
+impl<V: Vocabulary, I: Interpretation> LinkedDataDeserializePredicateObjects<I, V> for LangString
+where
+	V: LiteralVocabulary,
+	I: ReverseIriInterpretation<Iri = V::Iri> + ReverseLiteralInterpretation<Literal = V::Literal>,
+{
+	fn deserialize_objects_in<'a, D>(
+		vocabulary: &V,
+		interpretation: &I,
+		dataset: &D,
+		graph: Option<&I::Resource>,
+		objects: impl IntoIterator<Item = &'a I::Resource>,
+		context: Context<I>,
+	) -> Result<Self, FromLinkedDataError>
+	where
+		I::Resource: 'a,
+		D: PatternMatchingDataset<Resource = I::Resource>,
+	{
+		let mut objects = objects.into_iter();
+		match objects.next() {
+			Some(object) => {
+				if objects.next().is_none() {
+					Self::deserialize_subject_in(
+						vocabulary,
+						interpretation,
+						dataset,
+						graph,
+						object,
+						context,
+					)
+				} else {
+					Err(FromLinkedDataError::TooManyValues(
+						context.into_iris(vocabulary, interpretation),
+					))
+				}
+			}
+			None => Err(FromLinkedDataError::MissingRequiredValue(
+				context.into_iris(vocabulary, interpretation),
+			)),
+		}
+	}
+}
+
+/// Language map, binding at most one string per language.
+///
+/// Language tags are case-insensitive, so keys are normalized to the
+/// conventional case of [RFC 5646](https://www.rfc-editor.org/rfc/rfc5646#section-2.1.1)
+/// (`en-us` is stored as `en-US`). Each entry is serialized as a distinct
+/// `rdf:langString` object of the same predicate.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LangMap(pub BTreeMap<LangTagBuf, String>);
+
+impl LangMap {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the string for the given language, if any.
+	pub fn get(&self, lang: &LangTag) -> Option<&str> {
+		self.0.get(&normalize_case(lang)).map(String::as_str)
+	}
+
+	/// Binds a string to the given language, returning the previous string
+	/// of this language, if any.
+	pub fn insert(&mut self, lang: LangTagBuf, value: String) -> Option<String> {
+		self.0.insert(normalize_case(&lang), value)
+	}
+}
+
+/// Normalizes the case of a language tag: region subtags are upper case,
+/// script subtags title case and all other subtags lower case.
+fn normalize_case(lang: &LangTag) -> LangTagBuf {
+	let mut result = String::with_capacity(lang.len());
+	let mut extension = false;
+
+	for (i, subtag) in lang.as_str().split('-').enumerate() {
+		if i > 0 {
+			result.push('-');
+
+			// Subtags following a singleton are not normalized further.
+			extension |= subtag.len() == 1;
+		}
+
+		if i > 0 && !extension && subtag.len() == 2 {
+			result.push_str(&subtag.to_ascii_uppercase())
+		} else if i > 0 && !extension && subtag.len() == 4 {
+			result.push_str(&subtag[..1].to_ascii_uppercase());
+			result.push_str(&subtag[1..].to_ascii_lowercase())
+		} else {
+			result.push_str(&subtag.to_ascii_lowercase())
+		}
+	}
+
+	LangTagBuf::new(result).unwrap()
+}
+
+impl Deref for LangMap {
+	type Target = BTreeMap<LangTagBuf, String>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl DerefMut for LangMap {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.0
+	}
+}
+
+impl From<BTreeMap<LangTagBuf, String>> for LangMap {
+	fn from(value: BTreeMap<LangTagBuf, String>) -> Self {
+		value.into_iter().collect()
+	}
+}
+
+impl FromIterator<(LangTagBuf, String)> for LangMap {
+	fn from_iter<T: IntoIterator<Item = (LangTagBuf, String)>>(iter: T) -> Self {
+		let mut result = Self::new();
+		for (lang, value) in iter {
+			result.insert(lang, value);
+		}
+
+		result
+	}
+}
+
+impl<V: Vocabulary + IriVocabularyMut + LiteralVocabularyMut, I: Interpretation>
+	LinkedDataPredicateObjects<I, V> for LangMap
+{
+	fn visit_objects<S>(&self, mut visitor: S) -> Result<S::Ok, S::Error>
+	where
+		S: PredicateObjectsVisitor<I, V>,
+	{
+		for (lang, value) in &self.0 {
+			visitor.object(&LangStr { value, lang })?;
+		}
+
+		visitor.end()
+	}
+}
+
+impl<V: Vocabulary, I: Interpretation> LinkedDataDeserializePredicateObjects<I, V> for LangMap
+where
+	V: LiteralVocabulary,
+	I: ReverseIriInterpretation<Iri = V::Iri> + ReverseLiteralInterpretation<Literal = V::Literal>,
+{
+	fn deserialize_objects_in<'a, D>(
+		vocabulary: &V,
+		interpretation: &I,
+		dataset: &D,
+		graph: Option<&I::Resource>,
+		objects: impl IntoIterator<Item = &'a I::Resource>,
+		context: Context<I>,
+	) -> Result<Self, FromLinkedDataError>
+	where
+		I::Resource: 'a,
+		D: PatternMatchingDataset<Resource = I::Resource>,
+	{
+		let mut result = Self::new();
+
+		for object in objects {
+			let s = LangString::deserialize_subject_in(
+				vocabulary,
+				interpretation,
+				dataset,
+				graph,
+				object,
+				context,
+			)?;
+
+			if result.insert(s.lang, s.value).is_some() {
+				return Err(FromLinkedDataError::TooManyValues(
+					context.into_iris(vocabulary, interpretation),
+				));
+			}
+		}
+
+		Ok(result)
+	}
+}
+
+/// Language preference, selecting a single string among the
+/// language-tagged objects of a predicate.
+///
+/// Each language range matches the language tags equal to it, or starting
+/// with it followed by `-`, ignoring case (`en` matches `en-GB`). The range
+/// `*` matches any language tag. Earlier ranges take precedence.
+#[derive(Debug, Clone, Copy)]
+pub struct LanguagePreference<'a> {
+	/// Preferred language ranges, by decreasing priority.
+	pub languages: &'a [&'a str],
+
+	/// Whether to fall back to an untagged string, then to any
+	/// language-tagged string, when no preferred language matches.
+	pub fallback: bool,
+}
+
+impl<'a> LanguagePreference<'a> {
+	/// Creates a new language preference, with fallback.
+	pub fn new(languages: &'a [&'a str]) -> Self {
+		Self {
+			languages,
+			fallback: true,
+		}
+	}
+
+	/// Creates a new language preference without fallback.
+	pub fn strict(languages: &'a [&'a str]) -> Self {
+		Self {
+			languages,
+			fallback: false,
+		}
+	}
+
+	/// Selects the preferred string among the given objects.
+	///
+	/// Objects that are neither `xsd:string` nor `rdf:langString` literals
+	/// are ignored. Returns `None` if no string is acceptable.
+	pub fn select<'r, I, V>(
+		&self,
+		vocabulary: &V,
+		interpretation: &I,
+		objects: impl IntoIterator<Item = &'r I::Resource>,
+	) -> Option<String>
+	where
+		I: Interpretation
+			+ ReverseIriInterpretation<Iri = V::Iri>
+			+ ReverseLiteralInterpretation<Literal = V::Literal>,
+		I::Resource: 'r,
+		V: Vocabulary + LiteralVocabulary,
+	{
+		let mut plain = None;
+		let mut tagged = Vec::new();
+
+		for object in objects {
+			for l in interpretation.literals_of(object) {
+				let l = vocabulary.literal(l).unwrap();
+				match l.type_ {
+					LiteralTypeRef::LangString(lang) => tagged.push((lang, l.value)),
+					LiteralTypeRef::Any(ty) => {
+						if plain.is_none() && vocabulary.iri(ty).unwrap() == xsd_types::XSD_STRING {
+							plain = Some(l.value)
+						}
+					}
+				}
+			}
+		}
+
+		// Sort for a deterministic choice among equivalent candidates.
+		tagged.sort();
+
+		for range in self.languages {
+			if let Some((_, value)) = tagged.iter().find(|(lang, _)| matches_range(lang, range)) {
+				return Some((*value).to_owned());
+			}
+		}
+
+		if self.fallback {
+			plain
+				.or_else(|| tagged.first().map(|(_, value)| *value))
+				.map(ToOwned::to_owned)
+		} else {
+			None
+		}
+	}
+
+	/// Deserializes a string from the given objects, according to this
+	/// preference.
+	pub fn deserialize_objects_in<'r, I, V>(
+		&self,
+		vocabulary: &V,
+		interpretation: &I,
+		objects: impl IntoIterator<Item = &'r I::Resource>,
+		context: Context<I>,
+	) -> Result<String, FromLinkedDataError>
+	where
+		I: Interpretation
+			+ ReverseIriInterpretation<Iri = V::Iri>
+			+ ReverseLiteralInterpretation<Literal = V::Literal>,
+		I::Resource: 'r,
+		V: Vocabulary + LiteralVocabulary,
+	{
+		self.select(vocabulary, interpretation, objects)
+			.ok_or_else(|| {
+				FromLinkedDataError::MissingRequiredValue(
+					context.into_iris(vocabulary, interpretation),
+				)
+			})
+	}
+}
+
+/// Checks that the given language tag matches the given language range.
+fn matches_range(lang: &LangTag, range: &str) -> bool {
+	if range == "*" {
+		return true;
+	}
+
+	let lang = lang.as_str();
+	lang.eq_ignore_ascii_case(range)
+		|| (lang.len() > range.len()
+			&& lang.as_bytes()[range.len()] == b'-'
+			&& lang[..range.len()].eq_ignore_ascii_case(range))
+}
@@ -44,7 +44,7 @@
 use educe::Educe;
 use iref::{Iri, IriBuf};
 #[cfg(feature = "derive")]
-pub use linked_data_next_derive::{Deserialize, Serialize};
+pub use linked_data_next_derive::{Deserialize, JsonLdContext, Serialize};
 use rdf_types::{
 	Interpretation, Vocabulary,
 	dataset::{PatternMatchingDataset, TraversableDataset},
@@ -65,30 +65,54 @@ pub use xsd_types;
 pub use json_syntax;
 
 mod anonymous;
+mod coercion;
+mod container;
+mod dataset;
 mod datatypes;
 mod graph;
 mod r#impl;
+mod json_ld;
+mod lang;
 mod macros;
+pub mod nquads;
 mod predicate;
 mod quads;
 mod rdf;
+mod rdf_container;
 mod rdf_list;
 mod reference;
 mod resource;
 mod subject;
+pub mod turtle;
 
 pub use anonymous::*;
+pub use coercion::Coercion;
+pub use container::*;
+pub use dataset::*;
+pub use datatypes::{TypedLiteral, TypedLiteralReader, TypedLiterals};
 pub use graph::*;
+pub use json_ld::{IntoJsonLdError, JsonLdContext, to_json_ld_expanded};
+pub use lang::{LangMap, LangString, LanguagePreference};
+pub use langtag::{LangTag, LangTagBuf};
+pub use nquads::{from_nquads_reader, from_nquads_reader_in, from_nquads_str, from_nquads_str_in};
 pub use predicate::*;
 pub use quads::{
-	IntoQuadsError, to_interpreted_graph_quads, to_interpreted_quads, to_interpreted_subject_quads,
-	to_lexical_quads, to_lexical_quads_with, to_lexical_subject_quads,
-	to_lexical_subject_quads_with, to_quads, to_quads_with,
+	IntoQuadsError, QuadSink, to_interpreted_graph_quads, to_interpreted_quads,
+	to_interpreted_quads_into, to_interpreted_subject_quads, to_lexical_quads,
+	to_lexical_quads_into, to_lexical_quads_into_with, to_lexical_quads_with,
+	to_lexical_subject_quads, to_lexical_subject_quads_with, to_quads, to_quads_into,
+	to_quads_into_with, to_quads_with,
 };
 pub use rdf::*;
+pub use rdf_container::{Alt, Bag, MalformedContainerReason, Seq};
+pub use rdf_list::{MalformedListReason, RdfListReader};
 pub use reference::*;
 pub use resource::*;
 pub use subject::*;
+pub use turtle::{
+	LinkedDataPrefixes, from_turtle_reader, from_turtle_reader_in, from_turtle_str,
+	from_turtle_str_in,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum FromLinkedDataError {
@@ -133,12 +157,58 @@ pub enum FromLinkedDataError {
 	#[error("too many values")]
 	TooManyValues(ContextIris),
 
+	/// Named graph is empty or absent from the dataset.
+	#[error("empty graph")]
+	EmptyGraph(ContextIris),
+
+	/// Every subject of the graph is also an object in it, as in a cycle, so
+	/// it has no root subject.
+	#[error("no root subject")]
+	NoRootSubject(ContextIris),
+
+	/// Invalid `rdf:List` structure.
+	#[error("malformed list: {reason}")]
+	MalformedList {
+		context: ContextIris,
+		reason: MalformedListReason,
+	},
+
+	/// Invalid RDF container (`rdf:Seq`, `rdf:Bag` or `rdf:Alt`) membership.
+	#[error("malformed container: {reason}")]
+	MalformedContainer {
+		context: ContextIris,
+		reason: MalformedContainerReason,
+	},
+
+	/// `rdf:List` whose `rdf:rest` chain loops back on itself.
+	#[error("cyclic list")]
+	CyclicList(ContextIris),
+
+	/// `rdf:List` longer than the allowed maximum length.
+	#[error("list exceeds the maximum length of {max_length} items")]
+	ListTooLong {
+		context: ContextIris,
+		max_length: usize,
+	},
+
 	/// Generic error for invalid subjects.
 	#[error("invalid subject")]
 	InvalidSubject {
 		context: ContextIris,
 		subject: Option<IriBuf>,
 	},
+
+	/// Resource does not have the expected `rdf:type`.
+	#[error("type mismatch")]
+	TypeMismatch {
+		context: ContextIris,
+
+		/// Expected types.
+		expected: Vec<IriBuf>,
+
+		/// Types found on the resource.
+		found: Vec<IriBuf>,
+	},
 }
 
 impl FromLinkedDataError {
@@ -151,7 +221,14 @@ impl FromLinkedDataError {
 			Self::InvalidLiteral(c) => c,
 			Self::MissingRequiredValue(c) => c,
 			Self::TooManyValues(c) => c,
+			Self::EmptyGraph(c) => c,
+			Self::NoRootSubject(c) => c,
+			Self::MalformedList { context, .. } => context,
+			Self::MalformedContainer { context, .. } => context,
+			Self::CyclicList(c) => c,
+			Self::ListTooLong { context, .. } => context,
 			Self::InvalidSubject { context, .. } => context,
+			Self::TypeMismatch { context, .. } => context,
 		}
 	}
 }
@@ -266,54 +343,96 @@ impl<I: Interpretation> ResourceOrIriRef<'_, I> {
 	}
 }
 
-#[derive(Default, Educe)]
+/// Deserialization context.
+///
+/// Locates the value being deserialized, for error reporting, and carries
+/// the deserialization options down to nested values.
+///
+/// # Migrating from the `Context` enum
+///
+/// `Context` used to be an enum of locations. Its variants are now those of
+/// [`ContextLocation`], stored in the [`location`](Self::location) field:
+/// match on `context.location` instead of `context`, and build contexts from
+/// [`Context::default()`] and the `with_*` methods instead of the variants.
+#[derive(Educe)]
 #[educe(Debug(bound = "I::Resource: core::fmt::Debug"), Clone, Copy)]
-pub enum Context<'a, I: Interpretation> {
-	#[default]
-	Subject,
-	Predicate {
-		subject: ResourceOrIriRef<'a, I>,
-	},
-	Object {
-		subject: ResourceOrIriRef<'a, I>,
-		predicate: ResourceOrIriRef<'a, I>,
-	},
+pub struct Context<'a, I: Interpretation> {
+	/// Location of the value being deserialized.
+	pub location: ContextLocation<'a, I>,
+
+	/// Literal coercion policy.
+	pub coercion: Coercion,
+
+	/// Reader used for `rdf:List` values.
+	pub list_reader: RdfListReader,
+
+	/// Language preference used to select a single `String` among the
+	/// objects of a predicate.
+	///
+	/// When set, language-tagged strings are accepted and chosen according to
+	/// the preference. Fields with a `#[ld(lang = ...)]` attribute use their
+	/// own preference.
+	pub language: Option<LanguagePreference<'a>>,
+}
+
+impl<I: Interpretation> Default for Context<'_, I> {
+	fn default() -> Self {
+		Self {
+			location: ContextLocation::Subject,
+			coercion: Coercion::default(),
+			list_reader: RdfListReader::default(),
+			language: None,
+		}
+	}
 }
 
 impl<'a, I: Interpretation> Context<'a, I> {
+	pub fn with_coercion(self, coercion: Coercion) -> Self {
+		Self { coercion, ..self }
+	}
+
+	pub fn with_list_reader(self, list_reader: RdfListReader) -> Self {
+		Self {
+			list_reader,
+			..self
+		}
+	}
+
+	pub fn with_language(self, language: LanguagePreference<'a>) -> Self {
+		Self {
+			language: Some(language),
+			..self
+		}
+	}
+
 	pub fn with_subject(self, subject: &'a I::Resource) -> Self {
-		Self::Predicate {
+		self.with_location(ContextLocation::Predicate {
 			subject: ResourceOrIriRef::Resource(subject),
-		}
+		})
 	}
 
 	pub fn with_predicate(self, predicate: &'a I::Resource) -> Self {
-		match self {
-			Self::Predicate { subject } => Self::Object {
-				subject,
-				predicate: ResourceOrIriRef::Resource(predicate),
-			},
-			_ => Self::Subject,
-		}
+		self.with_object_predicate(ResourceOrIriRef::Resource(predicate))
 	}
 
 	pub fn with_predicate_iri(self, predicate: &'a Iri) -> Self {
-		match self {
-			Self::Predicate { subject } => Self::Object {
-				subject,
-				predicate: ResourceOrIriRef::Iri(predicate),
-			},
-			_ => Self::Subject,
-		}
+		self.with_object_predicate(ResourceOrIriRef::Iri(predicate))
 	}
 
 	pub fn with_anonymous_predicate(self) -> Self {
-		match self {
-			Self::Predicate { subject } => Self::Object {
-				subject,
-				predicate: ResourceOrIriRef::Anonymous,
-			},
-			_ => Self::Subject,
+		self.with_object_predicate(ResourceOrIriRef::Anonymous)
+	}
+
+	fn with_location(self, location: ContextLocation<'a, I>) -> Self {
+		Self { location, ..self }
+	}
+
+	fn with_object_predicate(self, predicate: ResourceOrIriRef<'a, I>) -> Self {
+		match self.location {
+			ContextLocation::Predicate { subject } => {
+				self.with_location(ContextLocation::Object { subject, predicate })
+			}
+			_ => self.with_location(ContextLocation::Subject),
 		}
 	}
 
@@ -322,12 +441,12 @@ impl<'a, I: Interpretation> Context<'a, I> {
 		V: IriVocabulary,
 		I: ReverseIriInterpretation<Iri = V::Iri>,
 	{
-		match self {
-			Self::Subject => ContextIris::Subject,
-			Self::Predicate { subject } => ContextIris::Predicate {
+		match self.location {
+			ContextLocation::Subject => ContextIris::Subject,
+			ContextLocation::Predicate { subject } => ContextIris::Predicate {
 				subject: subject.into_iri(vocabulary, interpretation),
 			},
-			Self::Object { subject, predicate } => ContextIris::Object {
+			ContextLocation::Object { subject, predicate } => ContextIris::Object {
 				subject: subject.into_iri(vocabulary, interpretation),
 				predicate: predicate.into_iri(vocabulary, interpretation),
 			},
@@ -335,6 +454,19 @@ impl<'a, I: Interpretation> Context<'a, I> {
 	}
 }
 
+#[derive(Educe)]
+#[educe(Debug(bound = "I::Resource: core::fmt::Debug"), Clone, Copy)]
+pub enum ContextLocation<'a, I: Interpretation> {
+	Subject,
+	Predicate {
+		subject: ResourceOrIriRef<'a, I>,
+	},
+	Object {
+		subject: ResourceOrIriRef<'a, I>,
+		predicate: ResourceOrIriRef<'a, I>,
+	},
+}
+
 #[derive(Debug, Clone)]
 pub enum ContextIris {
 	Subject,
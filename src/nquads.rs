@@ -0,0 +1,478 @@
+//! N-Quads serialization and deserialization.
+//!
+//! Quads are written one per line, as they are produced by the serializer.
+//! Values that only use the default graph produce valid N-Triples, which can
+//! also be read back by the parser.
+use std::io;
+
+use iref::IriBuf;
+use langtag::LangTagBuf;
+use rdf_types::{
+	BlankIdBuf, Generator, Id, Literal, LiteralType, Quad, RdfDisplay, Term, XSD_STRING,
+	dataset::IndexedBTreeDataset, interpretation::WithGenerator,
+};
+
+use crate::{
+	Context, FromLinkedDataError, IntoQuadsError, LinkedData, LinkedDataDeserializeSubject,
+	QuadSink, RdfQuad, to_quads, to_quads_into,
+};
+
+/// N-Quads writing error.
+#[derive(Debug, thiserror::Error)]
+pub enum WriteError {
+	#[error(transparent)]
+	Quads(#[from] IntoQuadsError),
+
+	#[error(transparent)]
+	Io(#[from] io::Error),
+}
+
+/// Quad sink writing each quad as an N-Quads statement.
+pub struct Writer<W> {
+	writer: W,
+}
+
+impl<W> Writer<W> {
+	pub fn new(writer: W) -> Self {
+		Self { writer }
+	}
+
+	pub fn into_inner(self) -> W {
+		self.writer
+	}
+}
+
+impl<W: io::Write> QuadSink<RdfQuad> for Writer<W> {
+	type Error = WriteError;
+
+	fn push(&mut self, quad: RdfQuad) -> Result<(), Self::Error> {
+		writeln!(self.writer, "{} .", quad.rdf_display())?;
+		Ok(())
+	}
+}
+
+/// Serializes the given value as N-Quads into `writer`.
+///
+/// Quads are written in the order they are produced by the serializer,
+/// without being buffered.
+pub fn to_writer<G: Generator>(
+	writer: impl io::Write,
+	generator: G,
+	value: &impl LinkedData<WithGenerator<G>>,
+) -> Result<(), WriteError> {
+	to_quads_into(generator, &mut Writer::new(writer), value)
+}
+
+/// Serializes the given value as N-Quads into `writer`, with the quads sorted
+/// and deduplicated.
+///
+/// Contrarily to [`to_writer`], all the quads are buffered before being
+/// written.
+pub fn to_writer_sorted<G: Generator>(
+	writer: impl io::Write,
+	generator: G,
+	value: &impl LinkedData<WithGenerator<G>>,
+) -> Result<(), WriteError> {
+	let mut quads = to_quads(generator, value)?;
+	quads.sort_unstable();
+	quads.dedup();
+
+	let mut writer = Writer::new(writer);
+	for quad in quads {
+		writer.push(quad)?;
+	}
+
+	Ok(())
+}
+
+/// Serializes the given value as an N-Quads string.
+pub fn to_string<G: Generator>(
+	generator: G,
+	value: &impl LinkedData<WithGenerator<G>>,
+) -> Result<String, IntoQuadsError> {
+	let mut result = Vec::new();
+	to_writer(&mut result, generator, value).map_err(expect_quads_error)?;
+	Ok(String::from_utf8(result).unwrap())
+}
+
+/// Serializes the given value as an N-Quads string, with the quads sorted
+/// and deduplicated.
+pub fn to_string_sorted<G: Generator>(
+	generator: G,
+	value: &impl LinkedData<WithGenerator<G>>,
+) -> Result<String, IntoQuadsError> {
+	let mut result = Vec::new();
+	to_writer_sorted(&mut result, generator, value).map_err(expect_quads_error)?;
+	Ok(String::from_utf8(result).unwrap())
+}
+
+/// Writing into a `Vec<u8>` never fails.
+fn expect_quads_error(e: WriteError) -> IntoQuadsError {
+	match e {
+		WriteError::Quads(e) => e,
+		WriteError::Io(_) => unreachable!(),
+	}
+}
+
+/// N-Quads parse error.
+#[derive(Debug, thiserror::Error)]
+#[error("{kind} at line {line}, column {column}")]
+pub struct ParseError {
+	/// Line number, starting from 1.
+	pub line: usize,
+
+	/// Column number, in characters, starting from 1.
+	pub column: usize,
+
+	/// Error kind.
+	pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseErrorKind {
+	#[error("unexpected character `{0}`")]
+	Unexpected(char),
+
+	#[error("unexpected end of line")]
+	UnexpectedEnd,
+
+	#[error("invalid IRI `{0}`")]
+	InvalidIri(String),
+
+	#[error("invalid blank node identifier `{0}`")]
+	InvalidBlankId(String),
+
+	#[error("invalid language tag `{0}`")]
+	InvalidLangTag(String),
+
+	#[error("invalid escape sequence")]
+	InvalidEscape,
+}
+
+/// N-Quads reading error.
+#[derive(Debug, thiserror::Error)]
+pub enum ReadError {
+	#[error(transparent)]
+	Parse(#[from] ParseError),
+
+	#[error(transparent)]
+	Io(#[from] io::Error),
+}
+
+/// Error returned by [`from_nquads_str`] and [`from_nquads_reader`].
+#[derive(Debug, thiserror::Error)]
+pub enum FromNQuadsError {
+	#[error(transparent)]
+	Read(#[from] ReadError),
+
+	#[error(transparent)]
+	Deserialize(#[from] FromLinkedDataError),
+}
+
+impl From<ParseError> for FromNQuadsError {
+	fn from(value: ParseError) -> Self {
+		Self::Read(value.into())
+	}
+}
+
+/// Parses the given N-Quads document into a dataset.
+pub fn parse_str(input: &str) -> Result<IndexedBTreeDataset, ParseError> {
+	let mut dataset = IndexedBTreeDataset::new();
+
+	for (i, line) in input.lines().enumerate() {
+		if let Some(quad) = LineParser::new(line, i + 1).parse()? {
+			dataset.insert(quad);
+		}
+	}
+
+	Ok(dataset)
+}
+
+/// Parses the N-Quads document provided by `reader` into a dataset.
+pub fn parse_reader(reader: impl io::BufRead) -> Result<IndexedBTreeDataset, ReadError> {
+	let mut dataset = IndexedBTreeDataset::new();
+
+	for (i, line) in reader.lines().enumerate() {
+		if let Some(quad) = LineParser::new(&line?, i + 1).parse()? {
+			dataset.insert(quad);
+		}
+	}
+
+	Ok(dataset)
+}
+
+/// Parses the given N-Quads document and deserializes `subject` from its
+/// default graph.
+pub fn from_nquads_str<T: LinkedDataDeserializeSubject>(
+	input: &str,
+	subject: &Term,
+) -> Result<T, FromNQuadsError> {
+	from_nquads_str_in(input, subject, Context::default())
+}
+
+/// Parses the given N-Quads document and deserializes `subject` from its
+/// default graph, using the given deserialization context.
+pub fn from_nquads_str_in<T: LinkedDataDeserializeSubject>(
+	input: &str,
+	subject: &Term,
+	context: Context<()>,
+) -> Result<T, FromNQuadsError> {
+	let dataset = parse_str(input)?;
+	Ok(T::deserialize_subject_in(
+		&(),
+		&(),
+		&dataset,
+		None,
+		subject,
+		context,
+	)?)
+}
+
+/// Parses the N-Quads document provided by `reader` and deserializes
+/// `subject` from its default graph.
+pub fn from_nquads_reader<T: LinkedDataDeserializeSubject>(
+	reader: impl io::BufRead,
+	subject: &Term,
+) -> Result<T, FromNQuadsError> {
+	from_nquads_reader_in(reader, subject, Context::default())
+}
+
+/// Parses the N-Quads document provided by `reader` and deserializes
+/// `subject` from its default graph, using the given deserialization context.
+pub fn from_nquads_reader_in<T: LinkedDataDeserializeSubject>(
+	reader: impl io::BufRead,
+	subject: &Term,
+	context: Context<()>,
+) -> Result<T, FromNQuadsError> {
+	let dataset = parse_reader(reader)?;
+	Ok(T::deserialize_subject_in(
+		&(),
+		&(),
+		&dataset,
+		None,
+		subject,
+		context,
+	)?)
+}
+
+/// Parser for a single N-Quads statement line.
+struct LineParser<'a> {
+	line: &'a str,
+	number: usize,
+	offset: usize,
+}
+
+impl<'a> LineParser<'a> {
+	fn new(line: &'a str, number: usize) -> Self {
+		Self {
+			line,
+			number,
+			offset: 0,
+		}
+	}
+
+	fn error_at(&self, offset: usize, kind: ParseErrorKind) -> ParseError {
+		ParseError {
+			line: self.number,
+			column: self.line[..offset].chars().count() + 1,
+			kind,
+		}
+	}
+
+	fn unexpected(&self) -> ParseError {
+		let kind = match self.peek() {
+			Some(c) => ParseErrorKind::Unexpected(c),
+			None => ParseErrorKind::UnexpectedEnd,
+		};
+
+		self.error_at(self.offset, kind)
+	}
+
+	fn peek(&self) -> Option<char> {
+		self.line[self.offset..].chars().next()
+	}
+
+	fn next_char(&mut self) -> Result<char, ParseError> {
+		let c = self.peek().ok_or_else(|| self.unexpected())?;
+		self.offset += c.len_utf8();
+		Ok(c)
+	}
+
+	fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+		if self.peek() == Some(expected) {
+			self.offset += expected.len_utf8();
+			Ok(())
+		} else {
+			Err(self.unexpected())
+		}
+	}
+
+	fn skip_whitespaces(&mut self) {
+		while matches!(self.peek(), Some(' ' | '\t')) {
+			self.offset += 1
+		}
+	}
+
+	fn parse(mut self) -> Result<Option<Quad<Term>>, ParseError> {
+		self.skip_whitespaces();
+		if matches!(self.peek(), None | Some('#')) {
+			return Ok(None);
+		}
+
+		let subject = self.id()?;
+		self.skip_whitespaces();
+		let predicate = self.iri()?;
+		self.skip_whitespaces();
+		let object = self.term()?;
+		self.skip_whitespaces();
+		let graph = match self.peek() {
+			Some('<' | '_') => Some(self.id()?),
+			_ => None,
+		};
+		self.skip_whitespaces();
+		self.expect('.')?;
+		self.skip_whitespaces();
+
+		if matches!(self.peek(), None | Some('#')) {
+			Ok(Some(Quad(
+				Term::Id(subject),
+				Term::iri(predicate),
+				object,
+				graph.map(Term::Id),
+			)))
+		} else {
+			Err(self.unexpected())
+		}
+	}
+
+	fn term(&mut self) -> Result<Term, ParseError> {
+		match self.peek() {
+			Some('"') => self.literal().map(Term::Literal),
+			_ => self.id().map(Term::Id),
+		}
+	}
+
+	fn id(&mut self) -> Result<Id, ParseError> {
+		match self.peek() {
+			Some('<') => self.iri().map(Id::Iri),
+			Some('_') => self.blank_id().map(Id::Blank),
+			_ => Err(self.unexpected()),
+		}
+	}
+
+	fn iri(&mut self) -> Result<IriBuf, ParseError> {
+		let start = self.offset;
+		self.expect('<')?;
+
+		let mut iri = String::new();
+		loop {
+			let offset = self.offset;
+			match self.next_char()? {
+				'>' => break,
+				'\\' => match self.next_char()? {
+					'u' => iri.push(self.hex_char(offset, 4)?),
+					'U' => iri.push(self.hex_char(offset, 8)?),
+					_ => return Err(self.error_at(offset, ParseErrorKind::InvalidEscape)),
+				},
+				c @ ('\0'..=' ' | '<' | '"' | '{' | '}' | '|' | '^' | '`') => {
+					return Err(self.error_at(offset, ParseErrorKind::Unexpected(c)));
+				}
+				c => iri.push(c),
+			}
+		}
+
+		IriBuf::new(iri).map_err(|e| self.error_at(start, ParseErrorKind::InvalidIri(e.0)))
+	}
+
+	fn blank_id(&mut self) -> Result<BlankIdBuf, ParseError> {
+		let start = self.offset;
+		self.expect('_')?;
+		self.expect(':')?;
+
+		while let Some(c) = self.peek() {
+			if c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':' | '\u{b7}') {
+				self.offset += c.len_utf8()
+			} else {
+				break;
+			}
+		}
+
+		// A trailing `.` terminates the statement.
+		while self.line[..self.offset].ends_with('.') {
+			self.offset -= 1
+		}
+
+		BlankIdBuf::new(self.line[start..self.offset].to_owned())
+			.map_err(|e| self.error_at(start, ParseErrorKind::InvalidBlankId(e.0)))
+	}
+
+	fn literal(&mut self) -> Result<Literal, ParseError> {
+		self.expect('"')?;
+
+		let mut value = String::new();
+		loop {
+			let offset = self.offset;
+			match self.next_char()? {
+				'"' => break,
+				'\\' => {
+					let c = match self.next_char()? {
+						't' => '\t',
+						'b' => '\u{8}',
+						'n' => '\n',
+						'r' => '\r',
+						'f' => '\u{c}',
+						'"' => '"',
+						'\'' => '\'',
+						'\\' => '\\',
+						'u' => self.hex_char(offset, 4)?,
+						'U' => self.hex_char(offset, 8)?,
+						_ => return Err(self.error_at(offset, ParseErrorKind::InvalidEscape)),
+					};
+
+					value.push(c)
+				}
+				c => value.push(c),
+			}
+		}
+
+		let type_ = match self.peek() {
+			Some('@') => {
+				self.offset += 1;
+				let start = self.offset;
+				while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || c == '-') {
+					self.offset += 1
+				}
+
+				let tag = self.line[start..self.offset].to_owned();
+				LiteralType::LangString(
+					LangTagBuf::new(tag)
+						.map_err(|e| self.error_at(start, ParseErrorKind::InvalidLangTag(e.0)))?,
+				)
+			}
+			Some('^') => {
+				self.offset += 1;
+				self.expect('^')?;
+				LiteralType::Any(self.iri()?)
+			}
+			_ => LiteralType::Any(XSD_STRING.to_owned()),
+		};
+
+		Ok(Literal::new(value, type_))
+	}
+
+	/// Reads the `len` hexadecimal digits of a `\u` or `\U` escape sequence
+	/// starting at `offset`.
+	fn hex_char(&mut self, offset: usize, len: usize) -> Result<char, ParseError> {
+		let digits = self
+			.line
+			.get(self.offset..self.offset + len)
+			.filter(|digits| digits.chars().all(|c| c.is_ascii_hexdigit()))
+			.ok_or_else(|| self.error_at(offset, ParseErrorKind::InvalidEscape))?;
+
+		self.offset += len;
+		u32::from_str_radix(digits, 16)
+			.ok()
+			.and_then(char::from_u32)
+			.ok_or_else(|| self.error_at(offset, ParseErrorKind::InvalidEscape))
+	}
+}
@@ -1,6 +1,6 @@
 use iref::{Iri, IriBuf};
 use rdf_types::{
-	BlankId, BlankIdBuf, Id, Interpretation, RDF_FIRST, RDF_REST, Vocabulary,
+	BlankId, BlankIdBuf, Id, Interpretation, Vocabulary,
 	dataset::PatternMatchingDataset,
 	interpretation::{
 		IriInterpretation, ReverseBlankIdInterpretation, ReverseIdInterpretation,
@@ -8,7 +8,7 @@ use rdf_types::{
 	},
 	vocabulary::{BlankIdVocabularyMut, IriVocabularyMut},
 };
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
 use std::hash::Hash;
 
 use crate::{
@@ -97,7 +97,23 @@ impl<
 	where
 		S: PredicateObjectsVisitor<I, V>,
 	{
-		visitor.object(&RdfList(self.as_slice()))?;
+		visitor.object(&RdfList(self.iter()))?;
+		visitor.end()
+	}
+}
+
+/// Serialized as an `rdf:List`, like `Vec<T>`.
+impl<
+	I: Interpretation,
+	V: Vocabulary + IriVocabularyMut,
+	T: LinkedDataSubject<I, V> + LinkedDataResource<I, V>,
+> LinkedDataPredicateObjects<I, V> for VecDeque<T>
+{
+	fn visit_objects<S>(&self, mut visitor: S) -> Result<S::Ok, S::Error>
+	where
+		S: PredicateObjectsVisitor<I, V>,
+	{
+		visitor.object(&RdfList(self.iter()))?;
 		visitor.end()
 	}
 }
@@ -359,10 +375,14 @@ where
 /// Deserialized by walking an `rdf:List` (a chain of blank nodes linked by
 /// `rdf:first`/`rdf:rest`, terminated by `rdf:nil`), preserving element
 /// order. A predicate with no value at all is treated as an empty list.
+///
+/// The list is read with the [`RdfListReader`](crate::RdfListReader) of the
+/// context.
 impl<I: Interpretation, V: Vocabulary, T: LinkedDataDeserializeSubject<I, V>>
 	LinkedDataDeserializePredicateObjects<I, V> for Vec<T>
 where
 	I: ReverseIriInterpretation<Iri = V::Iri> + IriInterpretation<V::Iri>,
+	I::Resource: PartialEq,
 {
 	fn deserialize_objects_in<'a, D>(
 		vocabulary: &V,
@@ -378,7 +398,7 @@ where
 	{
 		let mut objects = objects.into_iter();
 
-		let head = match objects.next() {
+		match objects.next() {
 			Some(head) => {
 				if objects.next().is_some() {
 					return Err(FromLinkedDataError::TooManyValues(
@@ -386,50 +406,36 @@ where
 					));
 				}
 
-				head
-			}
-			None => return Ok(Vec::new()),
-		};
-
-		let (Some(first_predicate), Some(rest_predicate)) = (
-			interpretation.lexical_iri_interpretation(vocabulary, RDF_FIRST),
-			interpretation.lexical_iri_interpretation(vocabulary, RDF_REST),
-		) else {
-			return Ok(Vec::new());
-		};
-
-		let mut result = Vec::new();
-		let mut node = head;
-
-		loop {
-			let mut firsts = dataset.quad_objects(graph, node, &first_predicate);
-			let Some(item) = firsts.next() else {
-				break;
-			};
-
-			if firsts.next().is_some() {
-				return Err(FromLinkedDataError::TooManyValues(
-					context.into_iris(vocabulary, interpretation),
-				));
-			}
-
-			result.push(T::deserialize_subject_in(
-				vocabulary,
-				interpretation,
-				dataset,
-				graph,
-				item,
-				context,
-			)?);
-
-			let mut rests = dataset.quad_objects(graph, node, &rest_predicate);
-			match rests.next() {
-				Some(next) => node = next,
-				None => break,
+				context
+					.list_reader
+					.read(vocabulary, interpretation, dataset, graph, head, context)
 			}
+			None => Ok(Vec::new()),
 		}
+	}
+}
 
-		Ok(result)
+/// Deserialized from an `rdf:List`, like `Vec<T>`.
+impl<I: Interpretation, V: Vocabulary, T: LinkedDataDeserializeSubject<I, V>>
+	LinkedDataDeserializePredicateObjects<I, V> for VecDeque<T>
+where
+	I: ReverseIriInterpretation<Iri = V::Iri> + IriInterpretation<V::Iri>,
+	I::Resource: PartialEq,
+{
+	fn deserialize_objects_in<'a, D>(
+		vocabulary: &V,
+		interpretation: &I,
+		dataset: &D,
+		graph: Option<&I::Resource>,
+		objects: impl IntoIterator<Item = &'a I::Resource>,
+		context: Context<I>,
+	) -> Result<Self, FromLinkedDataError>
+	where
+		I::Resource: 'a,
+		D: PatternMatchingDataset<Resource = I::Resource>,
+	{
+		Vec::deserialize_objects_in(vocabulary, interpretation, dataset, graph, objects, context)
+			.map(VecDeque::from)
 	}
 }
 
@@ -2,6 +2,7 @@ use educe::Educe;
 use iref::IriBuf;
 use rdf_types::{
 	Generator, Id, Interpretation, InterpretationMut, Quad, Term, Vocabulary,
+	dataset::{BTreeDataset, IndexedBTreeDataset},
 	interpretation::{
 		self, BlankIdInterpretationMut, IriInterpretationMut, LiteralInterpretationMut,
 		ReverseBlankIdInterpretation, ReverseIriInterpretation, ReverseTermInterpretation,
@@ -18,6 +19,55 @@ use crate::{
 	SubjectVisitor, Visitor,
 };
 
+/// Quad consumer.
+///
+/// Serializers push each quad into the sink as soon as it is produced,
+/// without buffering the whole output first.
+pub trait QuadSink<Q> {
+	/// Error returned when a quad could not be consumed.
+	///
+	/// Serialization errors are converted into this type.
+	type Error: From<IntoQuadsError>;
+
+	/// Consumes the given quad.
+	fn push(&mut self, quad: Q) -> Result<(), Self::Error>;
+}
+
+impl<Q> QuadSink<Q> for Vec<Q> {
+	type Error = IntoQuadsError;
+
+	fn push(&mut self, quad: Q) -> Result<(), Self::Error> {
+		Vec::push(self, quad);
+		Ok(())
+	}
+}
+
+impl<Q, S: QuadSink<Q>> QuadSink<Q> for &mut S {
+	type Error = S::Error;
+
+	fn push(&mut self, quad: Q) -> Result<(), Self::Error> {
+		S::push(self, quad)
+	}
+}
+
+impl QuadSink<RdfQuad> for IndexedBTreeDataset {
+	type Error = IntoQuadsError;
+
+	fn push(&mut self, Quad(s, p, o, g): RdfQuad) -> Result<(), Self::Error> {
+		self.insert(Quad(Term::Id(s), Term::iri(p), o, g.map(Term::Id)));
+		Ok(())
+	}
+}
+
+impl QuadSink<RdfQuad> for BTreeDataset {
+	type Error = IntoQuadsError;
+
+	fn push(&mut self, Quad(s, p, o, g): RdfQuad) -> Result<(), Self::Error> {
+		self.insert(Quad(Term::Id(s), Term::iri(p), o, g.map(Term::Id)));
+		Ok(())
+	}
+}
+
 pub fn to_interpreted_quads<I, V>(
 	vocabulary: &mut V,
 	interpretation: &mut I,
@@ -31,12 +81,33 @@ where
 	V: Vocabulary + IriVocabularyMut + LiteralVocabularyMut,
 	V::Iri: Clone,
 	V::BlankId: Clone,
+{
+	let mut result = Vec::new();
+	to_interpreted_quads_into(vocabulary, interpretation, &mut result, value)?;
+	Ok(result)
+}
+
+pub fn to_interpreted_quads_into<I, V, S>(
+	vocabulary: &mut V,
+	interpretation: &mut I,
+	sink: &mut S,
+	value: &impl LinkedData<I, V>,
+) -> Result<(), S::Error>
+where
+	I: Interpretation
+		+ InterpretationMut<V>
+		+ TermInterpretationMut<V::Iri, V::BlankId, V::Literal>,
+	I::Resource: Clone,
+	V: Vocabulary + IriVocabularyMut + LiteralVocabularyMut,
+	V::Iri: Clone,
+	V::BlankId: Clone,
+	S: QuadSink<InterpretedQuad<I>>,
 {
 	value.visit(QuadSerializer {
 		vocabulary,
 		interpretation,
 		domain: &mut InterpretationDomain,
-		result: Vec::new(),
+		sink,
 	})
 }
 
@@ -70,7 +141,7 @@ where
 		domain: &mut InterpretationDomain,
 		graph,
 		subject: SubjectOrObject::Subject(&subject),
-		result: &mut result,
+		sink: &mut result,
 	})?;
 
 	Ok((subject, result))
@@ -104,7 +175,7 @@ where
 		interpretation,
 		domain: &mut InterpretationDomain,
 		graph: Some(&graph),
-		result: &mut result,
+		sink: &mut result,
 	})?;
 
 	Ok((graph, result))
@@ -119,6 +190,23 @@ where
 	I: Interpretation
 		+ InterpretationMut<V>
 		+ ReverseTermInterpretation<Iri = V::Iri, BlankId = V::BlankId, Literal = V::Literal>,
+{
+	let mut result = Vec::new();
+	to_lexical_quads_into_with(vocabulary, interpretation, &mut result, value)?;
+	Ok(result)
+}
+
+pub fn to_lexical_quads_into_with<I, V: Vocabulary, S>(
+	vocabulary: &mut V,
+	interpretation: &mut I,
+	sink: &mut S,
+	value: &impl LinkedData<I, V>,
+) -> Result<(), S::Error>
+where
+	I: Interpretation
+		+ InterpretationMut<V>
+		+ ReverseTermInterpretation<Iri = V::Iri, BlankId = V::BlankId, Literal = V::Literal>,
+	S: QuadSink<RdfQuad>,
 {
 	let mut domain = LexicalDomain;
 
@@ -126,7 +214,7 @@ where
 		vocabulary,
 		interpretation,
 		domain: &mut domain,
-		result: Vec::new(),
+		sink,
 	})
 }
 
@@ -153,7 +241,7 @@ where
 		domain: &mut LexicalDomain,
 		graph,
 		subject: SubjectOrObject::Subject(&subject),
-		result: &mut result,
+		sink: &mut result,
 	})?;
 
 	Ok((subject, result))
@@ -167,6 +255,17 @@ pub fn to_lexical_quads<G: Generator>(
 	to_lexical_quads_with(&mut (), &mut interpretation, value)
 }
 
+/// Serializes the given value using lexical terms, pushing each quad into
+/// `sink` as soon as it is produced.
+pub fn to_lexical_quads_into<G: Generator, S: QuadSink<RdfQuad>>(
+	generator: G,
+	sink: &mut S,
+	value: &impl LinkedData<interpretation::WithGenerator<G>>,
+) -> Result<(), S::Error> {
+	let mut interpretation = rdf_types::interpretation::WithGenerator::new((), generator);
+	to_lexical_quads_into_with(&mut (), &mut interpretation, sink, value)
+}
+
 pub fn to_lexical_subject_quads<G: Generator>(
 	generator: G,
 	graph: Option<&Id>,
@@ -191,6 +290,26 @@ where
 	V::Literal: Clone,
 	I: InterpretationMut<V>
 		+ ReverseTermInterpretation<Iri = V::Iri, BlankId = V::BlankId, Literal = V::Literal>,
+{
+	let mut result = Vec::new();
+	to_quads_into_with(vocabulary, interpretation, &mut result, value)?;
+	Ok(result)
+}
+
+pub fn to_quads_into_with<I, V, S>(
+	vocabulary: &mut V,
+	interpretation: &mut I,
+	sink: &mut S,
+	value: &impl LinkedData<I, V>,
+) -> Result<(), S::Error>
+where
+	V: Vocabulary + IriVocabularyMut + LiteralVocabularyMut,
+	V::BlankId: Clone,
+	V::Iri: Clone,
+	V::Literal: Clone,
+	I: InterpretationMut<V>
+		+ ReverseTermInterpretation<Iri = V::Iri, BlankId = V::BlankId, Literal = V::Literal>,
+	S: QuadSink<RdfQuad<V>>,
 {
 	let mut domain = VocabularyDomain;
 
@@ -198,7 +317,7 @@ where
 		vocabulary,
 		interpretation,
 		domain: &mut domain,
-		result: Vec::new(),
+		sink,
 	})
 }
 
@@ -210,6 +329,17 @@ pub fn to_quads<G: Generator>(
 	to_quads_with(&mut (), &mut interpretation, value)
 }
 
+/// Serializes the given value, pushing each quad into `sink` as soon as it
+/// is produced.
+pub fn to_quads_into<G: Generator, S: QuadSink<RdfQuad>>(
+	generator: G,
+	sink: &mut S,
+	value: &impl LinkedData<interpretation::WithGenerator<G>>,
+) -> Result<(), S::Error> {
+	let mut interpretation = interpretation::WithGenerator::new((), generator);
+	to_quads_into_with(&mut (), &mut interpretation, sink, value)
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum IntoQuadsError {
 	#[error("invalid graph label")]
@@ -918,19 +1048,21 @@ where
 	}
 }
 
-/// A simple serializer generating a list of `Quad`s.
-struct QuadSerializer<'a, I: Interpretation, V: Vocabulary, D: Domain<I, V>> {
+/// A simple serializer pushing every generated `Quad` into a [`QuadSink`].
+struct QuadSerializer<'a, I: Interpretation, V: Vocabulary, D: Domain<I, V>, S> {
 	vocabulary: &'a mut V,
 	interpretation: &'a mut I,
 	domain: &'a mut D,
-	result: Vec<DomainQuad<I, V, D>>,
+	sink: &'a mut S,
 }
 
-impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> Visitor<I, V>
-	for QuadSerializer<'_, I, V, D>
+impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>, S> Visitor<I, V>
+	for QuadSerializer<'_, I, V, D, S>
+where
+	S: QuadSink<DomainQuad<I, V, D>>,
 {
-	type Ok = Vec<DomainQuad<I, V, D>>;
-	type Error = IntoQuadsError;
+	type Ok = ();
+	type Error = S::Error;
 
 	fn default_graph<T>(&mut self, value: &T) -> Result<(), Self::Error>
 	where
@@ -940,7 +1072,7 @@ impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> Visitor<I, V>
 			vocabulary: self.vocabulary,
 			interpretation: self.interpretation,
 			domain: self.domain,
-			result: &mut self.result,
+			sink: self.sink,
 			graph: None,
 		};
 
@@ -958,7 +1090,7 @@ impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> Visitor<I, V>
 			vocabulary: self.vocabulary,
 			interpretation: self.interpretation,
 			domain: self.domain,
-			result: &mut self.result,
+			sink: self.sink,
 			graph: Some(&graph),
 		};
 
@@ -966,23 +1098,25 @@ impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> Visitor<I, V>
 	}
 
 	fn end(self) -> Result<Self::Ok, Self::Error> {
-		Ok(self.result)
+		Ok(())
 	}
 }
 
-struct QuadGraphSerializer<'a, I: Interpretation, V: Vocabulary, D: Domain<I, V>> {
+struct QuadGraphSerializer<'a, I: Interpretation, V: Vocabulary, D: Domain<I, V>, S> {
 	vocabulary: &'a mut V,
 	interpretation: &'a mut I,
 	domain: &'a mut D,
-	result: &'a mut Vec<DomainQuad<I, V, D>>,
+	sink: &'a mut S,
 	graph: Option<&'a D::Subject>,
 }
 
-impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> GraphVisitor<I, V>
-	for QuadGraphSerializer<'_, I, V, D>
+impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>, S> GraphVisitor<I, V>
+	for QuadGraphSerializer<'_, I, V, D, S>
+where
+	S: QuadSink<DomainQuad<I, V, D>>,
 {
 	type Ok = ();
-	type Error = IntoQuadsError;
+	type Error = S::Error;
 
 	fn subject<T>(&mut self, value: &T) -> Result<(), Self::Error>
 	where
@@ -997,7 +1131,7 @@ impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> GraphVisitor<I, V>
 			vocabulary: self.vocabulary,
 			interpretation: self.interpretation,
 			domain: self.domain,
-			result: self.result,
+			sink: self.sink,
 			graph: self.graph,
 			subject: SubjectOrObject::Subject(&term),
 		};
@@ -1010,11 +1144,11 @@ impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> GraphVisitor<I, V>
 	}
 }
 
-struct QuadPropertiesSerializer<'a, I: Interpretation, V: Vocabulary, D: Domain<I, V>> {
+struct QuadPropertiesSerializer<'a, I: Interpretation, V: Vocabulary, D: Domain<I, V>, S> {
 	vocabulary: &'a mut V,
 	interpretation: &'a mut I,
 	domain: &'a mut D,
-	result: &'a mut Vec<DomainQuad<I, V, D>>,
+	sink: &'a mut S,
 	graph: Option<&'a D::Subject>,
 	subject: SubjectOrObject<'a, I, V, D>,
 }
@@ -1042,11 +1176,13 @@ impl<'a, I: Interpretation, V: Vocabulary, D: Domain<I, V>> SubjectOrObject<'a,
 	}
 }
 
-impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> SubjectVisitor<I, V>
-	for QuadPropertiesSerializer<'_, I, V, D>
+impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>, S> SubjectVisitor<I, V>
+	for QuadPropertiesSerializer<'_, I, V, D, S>
+where
+	S: QuadSink<DomainQuad<I, V, D>>,
 {
 	type Ok = ();
-	type Error = IntoQuadsError;
+	type Error = S::Error;
 
 	fn predicate<L, T>(&mut self, predicate: &L, value: &T) -> Result<(), Self::Error>
 	where
@@ -1064,7 +1200,7 @@ impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> SubjectVisitor<I, V>
 			vocabulary: self.vocabulary,
 			interpretation: self.interpretation,
 			domain: self.domain,
-			result: self.result,
+			sink: self.sink,
 			graph: self.graph,
 			subject,
 			predicate: term,
@@ -1089,7 +1225,7 @@ impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> SubjectVisitor<I, V>
 			vocabulary: self.vocabulary,
 			interpretation: self.interpretation,
 			domain: self.domain,
-			result: self.result,
+			sink: self.sink,
 			graph: self.graph,
 			object,
 			predicate: term,
@@ -1108,7 +1244,7 @@ impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> SubjectVisitor<I, V>
 			vocabulary: self.vocabulary,
 			interpretation: self.interpretation,
 			domain: self.domain,
-			result: self.result,
+			sink: self.sink,
 			graph: Some(graph),
 		};
 
@@ -1130,7 +1266,7 @@ impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> SubjectVisitor<I, V>
 			domain: self.domain,
 			graph: self.graph,
 			subject: SubjectOrObject::Subject(&subject),
-			result: self.result,
+			sink: self.sink,
 		})?;
 
 		Ok(())
@@ -1141,21 +1277,23 @@ impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> SubjectVisitor<I, V>
 	}
 }
 
-struct ObjectsSerializer<'a, I: Interpretation, V: Vocabulary, D: Domain<I, V>> {
+struct ObjectsSerializer<'a, I: Interpretation, V: Vocabulary, D: Domain<I, V>, S> {
 	vocabulary: &'a mut V,
 	interpretation: &'a mut I,
 	domain: &'a mut D,
-	result: &'a mut Vec<DomainQuad<I, V, D>>,
+	sink: &'a mut S,
 	graph: Option<&'a D::Subject>,
 	subject: &'a D::Subject,
 	predicate: D::Predicate,
 }
 
-impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> PredicateObjectsVisitor<I, V>
-	for ObjectsSerializer<'_, I, V, D>
+impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>, S> PredicateObjectsVisitor<I, V>
+	for ObjectsSerializer<'_, I, V, D, S>
+where
+	S: QuadSink<DomainQuad<I, V, D>>,
 {
 	type Ok = ();
-	type Error = IntoQuadsError;
+	type Error = S::Error;
 
 	fn object<T>(&mut self, value: &T) -> Result<(), Self::Error>
 	where
@@ -1169,19 +1307,18 @@ impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> PredicateObjectsVisitor<
 			vocabulary: self.vocabulary,
 			interpretation: self.interpretation,
 			domain: self.domain,
-			result: self.result,
+			sink: self.sink,
 			graph: self.graph,
 			subject: SubjectOrObject::Object(&term),
 		};
 
 		value.visit_subject(subject_serializer)?;
-		self.result.push(Quad(
+		self.sink.push(Quad(
 			self.subject.clone(),
 			self.predicate.clone(),
 			term,
 			self.graph.cloned(),
-		));
-		Ok(())
+		))
 	}
 
 	fn end(self) -> Result<Self::Ok, Self::Error> {
@@ -1189,21 +1326,23 @@ impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> PredicateObjectsVisitor<
 	}
 }
 
-struct ReversePredicateSerializer<'a, I: Interpretation, V: Vocabulary, D: Domain<I, V>> {
+struct ReversePredicateSerializer<'a, I: Interpretation, V: Vocabulary, D: Domain<I, V>, S> {
 	vocabulary: &'a mut V,
 	interpretation: &'a mut I,
 	domain: &'a mut D,
-	result: &'a mut Vec<DomainQuad<I, V, D>>,
+	sink: &'a mut S,
 	graph: Option<&'a D::Subject>,
 	object: D::ObjectRef<'a>,
 	predicate: D::Predicate,
 }
 
-impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> PredicateObjectsVisitor<I, V>
-	for ReversePredicateSerializer<'_, I, V, D>
+impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>, S> PredicateObjectsVisitor<I, V>
+	for ReversePredicateSerializer<'_, I, V, D, S>
+where
+	S: QuadSink<DomainQuad<I, V, D>>,
 {
 	type Ok = ();
-	type Error = IntoQuadsError;
+	type Error = S::Error;
 
 	fn object<T>(&mut self, value: &T) -> Result<(), Self::Error>
 	where
@@ -1218,19 +1357,18 @@ impl<I: Interpretation, V: Vocabulary, D: Domain<I, V>> PredicateObjectsVisitor<
 			vocabulary: self.vocabulary,
 			interpretation: self.interpretation,
 			domain: self.domain,
-			result: self.result,
+			sink: self.sink,
 			graph: self.graph,
 			subject: SubjectOrObject::Subject(&subject),
 		};
 
 		value.visit_subject(subject_serializer)?;
-		self.result.push(Quad(
+		self.sink.push(Quad(
 			subject,
 			self.predicate.clone(),
 			D::cloned_object_ref(self.object),
 			self.graph.cloned(),
-		));
-		Ok(())
+		))
 	}
 
 	fn end(self) -> Result<Self::Ok, Self::Error> {
@@ -0,0 +1,296 @@
+//! RDF containers (`rdf:Seq`, `rdf:Bag` and `rdf:Alt`).
+use iref::{Iri, IriBuf};
+use rdf_types::{
+	Interpretation, Quad, RDF_ALT, RDF_BAG, RDF_SEQ, RDF_TYPE, Vocabulary,
+	dataset::PatternMatchingDataset, interpretation::ReverseIriInterpretation,
+	pattern::CanonicalQuadPattern, vocabulary::IriVocabularyMut,
+};
+use std::ops::{Deref, DerefMut};
+
+use crate::{
+	Context, FromLinkedDataError, LinkedDataDeserializePredicateObjects,
+	LinkedDataDeserializeSubject, LinkedDataPredicateObjects, LinkedDataResource,
+	LinkedDataSubject, PredicateObjectsVisitor, ResourceInterpretation, SubjectVisitor,
+	rdf_list::Single,
+};
+
+const RDF_MEMBER_PREFIX: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#_";
+
+/// Returns the `rdf:_n` membership property of the given index, starting at 1.
+fn membership_property(n: usize) -> IriBuf {
+	IriBuf::new(format!("{RDF_MEMBER_PREFIX}{n}")).unwrap()
+}
+
+/// Returns the index of the given `rdf:_n` membership property.
+fn membership_index(iri: &Iri) -> Option<usize> {
+	let n = iri.as_str().strip_prefix(RDF_MEMBER_PREFIX)?;
+	if n.starts_with('0') || !n.bytes().all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+
+	n.parse().ok()
+}
+
+/// Reason why an RDF container is malformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum MalformedContainerReason {
+	#[error("missing member `rdf:_{0}`")]
+	Gap(usize),
+
+	#[error("multiple members `rdf:_{0}`")]
+	DuplicateMember(usize),
+}
+
+/// Deserializes the members of the container `resource`, in order.
+fn deserialize_members<I, V, D, T>(
+	vocabulary: &V,
+	interpretation: &I,
+	dataset: &D,
+	graph: Option<&I::Resource>,
+	resource: &I::Resource,
+	class: &Iri,
+	context: Context<I>,
+) -> Result<Vec<T>, FromLinkedDataError>
+where
+	I: Interpretation + ReverseIriInterpretation<Iri = V::Iri>,
+	V: Vocabulary,
+	D: PatternMatchingDataset<Resource = I::Resource>,
+	T: LinkedDataDeserializeSubject<I, V>,
+{
+	let context = context.with_subject(resource);
+	let mut types = Vec::new();
+	let mut members = Vec::new();
+
+	let quads = dataset.quad_pattern_matching(CanonicalQuadPattern::from_option_quad(Quad(
+		Some(resource),
+		None,
+		None,
+		Some(graph),
+	)));
+
+	for Quad(_, predicate, object, _) in quads {
+		for i in interpretation.iris_of(predicate) {
+			let iri = vocabulary.iri(i).unwrap();
+			if iri == RDF_TYPE {
+				types.extend(
+					interpretation
+						.iris_of(object)
+						.map(|i| vocabulary.iri(i).unwrap().to_owned()),
+				)
+			} else if let Some(n) = membership_index(iri) {
+				members.push((n, predicate, object))
+			}
+		}
+	}
+
+	if !types.is_empty() && !types.iter().any(|ty| ty == class) {
+		return Err(FromLinkedDataError::TypeMismatch {
+			context: context
+				.with_predicate_iri(RDF_TYPE)
+				.into_iris(vocabulary, interpretation),
+			expected: vec![class.to_owned()],
+			found: types,
+		});
+	}
+
+	members.sort_by_key(|(n, _, _)| *n);
+
+	let mut result = Vec::with_capacity(members.len());
+	for (expected, (n, predicate, object)) in (1..).zip(members) {
+		let context = context.with_predicate(predicate);
+
+		if n != expected {
+			let reason = if n < expected {
+				MalformedContainerReason::DuplicateMember(n)
+			} else {
+				MalformedContainerReason::Gap(expected)
+			};
+
+			return Err(FromLinkedDataError::MalformedContainer {
+				context: context.into_iris(vocabulary, interpretation),
+				reason,
+			});
+		}
+
+		result.push(T::deserialize_subject_in(
+			vocabulary,
+			interpretation,
+			dataset,
+			graph,
+			object,
+			context,
+		)?);
+	}
+
+	Ok(result)
+}
+
+macro_rules! container {
+	($(#[$meta:meta])* $id:ident: $class:ident) => {
+		$(#[$meta])*
+		#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+		pub struct $id<T>(pub Vec<T>);
+
+		impl<T> From<Vec<T>> for $id<T> {
+			fn from(value: Vec<T>) -> Self {
+				Self(value)
+			}
+		}
+
+		impl<T> Deref for $id<T> {
+			type Target = Vec<T>;
+
+			fn deref(&self) -> &Self::Target {
+				&self.0
+			}
+		}
+
+		impl<T> DerefMut for $id<T> {
+			fn deref_mut(&mut self) -> &mut Self::Target {
+				&mut self.0
+			}
+		}
+
+		impl<I: Interpretation, V: Vocabulary, T> LinkedDataResource<I, V> for $id<T> {
+			fn interpretation(
+				&self,
+				_vocabulary: &mut V,
+				_interpretation: &mut I,
+			) -> ResourceInterpretation<'_, I, V> {
+				ResourceInterpretation::Uninterpreted(None)
+			}
+		}
+
+		impl<
+			I: Interpretation,
+			V: Vocabulary + IriVocabularyMut,
+			T: LinkedDataSubject<I, V> + LinkedDataResource<I, V>,
+		> LinkedDataSubject<I, V> for $id<T>
+		{
+			fn visit_subject<S>(&self, mut serializer: S) -> Result<S::Ok, S::Error>
+			where
+				S: SubjectVisitor<I, V>,
+			{
+				serializer.predicate(RDF_TYPE, $class)?;
+
+				for (i, item) in self.0.iter().enumerate() {
+					serializer.predicate(&membership_property(i + 1), &Single(item))?;
+				}
+
+				serializer.end()
+			}
+		}
+
+		impl<
+			I: Interpretation,
+			V: Vocabulary + IriVocabularyMut,
+			T: LinkedDataSubject<I, V> + LinkedDataResource<I, V>,
+		> LinkedDataPredicateObjects<I, V> for $id<T>
+		{
+			fn visit_objects<S>(&self, mut visitor: S) -> Result<S::Ok, S::Error>
+			where
+				S: PredicateObjectsVisitor<I, V>,
+			{
+				visitor.object(self)?;
+				visitor.end()
+			}
+		}
+
+		impl<I: Interpretation, V: Vocabulary, T: LinkedDataDeserializeSubject<I, V>>
+			LinkedDataDeserializeSubject<I, V> for $id<T>
+		where
+			I: ReverseIriInterpretation<Iri = V::Iri>,
+		{
+			fn deserialize_subject_in<D>(
+				vocabulary: &V,
+				interpretation: &I,
+				dataset: &D,
+				graph: Option<&I::Resource>,
+				resource: &I::Resource,
+				context: Context<I>,
+			) -> Result<Self, FromLinkedDataError>
+			where
+				D: PatternMatchingDataset<Resource = I::Resource>,
+			{
+				deserialize_members(
+					vocabulary,
+					interpretation,
+					dataset,
+					graph,
+					resource,
+					$class,
+					context,
+				)
+				.map(Self)
+			}
+		}
+
+		impl<I: Interpretation, V: Vocabulary, T: LinkedDataDeserializeSubject<I, V>>
+			LinkedDataDeserializePredicateObjects<I, V> for $id<T>
+		where
+			I: ReverseIriInterpretation<Iri = V::Iri>,
+		{
+			fn deserialize_objects_in<'a, D>(
+				vocabulary: &V,
+				interpretation: &I,
+				dataset: &D,
+				graph: Option<&I::Resource>,
+				objects: impl IntoIterator<Item = &'a I::Resource>,
+				context: Context<I>,
+			) -> Result<Self, FromLinkedDataError>
+			where
+				I::Resource: 'a,
+				D: PatternMatchingDataset<Resource = I::Resource>,
+			{
+				let mut objects = objects.into_iter();
+				match objects.next() {
+					Some(object) => {
+						if objects.next().is_none() {
+							Self::deserialize_subject_in(
+								vocabulary,
+								interpretation,
+								dataset,
+								graph,
+								object,
+								context,
+							)
+						} else {
+							Err(FromLinkedDataError::TooManyValues(
+								context.into_iris(vocabulary, interpretation),
+							))
+						}
+					}
+					None => Err(FromLinkedDataError::MissingRequiredValue(
+						context.into_iris(vocabulary, interpretation),
+					)),
+				}
+			}
+		}
+	};
+}
+
+container! {
+	/// `rdf:Seq` container, an ordered sequence of members.
+	///
+	/// Members are bound to the container with the `rdf:_1`, `rdf:_2`, ...
+	/// membership properties. Deserialization preserves the membership order
+	/// and fails if an index is missing or duplicated.
+	Seq: RDF_SEQ
+}
+
+container! {
+	/// `rdf:Bag` container, an unordered collection of members.
+	///
+	/// Members are bound to the container with the `rdf:_1`, `rdf:_2`, ...
+	/// membership properties, like [`Seq`].
+	Bag: RDF_BAG
+}
+
+container! {
+	/// `rdf:Alt` container, a collection of alternatives. The first member is
+	/// the default choice.
+	///
+	/// Members are bound to the container with the `rdf:_1`, `rdf:_2`, ...
+	/// membership properties, like [`Seq`].
+	Alt: RDF_ALT
+}
@@ -1,16 +1,19 @@
 use rdf_types::{
 	Id, Interpretation, RDF_FIRST, RDF_NIL, RDF_REST, Term, Vocabulary,
+	dataset::PatternMatchingDataset,
+	interpretation::{IriInterpretation, ReverseIriInterpretation},
 	vocabulary::IriVocabularyMut,
 };
 
 use crate::{
-	CowRdfTerm, LinkedDataPredicateObjects, LinkedDataResource, LinkedDataSubject,
-	PredicateObjectsVisitor, ResourceInterpretation, SubjectVisitor,
+	Context, CowRdfTerm, FromLinkedDataError, LinkedDataDeserializeSubject,
+	LinkedDataPredicateObjects, LinkedDataResource, LinkedDataSubject, PredicateObjectsVisitor,
+	ResourceInterpretation, SubjectVisitor,
 };
 
 /// Binds a single value as the object of a predicate, regardless of whether
 /// that value implements [`LinkedDataPredicateObjects`] itself.
-struct Single<'a, T>(&'a T);
+pub(crate) struct Single<'a, T>(pub &'a T);
 
 impl<I: Interpretation, V: Vocabulary, T: LinkedDataResource<I, V> + LinkedDataSubject<I, V>>
 	LinkedDataPredicateObjects<I, V> for Single<'_, T>
@@ -24,22 +27,24 @@ impl<I: Interpretation, V: Vocabulary, T: LinkedDataResource<I, V> + LinkedDataS
 	}
 }
 
-/// `rdf:List` representation of a slice.
+/// `rdf:List` representation of a sequence of items.
 ///
-/// An empty slice is serialized as the `rdf:nil` resource. A non-empty
-/// slice is serialized as a chain of blank nodes linked by `rdf:first` and
+/// An empty sequence is serialized as the `rdf:nil` resource. A non-empty
+/// sequence is serialized as a chain of blank nodes linked by `rdf:first` and
 /// `rdf:rest`, terminated by `rdf:nil`.
-pub(crate) struct RdfList<'a, T>(pub &'a [T]);
+pub(crate) struct RdfList<J>(pub J);
 
-impl<I: Interpretation, V: Vocabulary + IriVocabularyMut, T> LinkedDataResource<I, V>
-	for RdfList<'_, T>
+impl<'a, I: Interpretation, V: Vocabulary + IriVocabularyMut, T: 'a, J> LinkedDataResource<I, V>
+	for RdfList<J>
+where
+	J: Clone + Iterator<Item = &'a T>,
 {
 	fn interpretation(
 		&self,
 		vocabulary: &mut V,
 		_interpretation: &mut I,
 	) -> ResourceInterpretation<'_, I, V> {
-		if self.0.is_empty() {
+		if self.0.clone().next().is_none() {
 			ResourceInterpretation::Uninterpreted(Some(CowRdfTerm::Owned(Term::Id(Id::Iri(
 				vocabulary.insert(RDF_NIL),
 			)))))
@@ -49,17 +54,18 @@ impl<I: Interpretation, V: Vocabulary + IriVocabularyMut, T> LinkedDataResource<
 	}
 }
 
-impl<
-	I: Interpretation,
-	V: Vocabulary + IriVocabularyMut,
+impl<'a, I: Interpretation, V: Vocabulary + IriVocabularyMut, T: 'a, J> LinkedDataSubject<I, V>
+	for RdfList<J>
+where
 	T: LinkedDataResource<I, V> + LinkedDataSubject<I, V>,
-> LinkedDataSubject<I, V> for RdfList<'_, T>
+	J: Clone + Iterator<Item = &'a T>,
 {
 	fn visit_subject<S>(&self, mut serializer: S) -> Result<S::Ok, S::Error>
 	where
 		S: SubjectVisitor<I, V>,
 	{
-		if let Some((first, rest)) = self.0.split_first() {
+		let mut rest = self.0.clone();
+		if let Some(first) = rest.next() {
 			serializer.predicate(RDF_FIRST, &Single(first))?;
 			serializer.predicate(RDF_REST, &RdfList(rest))?;
 		}
@@ -68,11 +74,11 @@ impl<
 	}
 }
 
-impl<
-	I: Interpretation,
-	V: Vocabulary + IriVocabularyMut,
+impl<'a, I: Interpretation, V: Vocabulary + IriVocabularyMut, T: 'a, J>
+	LinkedDataPredicateObjects<I, V> for RdfList<J>
+where
 	T: LinkedDataResource<I, V> + LinkedDataSubject<I, V>,
-> LinkedDataPredicateObjects<I, V> for RdfList<'_, T>
+	J: Clone + Iterator<Item = &'a T>,
 {
 	fn visit_objects<S>(&self, mut visitor: S) -> Result<S::Ok, S::Error>
 	where
@@ -82,3 +88,164 @@ impl<
 		visitor.end()
 	}
 }
+
+/// Reason why an `rdf:List` is malformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum MalformedListReason {
+	#[error("missing `rdf:first` value")]
+	MissingFirst,
+
+	#[error("multiple `rdf:first` values")]
+	MultipleFirsts,
+
+	#[error("missing `rdf:rest` value, the list is not terminated by `rdf:nil`")]
+	MissingRest,
+
+	#[error("multiple `rdf:rest` values")]
+	MultipleRests,
+}
+
+/// `rdf:List` reader.
+///
+/// Walks the `rdf:first`/`rdf:rest` chain of a list, checking that every node
+/// has exactly one of each, that the chain is terminated by `rdf:nil` without
+/// looping back on itself, and that it does not exceed the maximum length.
+///
+/// `Vec<T>` and `VecDeque<T>` values are read with the reader of the
+/// deserialization context, set with [`Context::with_list_reader`].
+#[derive(Debug, Clone, Copy)]
+pub struct RdfListReader {
+	/// Maximum number of items, if any.
+	pub max_length: Option<usize>,
+}
+
+impl Default for RdfListReader {
+	fn default() -> Self {
+		Self {
+			max_length: Some(Self::DEFAULT_MAX_LENGTH),
+		}
+	}
+}
+
+impl RdfListReader {
+	/// Maximum number of items read by default.
+	pub const DEFAULT_MAX_LENGTH: usize = 1 << 16;
+
+	/// Creates a reader with the default maximum length.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates a reader accepting lists of at most `max_length` items.
+	pub fn with_max_length(max_length: usize) -> Self {
+		Self {
+			max_length: Some(max_length),
+		}
+	}
+
+	/// Creates a reader accepting lists of any length.
+	pub fn unbounded() -> Self {
+		Self { max_length: None }
+	}
+
+	/// Reads the items of the list starting at `head`.
+	pub fn read<I, V, D, T>(
+		&self,
+		vocabulary: &V,
+		interpretation: &I,
+		dataset: &D,
+		graph: Option<&I::Resource>,
+		head: &I::Resource,
+		context: Context<I>,
+	) -> Result<Vec<T>, FromLinkedDataError>
+	where
+		I: Interpretation + ReverseIriInterpretation<Iri = V::Iri> + IriInterpretation<V::Iri>,
+		I::Resource: PartialEq,
+		V: Vocabulary,
+		D: PatternMatchingDataset<Resource = I::Resource>,
+		T: LinkedDataDeserializeSubject<I, V>,
+	{
+		let malformed = |reason| FromLinkedDataError::MalformedList {
+			context: context.into_iris(vocabulary, interpretation),
+			reason,
+		};
+
+		let is_nil = |node: &I::Resource| {
+			interpretation
+				.iris_of(node)
+				.any(|i| vocabulary.iri(i).unwrap() == RDF_NIL)
+		};
+
+		if is_nil(head) {
+			return Ok(Vec::new());
+		}
+
+		let first_predicate = interpretation.lexical_iri_interpretation(vocabulary, RDF_FIRST);
+		let rest_predicate = interpretation.lexical_iri_interpretation(vocabulary, RDF_REST);
+
+		let mut result = Vec::new();
+		let mut node = head;
+
+		// Brent's cycle detection, only requiring node equality.
+		let mut tortoise = head;
+		let mut power = 1usize;
+		let mut lambda = 0usize;
+
+		loop {
+			let mut firsts = first_predicate
+				.iter()
+				.flat_map(|p| dataset.quad_objects(graph, node, p));
+			let item = firsts
+				.next()
+				.ok_or_else(|| malformed(MalformedListReason::MissingFirst))?;
+			if firsts.next().is_some() {
+				return Err(malformed(MalformedListReason::MultipleFirsts));
+			}
+
+			if self.max_length.is_some_and(|max| result.len() >= max) {
+				return Err(FromLinkedDataError::ListTooLong {
+					context: context.into_iris(vocabulary, interpretation),
+					max_length: self.max_length.unwrap(),
+				});
+			}
+
+			result.push(T::deserialize_subject_in(
+				vocabulary,
+				interpretation,
+				dataset,
+				graph,
+				item,
+				context,
+			)?);
+
+			let mut rests = rest_predicate
+				.iter()
+				.flat_map(|p| dataset.quad_objects(graph, node, p));
+			let rest = rests
+				.next()
+				.ok_or_else(|| malformed(MalformedListReason::MissingRest))?;
+			if rests.next().is_some() {
+				return Err(malformed(MalformedListReason::MultipleRests));
+			}
+
+			if is_nil(rest) {
+				break Ok(result);
+			}
+
+			node = rest;
+
+			if node == tortoise {
+				return Err(FromLinkedDataError::CyclicList(
+					context.into_iris(vocabulary, interpretation),
+				));
+			}
+
+			lambda += 1;
+			if lambda == power {
+				tortoise = node;
+				power *= 2;
+				lambda = 0;
+			}
+		}
+	}
+}
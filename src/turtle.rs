@@ -0,0 +1,502 @@
+//! Turtle and TriG serialization and deserialization.
+//!
+//! Triples are grouped by subject, blank nodes referenced only once are nested
+//! as `[ ... ]` and `rdf:List` chains are written as `( ... )` collections.
+//! Named graphs are written as TriG graph blocks, so values that only use the
+//! default graph produce valid Turtle.
+//!
+//! The parser accepts both Turtle and TriG documents, and turns collections
+//! into `rdf:List` chains, as expected by the `Vec<T>` deserializer.
+use std::{
+	collections::{BTreeMap, HashMap, HashSet},
+	fmt::Write,
+	io,
+};
+
+use iref::{Iri, IriBuf};
+use rdf_types::{
+	BlankIdBuf, Generator, Id, Literal, LiteralType, RDF_FIRST, RDF_NIL, RDF_REST, RDF_TYPE,
+	RdfDisplay, Term, XSD_STRING, interpretation::WithGenerator,
+};
+
+use crate::{IntoQuadsError, LinkedData, RdfQuad, nquads::WriteError, to_quads};
+
+mod parser;
+
+pub use parser::*;
+
+/// Type declaring IRI prefixes.
+///
+/// This trait is automatically implemented by the `Serialize` derive macro
+/// for types with `#[ld(prefix(...))]` attributes. Only the prefixes of the
+/// serialized value type are used to compact the output: the prefixes
+/// declared by nested types are ignored. Use [`to_writer_with_prefixes`] to
+/// serialize a type without prefixes, or to provide more prefixes.
+pub trait LinkedDataPrefixes {
+	/// Prefix bindings, as `(prefix, namespace)` pairs.
+	const PREFIXES: &'static [(&'static str, &'static str)];
+}
+
+/// Prefix map used to compact IRIs.
+#[derive(Debug, Default, Clone)]
+pub struct PrefixMap(BTreeMap<String, String>);
+
+impl PrefixMap {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Binds `prefix` to the given namespace, replacing any previous binding.
+	pub fn insert(&mut self, prefix: String, namespace: String) -> Option<String> {
+		self.0.insert(prefix, namespace)
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+		self.0.iter().map(|(p, ns)| (p.as_str(), ns.as_str()))
+	}
+
+	/// Compacts the given IRI using the longest matching namespace.
+	///
+	/// Returns the prefix and local name, or `None` if no namespace matches
+	/// with a valid local name.
+	pub fn compact<'a>(&'a self, iri: &'a Iri) -> Option<(&'a str, &'a str)> {
+		self.iter()
+			.filter_map(|(prefix, namespace)| {
+				let local = iri.as_str().strip_prefix(namespace)?;
+				is_local_name(local).then_some((prefix, namespace.len(), local))
+			})
+			.max_by_key(|(_, len, _)| *len)
+			.map(|(prefix, _, local)| (prefix, local))
+	}
+}
+
+impl<'a> From<&'a [(&'a str, &'a str)]> for PrefixMap {
+	fn from(value: &'a [(&'a str, &'a str)]) -> Self {
+		value
+			.iter()
+			.map(|(p, ns)| (p.to_string(), ns.to_string()))
+			.collect()
+	}
+}
+
+impl FromIterator<(String, String)> for PrefixMap {
+	fn from_iter<T: IntoIterator<Item = (String, String)>>(iter: T) -> Self {
+		Self(iter.into_iter().collect())
+	}
+}
+
+impl Extend<(String, String)> for PrefixMap {
+	fn extend<T: IntoIterator<Item = (String, String)>>(&mut self, iter: T) {
+		self.0.extend(iter)
+	}
+}
+
+/// Checks that the given string can be used as the local part of a prefixed
+/// name without escaping.
+fn is_local_name(local: &str) -> bool {
+	local
+		.chars()
+		.all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':'))
+		&& !local.starts_with(['-', '.'])
+		&& !local.ends_with('.')
+}
+
+/// Serializes the given value as Turtle (or TriG if it uses named graphs)
+/// into `writer`, compacting IRIs with the prefixes declared by `T`.
+///
+/// Since triples are grouped by subject, the whole document is built in
+/// memory before being written.
+pub fn to_writer<G, T>(writer: impl io::Write, generator: G, value: &T) -> Result<(), WriteError>
+where
+	G: Generator,
+	T: LinkedData<WithGenerator<G>> + LinkedDataPrefixes,
+{
+	to_writer_with_prefixes(writer, generator, &PrefixMap::from(T::PREFIXES), value)
+}
+
+/// Serializes the given value as Turtle (or TriG if it uses named graphs)
+/// into `writer`, compacting IRIs with the given prefixes.
+///
+/// Since triples are grouped by subject, the whole document is built in
+/// memory before being written.
+pub fn to_writer_with_prefixes<G: Generator>(
+	mut writer: impl io::Write,
+	generator: G,
+	prefixes: &PrefixMap,
+	value: &impl LinkedData<WithGenerator<G>>,
+) -> Result<(), WriteError> {
+	let result = to_string_with_prefixes(generator, prefixes, value)?;
+	writer.write_all(result.as_bytes())?;
+	Ok(())
+}
+
+/// Serializes the given value as a Turtle (or TriG if it uses named graphs)
+/// string, compacting IRIs with the prefixes declared by `T`.
+pub fn to_string<G, T>(generator: G, value: &T) -> Result<String, IntoQuadsError>
+where
+	G: Generator,
+	T: LinkedData<WithGenerator<G>> + LinkedDataPrefixes,
+{
+	to_string_with_prefixes(generator, &PrefixMap::from(T::PREFIXES), value)
+}
+
+/// Serializes the given value as a Turtle (or TriG if it uses named graphs)
+/// string, compacting IRIs with the given prefixes.
+pub fn to_string_with_prefixes<G: Generator>(
+	generator: G,
+	prefixes: &PrefixMap,
+	value: &impl LinkedData<WithGenerator<G>>,
+) -> Result<String, IntoQuadsError> {
+	let quads = to_quads(generator, value)?;
+	Ok(quads_to_string(prefixes, &quads))
+}
+
+/// Formats the given quads as a Turtle (or TriG if some quads belong to a
+/// named graph) document.
+pub fn quads_to_string(prefixes: &PrefixMap, quads: &[RdfQuad]) -> String {
+	let mut result = String::new();
+	Document::new(prefixes, quads).write(&mut result).unwrap();
+	result
+}
+
+type PredicateObjects<'a> = Vec<(&'a IriBuf, Vec<&'a Term>)>;
+
+#[derive(Default)]
+struct Graph<'a> {
+	subjects: Vec<&'a Id>,
+	properties: HashMap<&'a Id, PredicateObjects<'a>>,
+}
+
+impl<'a> Graph<'a> {
+	fn insert(&mut self, subject: &'a Id, predicate: &'a IriBuf, object: &'a Term) {
+		let properties = self.properties.entry(subject).or_insert_with(|| {
+			self.subjects.push(subject);
+			Vec::new()
+		});
+
+		match properties.iter_mut().find(|(p, _)| *p == predicate) {
+			Some((_, objects)) => objects.push(object),
+			None => properties.push((predicate, vec![object])),
+		}
+	}
+
+	fn properties(&self, id: &Id) -> &[(&'a IriBuf, Vec<&'a Term>)] {
+		self.properties
+			.get(id)
+			.map(Vec::as_slice)
+			.unwrap_or_default()
+	}
+}
+
+struct Document<'a> {
+	prefixes: &'a PrefixMap,
+	graphs: Vec<(Option<&'a Id>, Graph<'a>)>,
+
+	/// Blank nodes that can be written in place of their unique reference.
+	anonymous: HashSet<&'a BlankIdBuf>,
+
+	/// Blank nodes already written.
+	written: HashSet<&'a BlankIdBuf>,
+}
+
+impl<'a> Document<'a> {
+	fn new(prefixes: &'a PrefixMap, quads: &'a [RdfQuad]) -> Self {
+		let mut graphs: Vec<(Option<&'a Id>, Graph<'a>)> = Vec::new();
+
+		// Number of references to each blank node, and the graph of the last one.
+		let mut references: HashMap<&'a BlankIdBuf, (usize, usize)> = HashMap::new();
+		// Graph in which each blank node is described.
+		let mut described_in: HashMap<&'a BlankIdBuf, usize> = HashMap::new();
+		let mut excluded = HashSet::new();
+
+		for rdf_types::Quad(s, p, o, g) in quads {
+			let g = g.as_ref();
+			let i = match graphs.iter().position(|(name, _)| *name == g) {
+				Some(i) => i,
+				None => {
+					if let Some(Id::Blank(b)) = g {
+						excluded.insert(b);
+					}
+
+					graphs.push((g, Graph::default()));
+					graphs.len() - 1
+				}
+			};
+
+			graphs[i].1.insert(s, p, o);
+
+			if let Id::Blank(b) = s {
+				if *described_in.entry(b).or_insert(i) != i {
+					excluded.insert(b);
+				}
+			}
+
+			if let Term::Id(Id::Blank(b)) = o {
+				let (count, graph) = references.entry(b).or_insert((0, i));
+				*count += 1;
+				*graph = i
+			}
+		}
+
+		let anonymous = references
+			.into_iter()
+			.filter(|(b, (count, graph))| {
+				*count == 1
+					&& !excluded.contains(b)
+					&& described_in.get(b).is_none_or(|i| i == graph)
+			})
+			.map(|(b, _)| b)
+			.collect();
+
+		Self {
+			prefixes,
+			graphs,
+			anonymous,
+			written: HashSet::new(),
+		}
+	}
+
+	fn write(mut self, f: &mut String) -> std::fmt::Result {
+		for (prefix, namespace) in self.prefixes.iter() {
+			writeln!(f, "@prefix {prefix}: <{namespace}> .")?;
+		}
+
+		// The default graph comes first.
+		let mut graphs = std::mem::take(&mut self.graphs);
+		graphs.sort_by_key(|(name, _)| name.is_some());
+		let mut separate = !self.prefixes.is_empty();
+		for (name, graph) in &graphs {
+			if separate {
+				writeln!(f)?;
+			}
+
+			let indent = match name {
+				Some(name) => {
+					self.write_id(f, name)?;
+					writeln!(f, " {{")?;
+					1
+				}
+				None => 0,
+			};
+
+			let mut first = true;
+			let mut write_subject = |this: &mut Self, f: &mut String, subject: &'a Id| {
+				if !std::mem::take(&mut first) {
+					writeln!(f)?;
+				}
+
+				this.write_subject(f, graph, subject, indent)
+			};
+
+			for &subject in &graph.subjects {
+				if !self.is_anonymous(subject) {
+					write_subject(&mut self, f, subject)?;
+				}
+			}
+
+			// Anonymous blank nodes only reachable through a cycle.
+			for &subject in &graph.subjects {
+				if let Id::Blank(b) = subject {
+					if self.anonymous.contains(b) && !self.written.contains(b) {
+						write_subject(&mut self, f, subject)?;
+					}
+				}
+			}
+
+			if name.is_some() {
+				writeln!(f, "}}")?;
+			}
+
+			separate = true
+		}
+
+		Ok(())
+	}
+
+	fn is_anonymous(&self, id: &Id) -> bool {
+		match id {
+			Id::Blank(b) => self.anonymous.contains(b),
+			Id::Iri(_) => false,
+		}
+	}
+
+	fn write_subject(
+		&mut self,
+		f: &mut String,
+		graph: &Graph<'a>,
+		subject: &'a Id,
+		indent: usize,
+	) -> std::fmt::Result {
+		if let Id::Blank(b) = subject {
+			self.written.insert(b);
+		}
+
+		write_indent(f, indent)?;
+		self.write_id(f, subject)?;
+		f.push(' ');
+		self.write_properties(f, graph, graph.properties(subject), indent + 1)?;
+		writeln!(f, " .")
+	}
+
+	fn write_properties(
+		&mut self,
+		f: &mut String,
+		graph: &Graph<'a>,
+		properties: &[(&'a IriBuf, Vec<&'a Term>)],
+		indent: usize,
+	) -> std::fmt::Result {
+		for (i, (predicate, objects)) in properties.iter().enumerate() {
+			if i > 0 {
+				writeln!(f, " ;")?;
+				write_indent(f, indent)?;
+			}
+
+			if predicate.as_iri() == RDF_TYPE {
+				f.push('a')
+			} else {
+				self.write_iri(f, predicate)?
+			}
+
+			for (j, object) in objects.iter().enumerate() {
+				f.push_str(if j > 0 { ", " } else { " " });
+				self.write_object(f, graph, object, indent)?;
+			}
+		}
+
+		Ok(())
+	}
+
+	fn write_object(
+		&mut self,
+		f: &mut String,
+		graph: &Graph<'a>,
+		object: &'a Term,
+		indent: usize,
+	) -> std::fmt::Result {
+		match object {
+			Term::Id(Id::Blank(b)) if self.anonymous.contains(b) && !self.written.contains(b) => {
+				match self.list_items(graph, b) {
+					Some(items) => {
+						f.push('(');
+						for item in items {
+							f.push(' ');
+							self.write_object(f, graph, item, indent)?;
+						}
+						f.push_str(" )");
+						Ok(())
+					}
+					None => {
+						self.written.insert(b);
+
+						let properties = graph.properties(object.as_id().unwrap());
+						if properties.is_empty() {
+							f.push_str("[]");
+						} else {
+							f.push_str("[\n");
+							write_indent(f, indent + 1)?;
+							self.write_properties(f, graph, properties, indent + 1)?;
+							f.push('\n');
+							write_indent(f, indent)?;
+							f.push(']');
+						}
+
+						Ok(())
+					}
+				}
+			}
+			Term::Id(Id::Iri(iri)) if iri == RDF_NIL => {
+				f.push_str("()");
+				Ok(())
+			}
+			Term::Id(id) => self.write_id(f, id),
+			Term::Literal(literal) => self.write_literal(f, literal),
+		}
+	}
+
+	/// Returns the items of the `rdf:List` starting at the given anonymous
+	/// blank node, marking every node of the list as written.
+	///
+	/// Returns `None` if the node is not the head of a well-formed list made
+	/// only of anonymous nodes.
+	fn list_items(&mut self, graph: &Graph<'a>, head: &'a BlankIdBuf) -> Option<Vec<&'a Term>> {
+		let mut items = Vec::new();
+		let mut nodes = Vec::new();
+		let mut node = head;
+
+		loop {
+			if nodes.contains(&node) {
+				return None;
+			}
+
+			let properties = graph.properties.get(&Id::Blank(node.clone()))?;
+
+			let mut first = None;
+			let mut rest = None;
+			for (predicate, objects) in properties {
+				match objects.as_slice() {
+					[object] if predicate.as_iri() == RDF_FIRST => first = Some(*object),
+					[object] if predicate.as_iri() == RDF_REST => rest = Some(*object),
+					_ => return None,
+				}
+			}
+
+			if properties.len() != 2 {
+				return None;
+			}
+
+			items.push(first?);
+			nodes.push(node);
+
+			match rest? {
+				Term::Id(Id::Iri(iri)) if iri == RDF_NIL => break,
+				Term::Id(Id::Blank(b))
+					if self.anonymous.contains(b) && !self.written.contains(b) =>
+				{
+					node = b
+				}
+				_ => return None,
+			}
+		}
+
+		self.written.extend(nodes);
+		Some(items)
+	}
+
+	fn write_id(&self, f: &mut String, id: &Id) -> std::fmt::Result {
+		match id {
+			Id::Iri(iri) => self.write_iri(f, iri),
+			Id::Blank(b) => write!(f, "{b}"),
+		}
+	}
+
+	fn write_iri(&self, f: &mut String, iri: &Iri) -> std::fmt::Result {
+		match self.prefixes.compact(iri) {
+			Some((prefix, local)) => write!(f, "{prefix}:{local}"),
+			None => write!(f, "<{iri}>"),
+		}
+	}
+
+	fn write_literal(&self, f: &mut String, literal: &Literal) -> std::fmt::Result {
+		write!(f, "{}", literal.value.rdf_display())?;
+		match &literal.type_ {
+			LiteralType::Any(ty) if ty == XSD_STRING => Ok(()),
+			LiteralType::Any(ty) => {
+				f.push_str("^^");
+				self.write_iri(f, ty)
+			}
+			LiteralType::LangString(tag) => write!(f, "@{tag}"),
+		}
+	}
+}
+
+fn write_indent(f: &mut String, indent: usize) -> std::fmt::Result {
+	for _ in 0..indent {
+		f.push('\t')
+	}
+
+	Ok(())
+}
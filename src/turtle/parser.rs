@@ -0,0 +1,819 @@
+use std::{collections::HashMap, io};
+
+use iref::{IriBuf, IriRef};
+use langtag::LangTagBuf;
+use rdf_types::{
+	BlankIdBuf, Id, Literal, LiteralType, Quad, RDF_FIRST, RDF_NIL, RDF_REST, RDF_TYPE, Term,
+	XSD_STRING, dataset::IndexedBTreeDataset,
+};
+use xsd_types::{XSD_BOOLEAN, XSD_DECIMAL, XSD_DOUBLE, XSD_INTEGER};
+
+use crate::{Context, FromLinkedDataError, LinkedDataDeserializeSubject};
+
+/// Turtle/TriG parse error.
+#[derive(Debug, thiserror::Error)]
+#[error("{kind} at line {line}, column {column}")]
+pub struct ParseError {
+	/// Line number, starting from 1.
+	pub line: usize,
+
+	/// Column number, in characters, starting from 1.
+	pub column: usize,
+
+	/// Error kind.
+	pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseErrorKind {
+	#[error("unexpected character `{0}`")]
+	Unexpected(char),
+
+	#[error("unexpected end of input")]
+	UnexpectedEnd,
+
+	#[error("invalid IRI `{0}`")]
+	InvalidIri(String),
+
+	#[error("unknown prefix `{0}`")]
+	UnknownPrefix(String),
+
+	#[error("invalid language tag `{0}`")]
+	InvalidLangTag(String),
+
+	#[error("invalid escape sequence")]
+	InvalidEscape,
+}
+
+/// Turtle/TriG reading error.
+#[derive(Debug, thiserror::Error)]
+pub enum ReadError {
+	#[error(transparent)]
+	Parse(#[from] ParseError),
+
+	#[error(transparent)]
+	Io(#[from] io::Error),
+}
+
+/// Error returned by [`from_turtle_str`] and [`from_turtle_reader`].
+#[derive(Debug, thiserror::Error)]
+pub enum FromTurtleError {
+	#[error(transparent)]
+	Read(#[from] ReadError),
+
+	#[error(transparent)]
+	Deserialize(#[from] FromLinkedDataError),
+}
+
+impl From<ParseError> for FromTurtleError {
+	fn from(value: ParseError) -> Self {
+		Self::Read(value.into())
+	}
+}
+
+/// Parses the given Turtle or TriG document into a dataset.
+///
+/// Blank node labels are scoped to the document: every blank node is given a
+/// fresh identifier.
+pub fn parse_str(input: &str) -> Result<IndexedBTreeDataset, ParseError> {
+	let mut parser = Parser::new(input);
+	parser.document()?;
+	Ok(parser.dataset)
+}
+
+/// Parses the Turtle or TriG document provided by `reader` into a dataset.
+pub fn parse_reader(mut reader: impl io::Read) -> Result<IndexedBTreeDataset, ReadError> {
+	let mut input = String::new();
+	reader.read_to_string(&mut input)?;
+	Ok(parse_str(&input)?)
+}
+
+/// Parses the given Turtle or TriG document and deserializes `subject` from
+/// its default graph.
+pub fn from_turtle_str<T: LinkedDataDeserializeSubject>(
+	input: &str,
+	subject: &Term,
+) -> Result<T, FromTurtleError> {
+	from_turtle_str_in(input, subject, Context::default())
+}
+
+/// Parses the given Turtle or TriG document and deserializes `subject` from
+/// its default graph, using the given deserialization context.
+pub fn from_turtle_str_in<T: LinkedDataDeserializeSubject>(
+	input: &str,
+	subject: &Term,
+	context: Context<()>,
+) -> Result<T, FromTurtleError> {
+	let dataset = parse_str(input)?;
+	Ok(T::deserialize_subject_in(
+		&(),
+		&(),
+		&dataset,
+		None,
+		subject,
+		context,
+	)?)
+}
+
+/// Parses the Turtle or TriG document provided by `reader` and deserializes
+/// `subject` from its default graph.
+pub fn from_turtle_reader<T: LinkedDataDeserializeSubject>(
+	reader: impl io::Read,
+	subject: &Term,
+) -> Result<T, FromTurtleError> {
+	from_turtle_reader_in(reader, subject, Context::default())
+}
+
+/// Parses the Turtle or TriG document provided by `reader` and deserializes
+/// `subject` from its default graph, using the given deserialization context.
+pub fn from_turtle_reader_in<T: LinkedDataDeserializeSubject>(
+	reader: impl io::Read,
+	subject: &Term,
+	context: Context<()>,
+) -> Result<T, FromTurtleError> {
+	let dataset = parse_reader(reader)?;
+	Ok(T::deserialize_subject_in(
+		&(),
+		&(),
+		&dataset,
+		None,
+		subject,
+		context,
+	)?)
+}
+
+/// Checks if the given character can appear in a prefixed name or blank node
+/// label.
+fn is_name_char(c: char) -> bool {
+	c.is_alphanumeric() || matches!(c, '_' | '-' | '\u{b7}')
+}
+
+struct Parser<'a> {
+	input: &'a str,
+	offset: usize,
+	base: Option<IriBuf>,
+	prefixes: HashMap<String, String>,
+	blank_ids: HashMap<String, BlankIdBuf>,
+	blank_id_count: usize,
+	graph: Option<Id>,
+	dataset: IndexedBTreeDataset,
+}
+
+impl<'a> Parser<'a> {
+	fn new(input: &'a str) -> Self {
+		Self {
+			input,
+			offset: 0,
+			base: None,
+			prefixes: HashMap::new(),
+			blank_ids: HashMap::new(),
+			blank_id_count: 0,
+			graph: None,
+			dataset: IndexedBTreeDataset::new(),
+		}
+	}
+
+	fn error_at(&self, offset: usize, kind: ParseErrorKind) -> ParseError {
+		let before = &self.input[..offset];
+		let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+		ParseError {
+			line: before.matches('\n').count() + 1,
+			column: before[line_start..].chars().count() + 1,
+			kind,
+		}
+	}
+
+	fn unexpected(&self) -> ParseError {
+		let kind = match self.peek() {
+			Some(c) => ParseErrorKind::Unexpected(c),
+			None => ParseErrorKind::UnexpectedEnd,
+		};
+
+		self.error_at(self.offset, kind)
+	}
+
+	fn rest(&self) -> &'a str {
+		&self.input[self.offset..]
+	}
+
+	fn peek(&self) -> Option<char> {
+		self.rest().chars().next()
+	}
+
+	fn peek_second(&self) -> Option<char> {
+		self.rest().chars().nth(1)
+	}
+
+	fn next_char(&mut self) -> Result<char, ParseError> {
+		let c = self.peek().ok_or_else(|| self.unexpected())?;
+		self.offset += c.len_utf8();
+		Ok(c)
+	}
+
+	fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+		if self.peek() == Some(expected) {
+			self.offset += expected.len_utf8();
+			Ok(())
+		} else {
+			Err(self.unexpected())
+		}
+	}
+
+	/// Skips whitespaces and comments.
+	fn skip_whitespaces(&mut self) {
+		loop {
+			match self.peek() {
+				Some(' ' | '\t' | '\r' | '\n') => self.offset += 1,
+				Some('#') => match self.rest().find('\n') {
+					Some(i) => self.offset += i + 1,
+					None => self.offset = self.input.len(),
+				},
+				_ => break,
+			}
+		}
+	}
+
+	/// Consumes the given case-insensitive keyword if it is next in the input.
+	fn keyword(&mut self, keyword: &str) -> bool {
+		let rest = self.rest();
+		let matches = rest
+			.get(..keyword.len())
+			.is_some_and(|k| k.eq_ignore_ascii_case(keyword))
+			&& !rest[keyword.len()..].starts_with(|c: char| is_name_char(c) || c == ':');
+
+		if matches {
+			self.offset += keyword.len()
+		}
+
+		matches
+	}
+
+	/// Checks if an anonymous blank node `[ ]` is next in the input.
+	fn at_anon(&mut self) -> bool {
+		let start = self.offset;
+		let anon = self.peek() == Some('[') && {
+			self.offset += 1;
+			self.skip_whitespaces();
+			self.peek() == Some(']')
+		};
+
+		self.offset = start;
+		anon
+	}
+
+	fn new_blank_id(&mut self) -> BlankIdBuf {
+		let id = BlankIdBuf::from_suffix(&self.blank_id_count.to_string()).unwrap();
+		self.blank_id_count += 1;
+		id
+	}
+
+	fn insert(&mut self, subject: Id, predicate: IriBuf, object: Term) {
+		self.dataset.insert(Quad(
+			Term::Id(subject),
+			Term::iri(predicate),
+			object,
+			self.graph.clone().map(Term::Id),
+		));
+	}
+
+	fn document(&mut self) -> Result<(), ParseError> {
+		loop {
+			self.skip_whitespaces();
+			if self.peek().is_none() {
+				break Ok(());
+			}
+
+			self.statement()?
+		}
+	}
+
+	fn statement(&mut self) -> Result<(), ParseError> {
+		if self.keyword("@prefix") {
+			self.prefix_id()?;
+			self.skip_whitespaces();
+			self.expect('.')
+		} else if self.keyword("@base") {
+			self.base()?;
+			self.skip_whitespaces();
+			self.expect('.')
+		} else if self.keyword("PREFIX") {
+			self.prefix_id()
+		} else if self.keyword("BASE") {
+			self.base()
+		} else if self.keyword("GRAPH") {
+			self.skip_whitespaces();
+			let label = self.graph_label()?;
+			self.wrapped_graph(Some(label))
+		} else if self.peek() == Some('{') {
+			self.wrapped_graph(None)
+		} else {
+			self.triples_or_graph()
+		}
+	}
+
+	fn prefix_id(&mut self) -> Result<(), ParseError> {
+		self.skip_whitespaces();
+		let prefix = self.prefix_name()?;
+		self.expect(':')?;
+		self.skip_whitespaces();
+		let namespace = self.iri_ref()?;
+		self.prefixes.insert(prefix, namespace.into_string());
+		Ok(())
+	}
+
+	fn base(&mut self) -> Result<(), ParseError> {
+		self.skip_whitespaces();
+		self.base = Some(self.iri_ref()?);
+		Ok(())
+	}
+
+	fn graph_label(&mut self) -> Result<Id, ParseError> {
+		if self.rest().starts_with('[') {
+			let start = self.offset;
+			self.offset += 1;
+			self.skip_whitespaces();
+			if self.peek() != Some(']') {
+				self.offset = start;
+				return Err(self.unexpected());
+			}
+
+			self.offset += 1;
+			Ok(Id::Blank(self.new_blank_id()))
+		} else {
+			self.id()
+		}
+	}
+
+	fn wrapped_graph(&mut self, label: Option<Id>) -> Result<(), ParseError> {
+		self.skip_whitespaces();
+		self.expect('{')?;
+		self.graph = label;
+
+		loop {
+			self.skip_whitespaces();
+			if self.peek() == Some('}') {
+				break;
+			}
+
+			self.triples()?;
+			self.skip_whitespaces();
+			match self.peek() {
+				Some('.') => self.offset += 1,
+				Some('}') => break,
+				_ => return Err(self.unexpected()),
+			}
+		}
+
+		self.offset += 1;
+		self.graph = None;
+		Ok(())
+	}
+
+	/// Parses top-level triples, or a TriG graph block introduced by its
+	/// label.
+	fn triples_or_graph(&mut self) -> Result<(), ParseError> {
+		let start = self.offset;
+		let blank_id_count = self.blank_id_count;
+
+		if matches!(self.peek(), Some('<' | '_')) || self.at_anon() {
+			let label = self.graph_label()?;
+			self.skip_whitespaces();
+			if self.peek() == Some('{') {
+				return self.wrapped_graph(Some(label));
+			}
+		} else if self.peek() != Some('[') && self.peek() != Some('(') {
+			let label = self.id()?;
+			self.skip_whitespaces();
+			if self.peek() == Some('{') {
+				return self.wrapped_graph(Some(label));
+			}
+		}
+
+		// Labelled blank nodes are kept in `blank_ids`, but the identifier
+		// given to an anonymous label must not be skipped.
+		self.offset = start;
+		if self.at_anon() {
+			self.blank_id_count = blank_id_count
+		}
+
+		self.triples()?;
+		self.skip_whitespaces();
+		self.expect('.')
+	}
+
+	fn triples(&mut self) -> Result<(), ParseError> {
+		let subject = match self.peek() {
+			Some('[') => {
+				let anon = self.at_anon();
+				let subject = self.blank_node_property_list()?;
+				self.skip_whitespaces();
+
+				// The predicate-object list is optional after a non-empty
+				// blank node property list.
+				if !anon && matches!(self.peek(), Some('.' | '}') | None) {
+					return Ok(());
+				}
+
+				subject
+			}
+			Some('(') => match self.collection()? {
+				Term::Id(id) => id,
+				Term::Literal(_) => unreachable!(),
+			},
+			_ => self.id()?,
+		};
+
+		self.skip_whitespaces();
+		self.predicate_object_list(&subject)
+	}
+
+	fn predicate_object_list(&mut self, subject: &Id) -> Result<(), ParseError> {
+		loop {
+			let predicate = self.verb()?;
+			self.skip_whitespaces();
+			self.object_list(subject, &predicate)?;
+			self.skip_whitespaces();
+
+			if self.peek() != Some(';') {
+				break Ok(());
+			}
+
+			while self.peek() == Some(';') {
+				self.offset += 1;
+				self.skip_whitespaces();
+			}
+
+			if matches!(self.peek(), Some('.' | ']' | '}') | None) {
+				break Ok(());
+			}
+		}
+	}
+
+	fn object_list(&mut self, subject: &Id, predicate: &IriBuf) -> Result<(), ParseError> {
+		loop {
+			let object = self.object()?;
+			self.insert(subject.clone(), predicate.clone(), object);
+			self.skip_whitespaces();
+
+			if self.peek() != Some(',') {
+				break Ok(());
+			}
+
+			self.offset += 1;
+			self.skip_whitespaces();
+		}
+	}
+
+	fn verb(&mut self) -> Result<IriBuf, ParseError> {
+		if self.peek() == Some('a')
+			&& !self
+				.peek_second()
+				.is_some_and(|c| is_name_char(c) || matches!(c, ':' | '.'))
+		{
+			self.offset += 1;
+			Ok(RDF_TYPE.to_owned())
+		} else {
+			self.iri()
+		}
+	}
+
+	fn object(&mut self) -> Result<Term, ParseError> {
+		match self.peek() {
+			Some('(') => self.collection(),
+			Some('[') => self.blank_node_property_list().map(Term::Id),
+			Some('"' | '\'') => self.literal().map(Term::Literal),
+			Some('0'..='9' | '+' | '-' | '.') => self.numeric_literal().map(Term::Literal),
+			_ => {
+				for value in ["true", "false"] {
+					if self.keyword(value) {
+						return Ok(Term::Literal(Literal::new(
+							value.to_owned(),
+							LiteralType::Any(XSD_BOOLEAN.to_owned()),
+						)));
+					}
+				}
+
+				self.id().map(Term::Id)
+			}
+		}
+	}
+
+	/// Parses a collection into an `rdf:List`.
+	fn collection(&mut self) -> Result<Term, ParseError> {
+		self.expect('(')?;
+
+		let mut items = Vec::new();
+		loop {
+			self.skip_whitespaces();
+			if self.peek() == Some(')') {
+				self.offset += 1;
+				break;
+			}
+
+			items.push(self.object()?);
+		}
+
+		let mut list = Term::iri(RDF_NIL.to_owned());
+		for item in items.into_iter().rev() {
+			let node = Id::Blank(self.new_blank_id());
+			self.insert(node.clone(), RDF_FIRST.to_owned(), item);
+			self.insert(node.clone(), RDF_REST.to_owned(), list);
+			list = Term::Id(node)
+		}
+
+		Ok(list)
+	}
+
+	fn blank_node_property_list(&mut self) -> Result<Id, ParseError> {
+		self.expect('[')?;
+		let id = Id::Blank(self.new_blank_id());
+
+		self.skip_whitespaces();
+		if self.peek() != Some(']') {
+			self.predicate_object_list(&id)?;
+			self.skip_whitespaces();
+		}
+
+		self.expect(']')?;
+		Ok(id)
+	}
+
+	fn id(&mut self) -> Result<Id, ParseError> {
+		if self.rest().starts_with("_:") {
+			self.blank_id().map(Id::Blank)
+		} else {
+			self.iri().map(Id::Iri)
+		}
+	}
+
+	fn blank_id(&mut self) -> Result<BlankIdBuf, ParseError> {
+		self.offset += 2;
+		let start = self.offset;
+
+		while let Some(c) = self.peek().filter(|&c| is_name_char(c) || c == '.') {
+			self.offset += c.len_utf8()
+		}
+
+		// A trailing `.` terminates the statement.
+		while self.input[..self.offset].ends_with('.') {
+			self.offset -= 1
+		}
+
+		if self.offset == start {
+			return Err(self.unexpected());
+		}
+
+		let label = &self.input[start..self.offset];
+		match self.blank_ids.get(label) {
+			Some(id) => Ok(id.clone()),
+			None => {
+				let id = self.new_blank_id();
+				self.blank_ids.insert(label.to_owned(), id.clone());
+				Ok(id)
+			}
+		}
+	}
+
+	fn iri(&mut self) -> Result<IriBuf, ParseError> {
+		if self.peek() == Some('<') {
+			self.iri_ref()
+		} else {
+			self.prefixed_name()
+		}
+	}
+
+	fn iri_ref(&mut self) -> Result<IriBuf, ParseError> {
+		let start = self.offset;
+		self.expect('<')?;
+
+		let mut iri = String::new();
+		loop {
+			let offset = self.offset;
+			match self.next_char()? {
+				'>' => break,
+				'\\' => match self.next_char()? {
+					'u' => iri.push(self.hex_char(offset, 4)?),
+					'U' => iri.push(self.hex_char(offset, 8)?),
+					_ => return Err(self.error_at(offset, ParseErrorKind::InvalidEscape)),
+				},
+				c @ ('\0'..=' ' | '<' | '"' | '{' | '}' | '|' | '^' | '`') => {
+					return Err(self.error_at(offset, ParseErrorKind::Unexpected(c)));
+				}
+				c => iri.push(c),
+			}
+		}
+
+		let resolved = match &self.base {
+			Some(base) => IriRef::new(&iri).ok().map(|r| r.resolved(base)),
+			None => IriBuf::new(iri.clone()).ok(),
+		};
+
+		resolved.ok_or_else(|| self.error_at(start, ParseErrorKind::InvalidIri(iri)))
+	}
+
+	fn prefix_name(&mut self) -> Result<String, ParseError> {
+		let start = self.offset;
+
+		if self.peek().is_some_and(char::is_alphabetic) {
+			while self.peek().is_some_and(|c| is_name_char(c) || c == '.') {
+				self.offset += self.peek().unwrap().len_utf8()
+			}
+
+			while self.input[..self.offset].ends_with('.') {
+				self.offset -= 1
+			}
+		}
+
+		if self.peek() == Some(':') {
+			Ok(self.input[start..self.offset].to_owned())
+		} else {
+			Err(self.unexpected())
+		}
+	}
+
+	fn prefixed_name(&mut self) -> Result<IriBuf, ParseError> {
+		let start = self.offset;
+		let prefix = self.prefix_name()?;
+		self.offset += 1;
+
+		let namespace = self
+			.prefixes
+			.get(&prefix)
+			.cloned()
+			.ok_or_else(|| self.error_at(start, ParseErrorKind::UnknownPrefix(prefix)))?;
+
+		// Local name, and its value and end offset without trailing dots.
+		let mut local = String::new();
+		let mut end = (0, self.offset);
+		loop {
+			let offset = self.offset;
+			match self.peek() {
+				Some('\\') => {
+					self.offset += 1;
+					match self.next_char()? {
+						c @ ('_' | '~' | '.' | '-' | '!' | '$' | '&' | '\'' | '(' | ')' | '*'
+						| '+' | ',' | ';' | '=' | '/' | '?' | '#' | '@' | '%') => local.push(c),
+						_ => return Err(self.error_at(offset, ParseErrorKind::InvalidEscape)),
+					}
+				}
+				Some('%') => {
+					let digits = self
+						.rest()
+						.get(1..3)
+						.filter(|d| d.chars().all(|c| c.is_ascii_hexdigit()))
+						.ok_or_else(|| self.error_at(offset, ParseErrorKind::InvalidEscape))?;
+					local.push('%');
+					local.push_str(digits);
+					self.offset += 3
+				}
+				Some(c) if is_name_char(c) || c == ':' || (c == '.' && !local.is_empty()) => {
+					local.push(c);
+					self.offset += c.len_utf8();
+					if c == '.' {
+						continue;
+					}
+				}
+				_ => break,
+			}
+
+			end = (local.len(), self.offset)
+		}
+
+		local.truncate(end.0);
+		self.offset = end.1;
+
+		let iri = format!("{namespace}{local}");
+		IriBuf::new(iri).map_err(|e| self.error_at(start, ParseErrorKind::InvalidIri(e.0)))
+	}
+
+	fn literal(&mut self) -> Result<Literal, ParseError> {
+		let quote = self.next_char()?;
+		let long_delimiter: String = [quote; 3].iter().collect();
+		let long = self.input[self.offset - 1..].starts_with(&long_delimiter);
+		if long {
+			self.offset += 2
+		}
+
+		let mut value = String::new();
+		loop {
+			let offset = self.offset;
+			if long && self.rest().starts_with(&long_delimiter) {
+				self.offset += 3;
+				break;
+			}
+
+			match self.next_char()? {
+				c if c == quote && !long => break,
+				'\\' => {
+					let c = match self.next_char()? {
+						't' => '\t',
+						'b' => '\u{8}',
+						'n' => '\n',
+						'r' => '\r',
+						'f' => '\u{c}',
+						'"' => '"',
+						'\'' => '\'',
+						'\\' => '\\',
+						'u' => self.hex_char(offset, 4)?,
+						'U' => self.hex_char(offset, 8)?,
+						_ => return Err(self.error_at(offset, ParseErrorKind::InvalidEscape)),
+					};
+
+					value.push(c)
+				}
+				c @ ('\n' | '\r') if !long => {
+					return Err(self.error_at(offset, ParseErrorKind::Unexpected(c)));
+				}
+				c => value.push(c),
+			}
+		}
+
+		let type_ = match self.peek() {
+			Some('@') => {
+				self.offset += 1;
+				let start = self.offset;
+				while self
+					.peek()
+					.is_some_and(|c| c.is_ascii_alphanumeric() || c == '-')
+				{
+					self.offset += 1
+				}
+
+				let tag = self.input[start..self.offset].to_owned();
+				LiteralType::LangString(
+					LangTagBuf::new(tag)
+						.map_err(|e| self.error_at(start, ParseErrorKind::InvalidLangTag(e.0)))?,
+				)
+			}
+			Some('^') => {
+				self.offset += 1;
+				self.expect('^')?;
+				LiteralType::Any(self.iri()?)
+			}
+			_ => LiteralType::Any(XSD_STRING.to_owned()),
+		};
+
+		Ok(Literal::new(value, type_))
+	}
+
+	fn numeric_literal(&mut self) -> Result<Literal, ParseError> {
+		let start = self.offset;
+		let digits = |this: &mut Self| {
+			let start = this.offset;
+			while this.peek().is_some_and(|c| c.is_ascii_digit()) {
+				this.offset += 1
+			}
+			this.offset > start
+		};
+
+		if matches!(self.peek(), Some('+' | '-')) {
+			self.offset += 1
+		}
+
+		let mut ty = XSD_INTEGER;
+		let integer = digits(self);
+
+		if self.peek() == Some('.') && self.peek_second().is_some_and(|c| c.is_ascii_digit()) {
+			self.offset += 1;
+			digits(self);
+			ty = XSD_DECIMAL
+		} else if !integer {
+			return Err(self.unexpected());
+		}
+
+		if matches!(self.peek(), Some('e' | 'E')) {
+			self.offset += 1;
+			if matches!(self.peek(), Some('+' | '-')) {
+				self.offset += 1
+			}
+
+			if !digits(self) {
+				return Err(self.unexpected());
+			}
+
+			ty = XSD_DOUBLE
+		}
+
+		Ok(Literal::new(
+			self.input[start..self.offset].to_owned(),
+			LiteralType::Any(ty.to_owned()),
+		))
+	}
+
+	/// Reads the `len` hexadecimal digits of a `\u` or `\U` escape sequence
+	/// starting at `offset`.
+	fn hex_char(&mut self, offset: usize, len: usize) -> Result<char, ParseError> {
+		let digits = self
+			.rest()
+			.get(..len)
+			.filter(|digits| digits.chars().all(|c| c.is_ascii_hexdigit()))
+			.ok_or_else(|| self.error_at(offset, ParseErrorKind::InvalidEscape))?;
+
+		self.offset += len;
+		u32::from_str_radix(digits, 16)
+			.ok()
+			.and_then(char::from_u32)
+			.ok_or_else(|| self.error_at(offset, ParseErrorKind::InvalidEscape))
+	}
+}
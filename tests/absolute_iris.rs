@@ -0,0 +1,86 @@
+use iref::IriBuf;
+use linked_data_next::{Deserialize, Serialize, nquads, roundtrip};
+use rdf_types::generator;
+
+/// Undeclared prefixes that are not close to a declared or well-known one
+/// are schemes of absolute IRIs.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+#[ld(type = "info:lccn/2002022641")]
+struct Book {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:title")]
+	title: String,
+
+	#[ld("isbn:0451450523")]
+	edition: String,
+
+	#[ld("geo:37.786971,-122.399677")]
+	location: String,
+
+	#[ld("ipfs:bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi")]
+	content: String,
+
+	#[ld("urn:example:cover")]
+	cover: String,
+}
+
+#[test]
+fn absolute_iris() {
+	let value = Book {
+		id: IriBuf::new("http://example.org/book".to_owned()).unwrap(),
+		title: "Title".to_owned(),
+		edition: "first".to_owned(),
+		location: "shelf".to_owned(),
+		content: "text".to_owned(),
+		cover: "red".to_owned(),
+	};
+
+	let document = nquads::to_string_sorted(generator::Blank::new(), &value).unwrap();
+	assert_eq!(
+		document,
+		"<http://example.org/book> <geo:37.786971,-122.399677> \"shelf\" .\n\
+		<http://example.org/book> <http://example.org/title> \"Title\" .\n\
+		<http://example.org/book> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <info:lccn/2002022641> .\n\
+		<http://example.org/book> <ipfs:bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi> \"text\" .\n\
+		<http://example.org/book> <isbn:0451450523> \"first\" .\n\
+		<http://example.org/book> <urn:example:cover> \"red\" .\n"
+	);
+
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+/// Schemes close to a declared prefix are declared as prefixes expanding to
+/// themselves.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("sio" = "http://semanticscience.org/resource/", "sip" = "sip:"))]
+struct Contact {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("sio:SIO_000116")]
+	name: String,
+
+	#[ld("sip:alice@example.org")]
+	phone: String,
+}
+
+#[test]
+fn scheme_declared_as_prefix() {
+	let value = Contact {
+		id: IriBuf::new("http://example.org/alice".to_owned()).unwrap(),
+		name: "Alice".to_owned(),
+		phone: "office".to_owned(),
+	};
+
+	let document = nquads::to_string_sorted(generator::Blank::new(), &value).unwrap();
+	assert_eq!(
+		document,
+		"<http://example.org/alice> <http://semanticscience.org/resource/SIO_000116> \"Alice\" .\n\
+		<http://example.org/alice> <sip:alice@example.org> \"office\" .\n"
+	);
+
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
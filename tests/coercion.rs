@@ -0,0 +1,140 @@
+use iref::Iri;
+use langtag::LangTagBuf;
+use linked_data_next::{Coercion, Context, FromLinkedDataError, LinkedDataDeserializeSubject};
+use rdf_types::{Literal, LiteralType, Term, XSD_STRING, dataset::IndexedBTreeDataset};
+use xsd_types::{
+	XSD_BYTE, XSD_DECIMAL, XSD_INT, XSD_INTEGER, XSD_LONG, XSD_POSITIVE_INTEGER, XSD_UNSIGNED_BYTE,
+};
+
+fn literal(value: &str, ty: &Iri) -> Term {
+	Term::Literal(Literal::new(
+		value.to_owned(),
+		LiteralType::Any(ty.to_owned()),
+	))
+}
+
+fn lang_string(value: &str, lang: &str) -> Term {
+	Term::Literal(Literal::new(
+		value.to_owned(),
+		LiteralType::LangString(LangTagBuf::new(lang.to_owned()).unwrap()),
+	))
+}
+
+/// Deserializes a value from the given literal with the given policy.
+fn read<T: LinkedDataDeserializeSubject>(
+	coercion: Coercion,
+	term: &Term,
+) -> Result<T, FromLinkedDataError> {
+	T::deserialize_subject_in(
+		&(),
+		&(),
+		&IndexedBTreeDataset::new(),
+		None,
+		term,
+		Context::default().with_coercion(coercion),
+	)
+}
+
+fn type_mismatch<T: LinkedDataDeserializeSubject + std::fmt::Debug>(
+	coercion: Coercion,
+	term: &Term,
+) {
+	match read::<T>(coercion, term) {
+		Err(FromLinkedDataError::LiteralTypeMismatch { .. }) => (),
+		other => panic!("unexpected result for {term}: {other:?}"),
+	}
+}
+
+fn invalid<T: LinkedDataDeserializeSubject + std::fmt::Debug>(coercion: Coercion, term: &Term) {
+	match read::<T>(coercion, term) {
+		Err(FromLinkedDataError::InvalidLiteral(_)) => (),
+		other => panic!("unexpected result for {term}: {other:?}"),
+	}
+}
+
+#[test]
+fn accepts() {
+	assert!(Coercion::Strict.accepts(XSD_LONG, XSD_LONG, &[XSD_INTEGER]));
+	assert!(Coercion::Strict.accepts(XSD_INTEGER, XSD_LONG, &[XSD_INTEGER]));
+	assert!(!Coercion::Strict.accepts(XSD_INT, XSD_LONG, &[XSD_INTEGER]));
+	assert!(!Coercion::Strict.accepts(XSD_POSITIVE_INTEGER, XSD_LONG, &[XSD_INTEGER]));
+
+	assert!(Coercion::Derived.accepts(XSD_BYTE, XSD_LONG, &[]));
+	assert!(Coercion::Derived.accepts(XSD_POSITIVE_INTEGER, XSD_LONG, &[XSD_INTEGER]));
+	assert!(!Coercion::Derived.accepts(XSD_DECIMAL, XSD_LONG, &[XSD_INTEGER]));
+	assert!(!Coercion::Derived.accepts(XSD_STRING, XSD_LONG, &[XSD_INTEGER]));
+
+	assert!(Coercion::Lenient.accepts(XSD_STRING, XSD_LONG, &[]));
+	assert!(!Coercion::Strict.accepts_lang_string());
+	assert!(!Coercion::Derived.accepts_lang_string());
+	assert!(Coercion::Lenient.accepts_lang_string())
+}
+
+#[test]
+fn strict() {
+	let strict = Coercion::Strict;
+	assert_eq!(
+		read::<u8>(strict, &literal("42", XSD_UNSIGNED_BYTE)).unwrap(),
+		42
+	);
+	assert_eq!(read::<u8>(strict, &literal("42", XSD_INTEGER)).unwrap(), 42);
+	assert_eq!(
+		read::<i32>(strict, &literal("-7", XSD_INTEGER)).unwrap(),
+		-7
+	);
+	assert_eq!(read::<i64>(strict, &literal("-7", XSD_LONG)).unwrap(), -7);
+
+	// Subtypes are rejected.
+	type_mismatch::<i64>(strict, &literal("-7", XSD_INT));
+	type_mismatch::<u32>(strict, &literal("7", XSD_UNSIGNED_BYTE));
+	type_mismatch::<u32>(strict, &literal("7", XSD_POSITIVE_INTEGER));
+
+	// Other datatypes are rejected.
+	type_mismatch::<u32>(strict, &literal("7", XSD_STRING));
+	type_mismatch::<u32>(strict, &lang_string("7", "en"));
+
+	// Ranges are checked.
+	invalid::<u8>(strict, &literal("256", XSD_INTEGER))
+}
+
+#[test]
+fn derived() {
+	let derived = Coercion::Derived;
+	assert_eq!(read::<i64>(derived, &literal("-7", XSD_BYTE)).unwrap(), -7);
+	assert_eq!(
+		read::<u32>(derived, &literal("7", XSD_POSITIVE_INTEGER)).unwrap(),
+		7
+	);
+	assert_eq!(
+		read::<u32>(derived, &literal("255", XSD_UNSIGNED_BYTE)).unwrap(),
+		255
+	);
+
+	// Compatible datatypes are accepted, provided the value fits.
+	invalid::<u8>(derived, &literal("300", XSD_INTEGER));
+	invalid::<u8>(derived, &literal("-1", XSD_INTEGER));
+	invalid::<i8>(derived, &literal("128", XSD_LONG));
+	invalid::<u32>(derived, &literal("4294967296", XSD_POSITIVE_INTEGER));
+
+	type_mismatch::<u32>(derived, &literal("7", XSD_DECIMAL));
+	type_mismatch::<u32>(derived, &literal("7", XSD_STRING));
+	type_mismatch::<u32>(derived, &lang_string("7", "en"))
+}
+
+#[test]
+fn lenient() {
+	let lenient = Coercion::Lenient;
+	assert_eq!(
+		read::<u32>(lenient, &literal("42", XSD_STRING)).unwrap(),
+		42
+	);
+	assert_eq!(read::<u32>(lenient, &lang_string("42", "en")).unwrap(), 42);
+	assert!(read::<bool>(lenient, &literal("true", XSD_STRING)).unwrap());
+	assert_eq!(
+		read::<f64>(lenient, &lang_string("1.5", "fr")).unwrap(),
+		1.5
+	);
+
+	invalid::<u32>(lenient, &literal("forty-two", XSD_STRING));
+	invalid::<u8>(lenient, &lang_string("256", "en"))
+}
@@ -0,0 +1,138 @@
+use iref::IriBuf;
+use linked_data_next::{
+	AsList, AsSet, AsSetOrList, Deserialize, LinkedDataDeserializePredicateObjects, Serialize,
+	from_nquads_str, nquads, roundtrip,
+};
+use rdf_types::{BlankIdBuf, Id, Literal, LiteralType, Term, XSD_STRING, generator};
+
+fn ex(local: &str) -> IriBuf {
+	IriBuf::new(format!("http://example.org/{local}")).unwrap()
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+	values.iter().map(|v| (*v).to_owned()).collect()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Item {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:tag", set)]
+	tags: Vec<String>,
+
+	#[ld("ex:steps", list)]
+	steps: Vec<String>,
+
+	#[ld("ex:names", lenient)]
+	names: Vec<String>,
+}
+
+fn item() -> Item {
+	Item {
+		id: ex("item"),
+		tags: strings(&["a", "b"]),
+		steps: strings(&["second", "first"]),
+		names: strings(&["y", "x"]),
+	}
+}
+
+#[test]
+fn serialize_containers() {
+	let output = nquads::to_string_sorted(generator::Blank::new(), &item()).unwrap();
+	assert_eq!(
+		output,
+		"_:0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> \"second\" .\n\
+		_:0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> _:1 .\n\
+		_:1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> \"first\" .\n\
+		_:1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .\n\
+		_:2 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> \"y\" .\n\
+		_:2 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> _:3 .\n\
+		_:3 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> \"x\" .\n\
+		_:3 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .\n\
+		<http://example.org/item> <http://example.org/names> _:2 .\n\
+		<http://example.org/item> <http://example.org/steps> _:0 .\n\
+		<http://example.org/item> <http://example.org/tag> \"a\" .\n\
+		<http://example.org/item> <http://example.org/tag> \"b\" .\n"
+	)
+}
+
+#[test]
+fn containers_round_trip() {
+	let value = item();
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn lenient_from_set_or_list() {
+	let subject = Term::<Id>::iri(ex("item"));
+
+	let set = "\
+		<http://example.org/item> <http://example.org/steps> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .\n\
+		<http://example.org/item> <http://example.org/names> \"x\" .\n\
+		<http://example.org/item> <http://example.org/names> \"y\" .\n";
+	let result: Item = from_nquads_str(set, &subject).unwrap();
+	assert_eq!(result.names, strings(&["x", "y"]));
+	assert!(result.tags.is_empty() && result.steps.is_empty());
+
+	let single = "\
+		<http://example.org/item> <http://example.org/steps> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .\n\
+		<http://example.org/item> <http://example.org/names> \"x\" .\n";
+	let result: Item = from_nquads_str(single, &subject).unwrap();
+	assert_eq!(result.names, strings(&["x"]));
+
+	let list = "\
+		<http://example.org/item> <http://example.org/steps> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .\n\
+		<http://example.org/item> <http://example.org/names> _:l .\n\
+		_:l <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> \"x\" .\n\
+		_:l <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .\n";
+	let result: Item = from_nquads_str(list, &subject).unwrap();
+	assert_eq!(result.names, strings(&["x"]));
+
+	let empty = "\
+		<http://example.org/item> <http://example.org/steps> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .\n\
+		<http://example.org/item> <http://example.org/names> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .\n";
+	let result: Item = from_nquads_str(empty, &subject).unwrap();
+	assert!(result.names.is_empty())
+}
+
+#[test]
+fn list_is_not_a_set() {
+	// A list field does not accept several objects.
+	let input = "\
+		<http://example.org/item> <http://example.org/steps> \"a\" .\n\
+		<http://example.org/item> <http://example.org/steps> \"b\" .\n";
+
+	assert!(from_nquads_str::<Item>(input, &Term::<Id>::iri(ex("item"))).is_err())
+}
+
+#[test]
+fn container_wrappers() {
+	let dataset = nquads::parse_str(
+		"_:l <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> \"x\" .\n\
+		_:l <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .\n",
+	)
+	.unwrap();
+	let list = Term::blank(BlankIdBuf::new("_:l".to_owned()).unwrap());
+	let x = Term::Literal(Literal::new(
+		"x".to_owned(),
+		LiteralType::Any(XSD_STRING.to_owned()),
+	));
+
+	let AsList(items) =
+		AsList::<Vec<String>>::deserialize_objects(&(), &(), &dataset, None, [&list]).unwrap();
+	assert_eq!(items, strings(&["x"]));
+
+	let AsSet(items) =
+		AsSet::<Vec<String>>::deserialize_objects(&(), &(), &dataset, None, [&x]).unwrap();
+	assert_eq!(items, strings(&["x"]));
+
+	let AsSetOrList(items) =
+		AsSetOrList::<Vec<String>>::deserialize_objects(&(), &(), &dataset, None, [&list]).unwrap();
+	assert_eq!(items, strings(&["x"]));
+
+	let AsSetOrList(items) =
+		AsSetOrList::<Vec<String>>::deserialize_objects(&(), &(), &dataset, None, [&x]).unwrap();
+	assert_eq!(items, strings(&["x"]))
+}
@@ -0,0 +1,50 @@
+use iref::IriBuf;
+use linked_data_next::{
+	Coercion, Context, Deserialize, Serialize, from_dataset, from_dataset_in, nquads, roundtrip,
+	to_dataset,
+};
+use rdf_types::{Id, generator};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Item {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:count")]
+	count: u32,
+}
+
+fn item() -> Item {
+	Item {
+		id: IriBuf::new("http://example.org/item".to_owned()).unwrap(),
+		count: 3,
+	}
+}
+
+#[test]
+fn to_and_from_dataset() {
+	let value = item();
+	let dataset = to_dataset(generator::Blank::new(), &value).unwrap();
+	let result: Item = from_dataset(&dataset, &Id::Iri(value.id.clone())).unwrap();
+	assert_eq!(result, value)
+}
+
+#[test]
+fn roundtrip_helper() {
+	let value = item();
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn from_dataset_with_context() {
+	let dataset =
+		nquads::parse_str(r#"<http://example.org/item> <http://example.org/count> "3" ."#).unwrap();
+	let subject = Id::Iri(item().id);
+
+	assert!(from_dataset::<Item>(&dataset, &subject).is_err());
+
+	let context = Context::default().with_coercion(Coercion::Lenient);
+	let result: Item = from_dataset_in(&dataset, &subject, context).unwrap();
+	assert_eq!(result, item())
+}
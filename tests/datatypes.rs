@@ -0,0 +1,236 @@
+use std::num::{NonZeroI8, NonZeroI128, NonZeroU8, NonZeroU32, NonZeroU128};
+
+use iref::{Iri, IriBuf};
+use linked_data_next::{
+	Coercion, Context, Deserialize, FromLinkedDataError, LinkedDataDeserializeSubject, Serialize,
+	from_nquads_str, from_nquads_str_in,
+	nquads::{self, FromNQuadsError},
+	roundtrip,
+};
+use rdf_types::{
+	Id, Literal, LiteralType, Term, XSD_STRING, dataset::IndexedBTreeDataset, generator,
+};
+use xsd_types::{
+	XSD_BYTE, XSD_INTEGER, XSD_NON_NEGATIVE_INTEGER, XSD_UNSIGNED_BYTE, XSD_UNSIGNED_INT,
+};
+
+fn literal(value: &str, ty: &Iri) -> Term {
+	Term::Literal(Literal::new(
+		value.to_owned(),
+		LiteralType::Any(ty.to_owned()),
+	))
+}
+
+/// Deserializes a value from the given literal.
+fn read<T: LinkedDataDeserializeSubject>(value: &str, ty: &Iri) -> Result<T, FromLinkedDataError> {
+	T::deserialize_subject(
+		&(),
+		&(),
+		&IndexedBTreeDataset::new(),
+		None,
+		&literal(value, ty),
+	)
+}
+
+fn invalid<T: LinkedDataDeserializeSubject + std::fmt::Debug>(value: &str, ty: &Iri) {
+	match read::<T>(value, ty) {
+		Err(FromLinkedDataError::InvalidLiteral(_)) => (),
+		other => panic!("unexpected result for {value:?}: {other:?}"),
+	}
+}
+
+#[test]
+fn non_zero_rejects_zero() {
+	invalid::<NonZeroU8>("0", XSD_UNSIGNED_BYTE);
+	invalid::<NonZeroU32>("0", XSD_UNSIGNED_INT);
+	invalid::<NonZeroU128>("0", XSD_NON_NEGATIVE_INTEGER);
+	invalid::<NonZeroI8>("0", XSD_BYTE);
+	invalid::<NonZeroI128>("0", XSD_INTEGER);
+
+	assert_eq!(
+		read::<NonZeroU8>("255", XSD_UNSIGNED_BYTE).unwrap().get(),
+		255
+	);
+	assert_eq!(read::<NonZeroI8>("-128", XSD_BYTE).unwrap().get(), -128);
+	invalid::<NonZeroU8>("256", XSD_UNSIGNED_BYTE)
+}
+
+#[test]
+fn u128_bounds() {
+	assert_eq!(
+		read::<u128>(&u128::MAX.to_string(), XSD_NON_NEGATIVE_INTEGER).unwrap(),
+		u128::MAX
+	);
+	assert_eq!(read::<u128>("0", XSD_INTEGER).unwrap(), 0);
+	invalid::<u128>(
+		"340282366920938463463374607431768211456",
+		XSD_NON_NEGATIVE_INTEGER,
+	);
+	invalid::<u128>("-1", XSD_INTEGER)
+}
+
+#[test]
+fn i128_bounds() {
+	assert_eq!(
+		read::<i128>(&i128::MAX.to_string(), XSD_INTEGER).unwrap(),
+		i128::MAX
+	);
+	assert_eq!(
+		read::<i128>(&i128::MIN.to_string(), XSD_INTEGER).unwrap(),
+		i128::MIN
+	);
+	invalid::<i128>("170141183460469231731687303715884105728", XSD_INTEGER);
+	invalid::<i128>("-170141183460469231731687303715884105729", XSD_INTEGER)
+}
+
+#[test]
+fn char_requires_a_single_character() {
+	assert_eq!(read::<char>("é", XSD_STRING).unwrap(), 'é');
+	invalid::<char>("ab", XSD_STRING);
+	invalid::<char>("", XSD_STRING)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Numbers {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:unsigned")]
+	unsigned: u128,
+
+	#[ld("ex:signed")]
+	signed: i128,
+
+	#[ld("ex:non-zero-unsigned")]
+	non_zero_unsigned: NonZeroU128,
+
+	#[ld("ex:non-zero-signed")]
+	non_zero_signed: NonZeroI8,
+
+	#[ld("ex:char")]
+	char: char,
+}
+
+#[test]
+fn numbers_round_trip() {
+	let value = Numbers {
+		id: IriBuf::new("http://example.org/numbers".to_owned()).unwrap(),
+		unsigned: u128::MAX,
+		signed: i128::MIN,
+		non_zero_unsigned: NonZeroU128::MAX,
+		non_zero_signed: NonZeroI8::MIN,
+		char: '\n',
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+/// Temperature in degrees Celsius, written as a decimal number.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Celsius(f64);
+
+impl std::fmt::Display for Celsius {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl std::str::FromStr for Celsius {
+	type Err = std::num::ParseFloatError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		s.parse().map(Self)
+	}
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Reading {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:temperature", datatype = "ex:celsius")]
+	temperature: Celsius,
+
+	#[ld("ex:minimum", datatype = "ex:celsius")]
+	minimum: Option<Celsius>,
+
+	#[ld("ex:samples", datatype = "ex:celsius")]
+	samples: Vec<Celsius>,
+
+	#[ld("ex:code", datatype = "http://example.org/code")]
+	code: String,
+}
+
+fn reading() -> Reading {
+	Reading {
+		id: IriBuf::new("http://example.org/reading".to_owned()).unwrap(),
+		temperature: Celsius(21.5),
+		minimum: Some(Celsius(-3.0)),
+		samples: vec![Celsius(1.25), Celsius(2.5)],
+		code: "A-1".to_owned(),
+	}
+}
+
+#[test]
+fn datatype_round_trip() {
+	let value = reading();
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value);
+
+	let value = Reading {
+		minimum: None,
+		samples: Vec::new(),
+		..reading()
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn datatype_serialization() {
+	let document = nquads::to_string_sorted(generator::Blank::new(), &reading()).unwrap();
+	assert_eq!(
+		document,
+		"<http://example.org/reading> <http://example.org/code> \"A-1\"^^<http://example.org/code> .\n\
+		<http://example.org/reading> <http://example.org/minimum> \"-3\"^^<http://example.org/celsius> .\n\
+		<http://example.org/reading> <http://example.org/samples> \"1.25\"^^<http://example.org/celsius> .\n\
+		<http://example.org/reading> <http://example.org/samples> \"2.5\"^^<http://example.org/celsius> .\n\
+		<http://example.org/reading> <http://example.org/temperature> \"21.5\"^^<http://example.org/celsius> .\n"
+	)
+}
+
+#[test]
+fn datatype_mismatch() {
+	let document = nquads::to_string_sorted(generator::Blank::new(), &reading())
+		.unwrap()
+		.replace(
+			"\"21.5\"^^<http://example.org/celsius>",
+			"\"21.5\"^^<http://www.w3.org/2001/XMLSchema#decimal>",
+		);
+	let subject = Term::<Id>::iri(reading().id);
+
+	match from_nquads_str::<Reading>(&document, &subject) {
+		Err(FromNQuadsError::Deserialize(FromLinkedDataError::LiteralTypeMismatch {
+			expected,
+			..
+		})) => assert_eq!(expected.unwrap().as_str(), "http://example.org/celsius"),
+		other => panic!("unexpected result: {other:?}"),
+	}
+
+	let context = Context::default().with_coercion(Coercion::Lenient);
+	let value: Reading = from_nquads_str_in(&document, &subject, context).unwrap();
+	assert_eq!(value, reading())
+}
+
+#[test]
+fn datatype_invalid_lexical_form() {
+	let document = nquads::to_string_sorted(generator::Blank::new(), &reading())
+		.unwrap()
+		.replace("\"21.5\"", "\"warm\"");
+
+	assert!(matches!(
+		from_nquads_str::<Reading>(&document, &Term::<Id>::iri(reading().id)),
+		Err(FromNQuadsError::Deserialize(
+			FromLinkedDataError::InvalidLiteral(_)
+		))
+	))
+}
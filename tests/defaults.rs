@@ -0,0 +1,109 @@
+use iref::IriBuf;
+use linked_data_next::{Deserialize, Serialize, from_nquads_str, nquads, roundtrip};
+use rdf_types::{Id, Term, generator};
+
+fn ex(local: &str) -> IriBuf {
+	IriBuf::new(format!("http://example.org/{local}")).unwrap()
+}
+
+fn default_port() -> u16 {
+	80
+}
+
+fn is_default_port(port: &u16) -> bool {
+	*port == default_port()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Server {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld(
+		"ex:port",
+		default = "default_port",
+		skip_serializing_if = "is_default_port"
+	)]
+	port: u16,
+
+	#[ld("ex:alias", set, default, skip_serializing_if = "Vec::is_empty")]
+	aliases: Vec<String>,
+
+	#[ld("ex:name", default)]
+	name: String,
+
+	#[ld("ex:note", skip_serializing_if = "Option::is_none")]
+	note: Option<String>,
+}
+
+fn server() -> Server {
+	Server {
+		id: ex("server"),
+		port: 80,
+		aliases: Vec::new(),
+		name: String::new(),
+		note: None,
+	}
+}
+
+fn read(document: &str) -> Server {
+	from_nquads_str(document, &Term::<Id>::iri(ex("server"))).unwrap()
+}
+
+#[test]
+fn default_values() {
+	assert_eq!(read(""), server());
+
+	let value = read(
+		"<http://example.org/server> <http://example.org/port> \"8080\"^^<http://www.w3.org/2001/XMLSchema#unsignedShort> .\n\
+		<http://example.org/server> <http://example.org/name> \"main\" .\n",
+	);
+	assert_eq!(
+		value,
+		Server {
+			port: 8080,
+			name: "main".to_owned(),
+			..server()
+		}
+	)
+}
+
+#[test]
+fn default_values_are_skipped() {
+	let document = nquads::to_string_sorted(generator::Blank::new(), &server()).unwrap();
+	assert_eq!(
+		document,
+		"<http://example.org/server> <http://example.org/name> \"\" .\n"
+	);
+
+	let value = Server {
+		port: 8080,
+		aliases: vec!["www".to_owned()],
+		note: Some("primary".to_owned()),
+		..server()
+	};
+	let document = nquads::to_string_sorted(generator::Blank::new(), &value).unwrap();
+	assert_eq!(
+		document,
+		"<http://example.org/server> <http://example.org/alias> \"www\" .\n\
+		<http://example.org/server> <http://example.org/name> \"\" .\n\
+		<http://example.org/server> <http://example.org/note> \"primary\" .\n\
+		<http://example.org/server> <http://example.org/port> \"8080\"^^<http://www.w3.org/2001/XMLSchema#unsignedShort> .\n"
+	)
+}
+
+#[test]
+fn default_round_trip() {
+	let value = server();
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value);
+
+	let value = Server {
+		port: 443,
+		aliases: vec!["api".to_owned(), "www".to_owned()],
+		name: "main".to_owned(),
+		note: Some("primary".to_owned()),
+		..server()
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
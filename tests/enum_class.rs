@@ -0,0 +1,150 @@
+use iref::IriBuf;
+use linked_data_next::{
+	Deserialize, FromLinkedDataError, LinkedDataDeserializeSubject, Serialize, from_nquads_str,
+	nquads::{self, FromNQuadsError},
+	roundtrip,
+};
+use rdf_types::{Id, Term, generator};
+
+fn ex(local: &str) -> IriBuf {
+	IriBuf::new(format!("http://example.org/{local}")).unwrap()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Address {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:street")]
+	street: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+enum Agent {
+	#[ld(class = "ex:Person")]
+	Person {
+		#[ld(id)]
+		id: IriBuf,
+
+		#[ld("ex:name")]
+		name: String,
+	},
+
+	#[ld(class = "ex:Place")]
+	Place(Address),
+
+	#[ld(class = "ex:Anonymous")]
+	Anonymous,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+enum Contact {
+	#[ld(class = "ex:Person")]
+	Person {
+		#[ld(id)]
+		id: IriBuf,
+
+		#[ld("ex:name")]
+		name: String,
+	},
+
+	Email(#[ld("ex:email")] String),
+}
+
+fn read<T: LinkedDataDeserializeSubject>(document: &str) -> Result<T, FromLinkedDataError> {
+	match from_nquads_str(document, &Term::<Id>::iri(ex("jane"))) {
+		Ok(value) => Ok(value),
+		Err(FromNQuadsError::Deserialize(e)) => Err(e),
+		Err(e) => panic!("{e}"),
+	}
+}
+
+#[test]
+fn struct_variant() {
+	let value = Agent::Person {
+		id: ex("jane"),
+		name: "Jane".to_owned(),
+	};
+
+	let document = nquads::to_string_sorted(generator::Blank::new(), &value).unwrap();
+	assert_eq!(
+		document,
+		"<http://example.org/jane> <http://example.org/name> \"Jane\" .\n\
+		<http://example.org/jane> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/Person> .\n"
+	);
+
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn tuple_variant() {
+	let value = Agent::Place(Address {
+		id: ex("home"),
+		street: "Main Street".to_owned(),
+	});
+
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn unit_variant() {
+	let value = Agent::Anonymous;
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn mixed_variants() {
+	let value = Contact::Person {
+		id: ex("jane"),
+		name: "Jane".to_owned(),
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value);
+
+	let value = Contact::Email("jane@example.org".to_owned());
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value);
+
+	// Untyped nodes fall back to the variants without a class.
+	assert_eq!(
+		read::<Contact>(
+			"<http://example.org/jane> <http://example.org/email> \"jane@example.org\" .\n"
+		)
+		.unwrap(),
+		value
+	)
+}
+
+#[test]
+fn several_classes() {
+	let document = "<http://example.org/jane> <http://example.org/name> \"Jane\" .\n\
+		<http://example.org/jane> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/Agent> .\n\
+		<http://example.org/jane> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/Person> .\n";
+
+	assert_eq!(
+		read::<Agent>(document).unwrap(),
+		Agent::Person {
+			id: ex("jane"),
+			name: "Jane".to_owned()
+		}
+	)
+}
+
+#[test]
+fn no_matching_class() {
+	let document = "<http://example.org/jane> <http://example.org/name> \"Jane\" .\n\
+		<http://example.org/jane> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/Robot> .\n";
+
+	match read::<Agent>(document) {
+		Err(FromLinkedDataError::TypeMismatch {
+			expected, found, ..
+		}) => {
+			let mut expected = expected;
+			expected.sort();
+			assert_eq!(expected, [ex("Anonymous"), ex("Person"), ex("Place")]);
+			assert_eq!(found, [ex("Robot")])
+		}
+		other => panic!("unexpected result: {other:?}"),
+	}
+}
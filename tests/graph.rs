@@ -0,0 +1,159 @@
+use iref::IriBuf;
+use linked_data_next::{
+	Deserialize, FromLinkedDataError, LinkedDataDeserialize, LinkedDataDeserializeGraph, Serialize,
+	nquads, roundtrip, to_dataset,
+};
+use rdf_types::generator;
+
+fn ex(local: &str) -> IriBuf {
+	IriBuf::new(format!("http://example.org/{local}")).unwrap()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Person {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:knows")]
+	knows: Option<IriBuf>,
+
+	#[ld("ex:address")]
+	address: Address,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Address {
+	#[ld("ex:city")]
+	city: String,
+}
+
+fn person(local: &str, knows: Option<&str>) -> Person {
+	Person {
+		id: ex(local),
+		knows: knows.map(ex),
+		address: Address {
+			city: "Paris".to_owned(),
+		},
+	}
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+#[ld(dataset)]
+struct Directory {
+	#[ld(named_graph = "ex:people")]
+	people: Vec<Person>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Snapshot {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:people", graph)]
+	people: Vec<Person>,
+}
+
+#[test]
+fn vec_graph_with_references() {
+	let value = Directory {
+		people: vec![
+			person("alice", Some("bob")),
+			person("bob", Some("alice")),
+			person("carol", Some("alice")),
+		],
+	};
+
+	let dataset = to_dataset(generator::Blank::new(), &value).unwrap();
+	let result = Directory::deserialize_dataset(&(), &(), &dataset).unwrap();
+	assert_eq!(result, value)
+}
+
+#[test]
+fn vec_graph_field_with_references() {
+	let value = Snapshot {
+		id: ex("snapshot"),
+		people: vec![person("alice", Some("bob")), person("bob", Some("alice"))],
+	};
+
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn empty_vec_graph() {
+	let dataset = nquads::parse_str("").unwrap();
+	let result: Vec<Person> = Vec::deserialize_graph(&(), &(), &dataset, None).unwrap();
+	assert!(result.is_empty())
+}
+
+#[test]
+fn vec_graph_item_errors() {
+	let dataset = nquads::parse_str(
+		"<http://example.org/alice> <http://example.org/address> _:a .\n\
+		_:a <http://example.org/city> \"Paris\" .\n\
+		<http://example.org/bob> <http://example.org/knows> <http://example.org/alice> .\n",
+	)
+	.unwrap();
+
+	assert!(matches!(
+		Vec::<Person>::deserialize_graph(&(), &(), &dataset, None),
+		Err(FromLinkedDataError::MissingRequiredValue(_))
+	))
+}
+
+#[test]
+fn root_subject() {
+	let dataset = nquads::parse_str(
+		"<http://example.org/alice> <http://example.org/address> _:a .\n\
+		_:a <http://example.org/city> \"Paris\" .\n",
+	)
+	.unwrap();
+
+	let result = Person::deserialize_graph(&(), &(), &dataset, None).unwrap();
+	assert_eq!(result, person("alice", None));
+
+	let empty = nquads::parse_str("").unwrap();
+	assert!(matches!(
+		Person::deserialize_graph(&(), &(), &empty, None),
+		Err(FromLinkedDataError::EmptyGraph(_))
+	));
+
+	let dataset = nquads::parse_str(
+		"<http://example.org/alice> <http://example.org/address> _:a .\n\
+		<http://example.org/bob> <http://example.org/address> _:a .\n\
+		_:a <http://example.org/city> \"Paris\" .\n",
+	)
+	.unwrap();
+	assert!(matches!(
+		Person::deserialize_graph(&(), &(), &dataset, None),
+		Err(FromLinkedDataError::TooManyValues(_))
+	))
+}
+
+#[test]
+fn cyclic_graph() {
+	let cycle = nquads::parse_str(
+		"<http://example.org/alice> <http://example.org/knows> <http://example.org/bob> .\n\
+		<http://example.org/alice> <http://example.org/address> _:a .\n\
+		<http://example.org/bob> <http://example.org/knows> <http://example.org/alice> .\n\
+		<http://example.org/bob> <http://example.org/address> _:b .\n\
+		_:a <http://example.org/city> \"Paris\" .\n\
+		_:b <http://example.org/city> \"Paris\" .\n",
+	)
+	.unwrap();
+
+	assert!(matches!(
+		Person::deserialize_graph(&(), &(), &cycle, None),
+		Err(FromLinkedDataError::NoRootSubject(_))
+	));
+
+	// Graphs of several subjects do not need a root.
+	let result: Vec<Person> = Vec::deserialize_graph(&(), &(), &cycle, None).unwrap();
+	assert_eq!(
+		result,
+		[person("alice", Some("bob")), person("bob", Some("alice"))]
+	)
+}
@@ -0,0 +1,266 @@
+use iref::IriBuf;
+use linked_data_next::{
+	Deserialize, FromLinkedDataError, Serialize, from_nquads_str,
+	nquads::{self, FromNQuadsError},
+	roundtrip,
+};
+use rdf_types::{Id, Term, generator};
+
+fn ex(local: &str) -> IriBuf {
+	IriBuf::new(format!("http://example.org/{local}")).unwrap()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+#[ld(type = "ex:Friend")]
+struct Friend {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:name")]
+	name: String,
+
+	#[ld("ex:knows")]
+	knows: Option<IriBuf>,
+}
+
+fn friend(local: &str, knows: Option<&str>) -> Friend {
+	Friend {
+		id: ex(local),
+		name: local.to_owned(),
+		knows: knows.map(ex),
+	}
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Doc {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:friend")]
+	friend: Friend,
+
+	#[ld(include = "ex:Friend")]
+	extra: Vec<Friend>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct SingleDoc {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:friend")]
+	friend: Friend,
+
+	#[ld(include = "ex:Friend")]
+	extra: Friend,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct OptionalDoc {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld(include = "ex:Friend")]
+	extra: Option<Friend>,
+}
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Bundle {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld(include)]
+	extra: Vec<Friend>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+#[ld(type = "ex:Extra")]
+struct Extra {
+	#[ld(id)]
+	id: IriBuf,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Child {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld(include = "ex:Extra")]
+	extras: Vec<Extra>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Parent {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:child")]
+	child: Child,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Siblings {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:first")]
+	first: Child,
+
+	#[ld("ex:second")]
+	second: Child,
+}
+
+fn extras(locals: &[&str]) -> Vec<Extra> {
+	locals.iter().map(|local| Extra { id: ex(local) }).collect()
+}
+
+#[test]
+fn values_are_not_included() {
+	let value = Doc {
+		id: ex("doc"),
+		friend: friend("alice", Some("bob")),
+		extra: vec![friend("carol", None)],
+	};
+
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn single_included_node() {
+	let value = SingleDoc {
+		id: ex("doc"),
+		friend: friend("alice", None),
+		extra: friend("carol", None),
+	};
+
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn optional_included_node() {
+	let value = OptionalDoc {
+		id: ex("doc"),
+		extra: None,
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value);
+
+	let value = OptionalDoc {
+		id: ex("doc"),
+		extra: Some(friend("carol", None)),
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn typed_included_nodes_with_references() {
+	let value = Doc {
+		id: ex("doc"),
+		friend: friend("alice", Some("carol")),
+		extra: vec![friend("carol", None), friend("dave", Some("carol"))],
+	};
+
+	// `carol` is reachable from `doc`, through `alice`.
+	let result = roundtrip(generator::Blank::new(), &value).unwrap();
+	assert_eq!(result.extra, vec![friend("dave", Some("carol"))]);
+
+	let value = Doc {
+		id: ex("doc"),
+		friend: friend("alice", None),
+		extra: vec![friend("carol", Some("dave")), friend("dave", Some("carol"))],
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn untyped_included_nodes_are_serialized() {
+	let value = Bundle {
+		id: ex("bundle"),
+		extra: vec![friend("carol", None)],
+	};
+
+	let document = nquads::to_string_sorted(generator::Blank::new(), &value).unwrap();
+	assert_eq!(
+		document,
+		"<http://example.org/carol> <http://example.org/name> \"carol\" .\n\
+		<http://example.org/carol> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/Friend> .\n"
+	)
+}
+
+#[test]
+fn nested_included_nodes() {
+	let value = Parent {
+		id: ex("parent"),
+		child: Child {
+			id: ex("kid"),
+			extras: extras(&["a", "b"]),
+		},
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value);
+
+	let value = Parent {
+		id: ex("parent"),
+		child: Child {
+			id: ex("kid"),
+			extras: Vec::new(),
+		},
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn sibling_included_nodes() {
+	let value = Siblings {
+		id: ex("siblings"),
+		first: Child {
+			id: ex("older"),
+			extras: Vec::new(),
+		},
+		second: Child {
+			id: ex("younger"),
+			extras: Vec::new(),
+		},
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value);
+
+	// Included nodes are not linked to their owner, so both siblings
+	// receive them.
+	let value = Siblings {
+		first: Child {
+			id: ex("older"),
+			extras: extras(&["a"]),
+		},
+		second: Child {
+			id: ex("younger"),
+			extras: extras(&["b"]),
+		},
+		..value
+	};
+	let result = roundtrip(generator::Blank::new(), &value).unwrap();
+	assert_eq!(result.first.extras, extras(&["a", "b"]));
+	assert_eq!(result.second.extras, extras(&["a", "b"]))
+}
+
+#[test]
+fn included_errors_are_reported() {
+	let input = "\
+		<http://example.org/doc> <http://example.org/friend> <http://example.org/alice> .\n\
+		<http://example.org/alice> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/Friend> .\n\
+		<http://example.org/alice> <http://example.org/name> \"alice\" .\n\
+		<http://example.org/carol> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/Friend> .\n";
+
+	assert!(matches!(
+		from_nquads_str::<Doc>(input, &Term::<Id>::iri(ex("doc"))),
+		Err(FromNQuadsError::Deserialize(
+			FromLinkedDataError::MissingRequiredValue(_)
+		))
+	))
+}
@@ -0,0 +1,298 @@
+use std::{
+	collections::{BTreeSet, HashSet, VecDeque},
+	rc::Rc,
+	sync::Arc,
+};
+
+use iref::IriBuf;
+use json_syntax::json;
+use linked_data_next::{
+	AnonymousGraph, IntoJsonLdError, JsonLdContext, LangMap, NamedGraph, Serialize,
+	to_json_ld_expanded,
+};
+
+fn ex(local: &str) -> IriBuf {
+	IriBuf::new(format!("http://example.org/{local}")).unwrap()
+}
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+#[ld(type = "ex:Person")]
+struct Person {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:name")]
+	name: String,
+
+	#[ld("ex:nick")]
+	nicks: HashSet<String>,
+
+	#[ld("ex:steps")]
+	steps: Vec<u32>,
+
+	#[ld("ex:label")]
+	label: LangMap,
+
+	#[ld("ex:address")]
+	address: Address,
+}
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Address {
+	#[ld("ex:city")]
+	city: String,
+}
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Member {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld(reverse = "ex:member")]
+	group: Group,
+}
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Group {
+	#[ld(id)]
+	id: IriBuf,
+}
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Members {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld(reverse = "ex:member")]
+	groups: Vec<Group>,
+}
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct ReverseLiteral {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld(reverse = "ex:name")]
+	name: String,
+}
+
+fn alice() -> Person {
+	Person {
+		id: ex("alice"),
+		name: "Alice".to_owned(),
+		nicks: ["Al".to_owned()].into_iter().collect(),
+		steps: vec![1, 2],
+		label: [("en", "Alice"), ("fr", "Alix")]
+			.into_iter()
+			.map(|(lang, value)| (lang.parse().unwrap(), value.to_owned()))
+			.collect(),
+		address: Address {
+			city: "Paris".to_owned(),
+		},
+	}
+}
+
+fn alice_node() -> json_syntax::Value {
+	json!({
+		"@id": "http://example.org/alice",
+		"@type": ["http://example.org/Person"],
+		"http://example.org/name": [{ "@value": "Alice" }],
+		"http://example.org/nick": [{ "@value": "Al" }],
+		"http://example.org/steps": [{
+			"@list": [
+				{
+					"@value": "1",
+					"@type": "http://www.w3.org/2001/XMLSchema#unsignedInt"
+				},
+				{
+					"@value": "2",
+					"@type": "http://www.w3.org/2001/XMLSchema#unsignedInt"
+				}
+			]
+		}],
+		"http://example.org/label": [
+			{ "@value": "Alice", "@language": "en" },
+			{ "@value": "Alix", "@language": "fr" }
+		],
+		"http://example.org/address": [{
+			"http://example.org/city": [{ "@value": "Paris" }]
+		}]
+	})
+}
+
+#[test]
+fn expanded_node() {
+	let output = to_json_ld_expanded(&alice()).unwrap();
+	assert_eq!(output, json!([alice_node()]))
+}
+
+#[test]
+fn expanded_named_graph() {
+	let output = to_json_ld_expanded(&NamedGraph(ex("g"), alice())).unwrap();
+	assert_eq!(
+		output,
+		json!([{
+			"@id": "http://example.org/g",
+			"@graph": [alice_node()]
+		}])
+	)
+}
+
+#[test]
+fn anonymous_named_graph() {
+	assert!(matches!(
+		to_json_ld_expanded(&AnonymousGraph(alice())),
+		Err(IntoJsonLdError::Graph)
+	))
+}
+
+#[test]
+fn expanded_reverse() {
+	let value = Member {
+		id: ex("alice"),
+		group: Group { id: ex("club") },
+	};
+
+	let output = to_json_ld_expanded(&value).unwrap();
+	assert_eq!(
+		output,
+		json!([{
+			"@id": "http://example.org/alice",
+			"@reverse": {
+				"http://example.org/member": [{ "@id": "http://example.org/club" }]
+			}
+		}])
+	)
+}
+
+#[test]
+fn reverse_list_is_a_node() {
+	let value = Members {
+		id: ex("alice"),
+		groups: vec![Group { id: ex("club") }],
+	};
+
+	let output = to_json_ld_expanded(&value).unwrap();
+	assert_eq!(
+		output,
+		json!([{
+			"@id": "http://example.org/alice",
+			"@reverse": {
+				"http://example.org/member": [{
+					"http://www.w3.org/1999/02/22-rdf-syntax-ns#first": [
+						{ "@id": "http://example.org/club" }
+					],
+					"http://www.w3.org/1999/02/22-rdf-syntax-ns#rest": [{ "@list": [] }]
+				}]
+			}
+		}])
+	)
+}
+
+#[test]
+fn reverse_literal() {
+	let value = ReverseLiteral {
+		id: ex("alice"),
+		name: "Alice".to_owned(),
+	};
+
+	assert!(matches!(
+		to_json_ld_expanded(&value),
+		Err(IntoJsonLdError::Subject)
+	))
+}
+
+type Names = Vec<String>;
+
+#[derive(JsonLdContext)]
+#[ld(prefix("ex" = "http://example.org/", "xsd" = "http://www.w3.org/2001/XMLSchema#"))]
+#[ld(vocab = "http://example.org/vocab#")]
+#[ld(type = "ex:Containers")]
+#[allow(dead_code)]
+struct Containers {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:name")]
+	name: String,
+
+	#[ld("ex:queue")]
+	queue: VecDeque<String>,
+
+	#[ld("ex:tags")]
+	tags: BTreeSet<String>,
+
+	#[ld("ex:shared")]
+	shared: Arc<Vec<String>>,
+
+	#[ld("ex:counted")]
+	counted: Option<Rc<HashSet<String>>>,
+
+	#[ld("ex:label")]
+	label: Option<Box<LangMap>>,
+
+	#[ld("ex:alias")]
+	alias: Names,
+
+	#[ld("ex:alias_list", list)]
+	alias_list: Names,
+
+	// The path is only read by `Serialize` and `Deserialize`.
+	#[ld("ex:custom", with = "custom")]
+	custom: Vec<String>,
+
+	#[ld("ex:graph", graph)]
+	graph: Group,
+
+	#[ld("ex:age", datatype = "xsd:integer")]
+	age: String,
+
+	#[ld(reverse = "ex:member")]
+	groups: HashSet<Group>,
+
+	#[ld(reverse = "ex:owner")]
+	owned: Vec<Group>,
+
+	#[ld(include)]
+	included: Vec<Group>,
+
+	#[ld(ignore)]
+	ignored: u32,
+}
+
+#[test]
+fn derived_context() {
+	assert_eq!(
+		Containers::json_ld_context(),
+		json!({
+			"ex": "http://example.org/",
+			"xsd": "http://www.w3.org/2001/XMLSchema#",
+			"@vocab": "http://example.org/vocab#",
+			"Containers": "http://example.org/Containers",
+			"id": "@id",
+			"name": "http://example.org/name",
+			"queue": { "@id": "http://example.org/queue", "@container": "@list" },
+			"tags": { "@id": "http://example.org/tags", "@container": "@set" },
+			"shared": { "@id": "http://example.org/shared", "@container": "@list" },
+			"counted": { "@id": "http://example.org/counted", "@container": "@set" },
+			"label": { "@id": "http://example.org/label", "@container": "@language" },
+			"alias": "http://example.org/alias",
+			"alias_list": { "@id": "http://example.org/alias_list", "@container": "@list" },
+			"custom": "http://example.org/custom",
+			"graph": { "@id": "http://example.org/graph", "@container": "@graph" },
+			"age": {
+				"@id": "http://example.org/age",
+				"@type": "http://www.w3.org/2001/XMLSchema#integer"
+			},
+			"groups": { "@reverse": "http://example.org/member", "@container": "@set" },
+			"owned": { "@reverse": "http://example.org/owner" },
+			"included": "@included"
+		})
+	)
+}
@@ -0,0 +1,296 @@
+use iref::IriBuf;
+use langtag::LangTagBuf;
+use linked_data_next::{
+	Context, Deserialize, FromLinkedDataError, LangMap, LangString, LanguagePreference, Serialize,
+	from_nquads_str, from_nquads_str_in, nquads::FromNQuadsError, roundtrip,
+};
+use rdf_types::{Id, Literal, LiteralType, Term, XSD_STRING, generator};
+use xsd_types::XSD_INTEGER;
+
+fn ex(local: &str) -> IriBuf {
+	IriBuf::new(format!("http://example.org/{local}")).unwrap()
+}
+
+fn tag(value: &str) -> LangTagBuf {
+	LangTagBuf::new(value.to_owned()).unwrap()
+}
+
+fn tagged(value: &str, lang: &str) -> Term {
+	Term::Literal(Literal::new(
+		value.to_owned(),
+		LiteralType::LangString(tag(lang)),
+	))
+}
+
+fn typed(value: &str, ty: &iref::Iri) -> Term {
+	Term::Literal(Literal::new(
+		value.to_owned(),
+		LiteralType::Any(ty.to_owned()),
+	))
+}
+
+fn select(preference: LanguagePreference, objects: &[Term]) -> Option<String> {
+	preference.select(&(), &(), objects)
+}
+
+#[test]
+fn select_preferred_language() {
+	let objects = [
+		tagged("colour", "en-GB"),
+		tagged("couleur", "fr"),
+		typed("color", XSD_STRING),
+	];
+
+	assert_eq!(
+		select(LanguagePreference::new(&["fr", "en"]), &objects).as_deref(),
+		Some("couleur")
+	);
+	assert_eq!(
+		select(LanguagePreference::new(&["de", "EN"]), &objects).as_deref(),
+		Some("colour")
+	);
+	assert_eq!(
+		select(LanguagePreference::new(&["en-GB"]), &objects).as_deref(),
+		Some("colour")
+	);
+	assert_eq!(
+		select(LanguagePreference::new(&["*"]), &objects).as_deref(),
+		Some("colour")
+	)
+}
+
+#[test]
+fn select_range_matches_subtags_only() {
+	let objects = [tagged("anglais", "eng")];
+	assert_eq!(select(LanguagePreference::strict(&["en"]), &objects), None);
+
+	let objects = [tagged("colour", "en")];
+	assert_eq!(
+		select(LanguagePreference::strict(&["en-GB"]), &objects),
+		None
+	)
+}
+
+#[test]
+fn select_fallback() {
+	let objects = [
+		tagged("couleur", "fr"),
+		typed("color", XSD_STRING),
+		tagged("Farbe", "de"),
+	];
+
+	// Untagged strings come first.
+	assert_eq!(
+		select(LanguagePreference::new(&["es"]), &objects).as_deref(),
+		Some("color")
+	);
+
+	// Then any language-tagged string, in a deterministic order.
+	assert_eq!(
+		select(LanguagePreference::new(&["es"]), &objects[..1]).as_deref(),
+		Some("couleur")
+	);
+	assert_eq!(
+		select(
+			LanguagePreference::new(&["es"]),
+			&[objects[0].clone(), objects[2].clone()]
+		)
+		.as_deref(),
+		Some("Farbe")
+	);
+
+	// Non-string literals are ignored.
+	assert_eq!(
+		select(
+			LanguagePreference::new(&["es"]),
+			&[typed("1", XSD_INTEGER), Term::Id(Id::Iri(ex("color")))]
+		),
+		None
+	)
+}
+
+#[test]
+fn select_without_fallback() {
+	let objects = [tagged("couleur", "fr"), typed("color", XSD_STRING)];
+	assert_eq!(select(LanguagePreference::strict(&["es"]), &objects), None);
+	assert_eq!(
+		select(LanguagePreference::strict(&["es", "fr"]), &objects).as_deref(),
+		Some("couleur")
+	)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Labelled {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:label")]
+	label: LangMap,
+
+	#[ld("ex:note")]
+	note: Option<LangString>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Preferred {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:label", lang("fr", "en"))]
+	label: String,
+
+	#[ld("ex:label", lang("de"))]
+	german: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Plain {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:label")]
+	label: String,
+
+	#[ld("ex:part")]
+	part: Part,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Part {
+	#[ld("ex:label")]
+	label: String,
+}
+
+fn read<T: linked_data_next::LinkedDataDeserializeSubject>(
+	document: &str,
+) -> Result<T, FromLinkedDataError> {
+	match from_nquads_str(document, &Term::<Id>::iri(ex("item"))) {
+		Ok(item) => Ok(item),
+		Err(FromNQuadsError::Deserialize(e)) => Err(e),
+		Err(e) => panic!("{e}"),
+	}
+}
+
+#[test]
+fn lang_map_round_trip() {
+	let value = Labelled {
+		id: ex("item"),
+		label: [
+			(tag("en"), "colour".to_owned()),
+			(tag("fr"), "couleur".to_owned()),
+		]
+		.into_iter()
+		.collect(),
+		note: Some(LangString::new("nota".to_owned(), tag("la"))),
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value);
+
+	let value = Labelled {
+		id: ex("item"),
+		label: LangMap::new(),
+		note: None,
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn lang_map_duplicate_language() {
+	let document = "<http://example.org/item> <http://example.org/label> \"colour\"@en .\n\
+		<http://example.org/item> <http://example.org/label> \"color\"@en .\n";
+	assert!(matches!(
+		read::<Labelled>(document),
+		Err(FromLinkedDataError::TooManyValues(_))
+	))
+}
+
+#[test]
+fn lang_map_rejects_untagged_strings() {
+	let document = "<http://example.org/item> <http://example.org/label> \"color\" .\n";
+	assert!(matches!(
+		read::<Labelled>(document),
+		Err(FromLinkedDataError::LiteralTypeMismatch { .. })
+	))
+}
+
+#[test]
+fn lang_field() {
+	let document = "<http://example.org/item> <http://example.org/label> \"colour\"@en-GB .\n\
+		<http://example.org/item> <http://example.org/label> \"Farbe\"@de .\n";
+	let value: Preferred = read(document).unwrap();
+	assert_eq!(value.label, "colour");
+	assert_eq!(value.german.as_deref(), Some("Farbe"));
+
+	let document = "<http://example.org/item> <http://example.org/label> \"color\" .\n";
+	let value: Preferred = read(document).unwrap();
+	assert_eq!(value.label, "color");
+	assert_eq!(value.german.as_deref(), Some("color"));
+
+	assert!(matches!(
+		read::<Preferred>(""),
+		Err(FromLinkedDataError::MissingRequiredValue(_))
+	))
+}
+
+#[test]
+fn lang_map_normalizes_case() {
+	let map: LangMap = [
+		(tag("EN-us"), "color".to_owned()),
+		(tag("zh-hant-tw"), "顏色".to_owned()),
+		(tag("de-CH-x-Phonebk"), "Farbe".to_owned()),
+	]
+	.into_iter()
+	.collect();
+
+	let keys: Vec<_> = map.keys().map(|lang| lang.as_str()).collect();
+	assert_eq!(keys, ["de-CH-x-phonebk", "en-US", "zh-Hant-TW"]);
+	assert_eq!(map.get(&tag("en-us")), Some("color"));
+
+	let document = "<http://example.org/item> <http://example.org/label> \"color\"@en-US .\n\
+		<http://example.org/item> <http://example.org/label> \"colour\"@en-us .\n";
+	assert!(matches!(
+		read::<Labelled>(document),
+		Err(FromLinkedDataError::TooManyValues(_))
+	))
+}
+
+#[test]
+fn context_language() {
+	let document = "<http://example.org/item> <http://example.org/label> \"colour\"@en-GB .\n\
+		<http://example.org/item> <http://example.org/label> \"couleur\"@fr .\n\
+		<http://example.org/item> <http://example.org/part> _:part .\n\
+		_:part <http://example.org/label> \"pièce\"@fr .\n\
+		_:part <http://example.org/label> \"part\"@en .\n";
+	let subject = Term::<Id>::iri(ex("item"));
+
+	let read_in = |languages| {
+		let context = Context::default().with_language(LanguagePreference::new(languages));
+		from_nquads_str_in::<Plain>(document, &subject, context).unwrap()
+	};
+
+	// The preference applies to nested values.
+	let value = read_in(&["fr"]);
+	assert_eq!(value.label, "couleur");
+	assert_eq!(value.part.label, "pièce");
+
+	let value = read_in(&["en"]);
+	assert_eq!(value.label, "colour");
+	assert_eq!(value.part.label, "part");
+
+	let context = Context::default().with_language(LanguagePreference::strict(&["de"]));
+	assert!(matches!(
+		from_nquads_str_in::<Plain>(document, &subject, context),
+		Err(FromNQuadsError::Deserialize(
+			FromLinkedDataError::MissingRequiredValue(_)
+		))
+	));
+
+	// Without preference, language-tagged strings are rejected.
+	assert!(matches!(
+		read::<Plain>(document),
+		Err(FromLinkedDataError::LiteralTypeMismatch { .. })
+	))
+}
@@ -0,0 +1,311 @@
+use iref::IriBuf;
+use langtag::LangTagBuf;
+use linked_data_next::{
+	Coercion, Context, Deserialize, QuadSink, RdfQuad, Serialize, from_nquads_reader,
+	from_nquads_str, from_nquads_str_in,
+	nquads::{self, FromNQuadsError, ParseErrorKind},
+	to_dataset,
+};
+use rdf_types::{
+	BlankIdBuf, Id, Literal, LiteralType, Quad, Term, XSD_STRING, dataset::TraversableDataset,
+	generator,
+};
+
+fn iri(value: &str) -> IriBuf {
+	IriBuf::new(value.to_owned()).unwrap()
+}
+
+fn blank(value: &str) -> BlankIdBuf {
+	BlankIdBuf::new(value.to_owned()).unwrap()
+}
+
+fn string(value: &str) -> Term {
+	Term::Literal(Literal::new(
+		value.to_owned(),
+		LiteralType::Any(XSD_STRING.to_owned()),
+	))
+}
+
+fn write(quads: Vec<RdfQuad>) -> String {
+	let mut writer = nquads::Writer::new(Vec::new());
+	for quad in quads {
+		writer.push(quad).unwrap();
+	}
+
+	String::from_utf8(writer.into_inner()).unwrap()
+}
+
+#[test]
+fn write_escapes() {
+	let output = write(vec![Quad(
+		Id::Iri(iri("http://example.org/s")),
+		iri("http://example.org/p"),
+		string("a \"quoted\"\nline\rwith back\\slash"),
+		None,
+	)]);
+
+	assert_eq!(
+		output,
+		"<http://example.org/s> <http://example.org/p> \"a \\\"quoted\\\"\\nline\\rwith back\\\\slash\" .\n"
+	);
+}
+
+#[test]
+fn write_language_tag() {
+	let output = write(vec![Quad(
+		Id::Iri(iri("http://example.org/s")),
+		iri("http://example.org/p"),
+		Term::Literal(Literal::new(
+			"bonjour".to_owned(),
+			LiteralType::LangString(LangTagBuf::new("fr-BE".to_owned()).unwrap()),
+		)),
+		None,
+	)]);
+
+	assert_eq!(
+		output,
+		"<http://example.org/s> <http://example.org/p> \"bonjour\"@fr-BE .\n"
+	);
+}
+
+#[test]
+fn write_datatype() {
+	let output = write(vec![Quad(
+		Id::Iri(iri("http://example.org/s")),
+		iri("http://example.org/p"),
+		Term::Literal(Literal::new(
+			"42".to_owned(),
+			LiteralType::Any(iri("http://www.w3.org/2001/XMLSchema#integer")),
+		)),
+		None,
+	)]);
+
+	assert_eq!(
+		output,
+		"<http://example.org/s> <http://example.org/p> \"42\"^^<http://www.w3.org/2001/XMLSchema#integer> .\n"
+	);
+}
+
+#[test]
+fn write_blank_nodes_and_named_graph() {
+	let output = write(vec![
+		Quad(
+			Id::Blank(blank("_:b0")),
+			iri("http://example.org/p"),
+			Term::<Id>::blank(blank("_:b1")),
+			Some(Id::Iri(iri("http://example.org/g"))),
+		),
+		Quad(
+			Id::Iri(iri("http://example.org/s")),
+			iri("http://example.org/p"),
+			string("x"),
+			Some(Id::Blank(blank("_:g"))),
+		),
+	]);
+
+	assert_eq!(
+		output,
+		"_:b0 <http://example.org/p> _:b1 <http://example.org/g> .\n\
+		<http://example.org/s> <http://example.org/p> \"x\" _:g .\n"
+	);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Person {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:name")]
+	name: String,
+
+	#[ld("ex:address")]
+	address: Address,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Address {
+	#[ld("ex:city")]
+	city: String,
+}
+
+fn person() -> Person {
+	Person {
+		id: iri("http://example.org/alice"),
+		name: "Alice".to_owned(),
+		address: Address {
+			city: "Paris".to_owned(),
+		},
+	}
+}
+
+#[test]
+fn to_string_sorted() {
+	let output = nquads::to_string_sorted(generator::Blank::new(), &person()).unwrap();
+
+	assert_eq!(
+		output,
+		"_:0 <http://example.org/city> \"Paris\" .\n\
+		<http://example.org/alice> <http://example.org/address> _:0 .\n\
+		<http://example.org/alice> <http://example.org/name> \"Alice\" .\n"
+	);
+}
+
+#[test]
+fn to_writer_streams_quads() {
+	let mut output = Vec::new();
+	nquads::to_writer(&mut output, generator::Blank::new(), &person()).unwrap();
+	let output = String::from_utf8(output).unwrap();
+
+	let mut lines: Vec<_> = output.lines().collect();
+	lines.sort_unstable();
+	let sorted = nquads::to_string_sorted(generator::Blank::new(), &person()).unwrap();
+	let mut expected: Vec<_> = sorted.lines().collect();
+	expected.sort_unstable();
+	assert_eq!(lines, expected);
+}
+
+/// Parses a document made of a single statement.
+fn parse_one(input: &str) -> Quad<Term> {
+	let dataset = nquads::parse_str(input).unwrap();
+	assert_eq!(dataset.len(), 1);
+	dataset.quads().next().unwrap().cloned()
+}
+
+fn object_literal(input: &str) -> Literal {
+	match parse_one(input).2 {
+		Term::Literal(literal) => literal,
+		Term::Id(_) => panic!("expected a literal"),
+	}
+}
+
+#[test]
+fn parse_escapes() {
+	let literal = object_literal(
+		r#"<http://example.org/s> <http://example.org/p> "a\"b\\c\nd\u00E9\U0001F600" ."#,
+	);
+	assert_eq!(literal.value, "a\"b\\c\nd\u{e9}\u{1f600}");
+	assert_eq!(literal.type_, LiteralType::Any(XSD_STRING.to_owned()));
+
+	let quad = parse_one(r#"<http://example.org/caf\u00E9> <http://example.org/p> "x" ."#);
+	assert_eq!(quad.0, Term::<Id>::iri(iri("http://example.org/caf\u{e9}")));
+}
+
+#[test]
+fn parse_language_tag_and_datatype() {
+	let literal = object_literal(r#"<http://example.org/s> <http://example.org/p> "hi"@en-GB ."#);
+	assert_eq!(
+		literal.type_,
+		LiteralType::LangString(LangTagBuf::new("en-GB".to_owned()).unwrap())
+	);
+
+	let literal = object_literal(
+		r#"<http://example.org/s> <http://example.org/p> "1"^^<http://www.w3.org/2001/XMLSchema#integer> ."#,
+	);
+	assert_eq!(
+		literal.type_,
+		LiteralType::Any(iri("http://www.w3.org/2001/XMLSchema#integer"))
+	);
+}
+
+#[test]
+fn parse_blank_nodes_and_named_graphs() {
+	let quad = parse_one("_:a <http://example.org/p> _:b _:g.");
+	assert_eq!(quad.0, Term::<Id>::blank(blank("_:a")));
+	assert_eq!(quad.2, Term::<Id>::blank(blank("_:b")));
+	assert_eq!(quad.3, Some(Term::<Id>::blank(blank("_:g"))));
+
+	let quad = parse_one(
+		"<http://example.org/s> <http://example.org/p> <http://example.org/o> <http://example.org/g> .",
+	);
+	assert_eq!(quad.3, Some(Term::<Id>::iri(iri("http://example.org/g"))));
+}
+
+#[test]
+fn parse_comments_and_empty_lines() {
+	let dataset = nquads::parse_str(
+		"# comment\n\n  \t\n<http://example.org/s> <http://example.org/p> \"#\" . # trailing\n",
+	)
+	.unwrap();
+	assert_eq!(dataset.len(), 1);
+}
+
+/// Parses the given document, expecting an error.
+fn parse_error(input: &str) -> nquads::ParseError {
+	nquads::parse_str(input).unwrap_err()
+}
+
+#[test]
+fn parse_malformed_lines() {
+	let e = parse_error(
+		"<http://example.org/s> <http://example.org/p> \"x\" .\n<http://example.org/s> <http://example.org/p> \"x\"",
+	);
+	assert_eq!((e.line, e.column), (2, 50));
+	assert!(matches!(e.kind, ParseErrorKind::UnexpectedEnd));
+
+	let e = parse_error(r#"<http://example.org/s> <http://example.org/p> "\q" ."#);
+	assert_eq!(e.column, 48);
+	assert!(matches!(e.kind, ParseErrorKind::InvalidEscape));
+
+	let e = parse_error(r#"<http://example.org/s> <http://example.org/p> "\u00G1" ."#);
+	assert!(matches!(e.kind, ParseErrorKind::InvalidEscape));
+
+	let e = parse_error(r#"<not an iri> <http://example.org/p> "x" ."#);
+	assert!(matches!(e.kind, ParseErrorKind::Unexpected(' ')));
+
+	let e = parse_error(r#"<relative> <http://example.org/p> "x" ."#);
+	assert!(matches!(e.kind, ParseErrorKind::InvalidIri(_)));
+
+	let e = parse_error(r#"<http://example.org/s> <http://example.org/p> "x"@ ."#);
+	assert!(matches!(e.kind, ParseErrorKind::InvalidLangTag(_)));
+
+	let e = parse_error(r#"<http://example.org/s> <http://example.org/p> "x" . extra"#);
+	assert!(matches!(e.kind, ParseErrorKind::Unexpected('e')));
+
+	let e = parse_error(r#""x" <http://example.org/p> "x" ."#);
+	assert!(matches!(e.kind, ParseErrorKind::Unexpected('"')));
+}
+
+#[test]
+fn roundtrip() {
+	let value = person();
+	let output = nquads::to_string_sorted(generator::Blank::new(), &value).unwrap();
+
+	let parsed = nquads::parse_str(&output).unwrap();
+	let expected = to_dataset(generator::Blank::new(), &value).unwrap();
+	let mut parsed_quads: Vec<_> = parsed.quads().collect();
+	let mut expected_quads: Vec<_> = expected.quads().collect();
+	parsed_quads.sort_unstable();
+	expected_quads.sort_unstable();
+	assert_eq!(parsed_quads, expected_quads);
+
+	let subject = Term::iri(value.id.clone());
+	let result: Person = from_nquads_str(&output, &subject).unwrap();
+	assert_eq!(result, value);
+
+	let result: Person = from_nquads_reader(output.as_bytes(), &subject).unwrap();
+	assert_eq!(result, value)
+}
+
+#[derive(Debug, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Counter {
+	#[ld("ex:count")]
+	count: u32,
+}
+
+#[test]
+fn deserialize_with_context() {
+	let input = r#"<http://example.org/c> <http://example.org/count> "42" ."#;
+	let subject = Term::<Id>::iri(iri("http://example.org/c"));
+
+	assert!(matches!(
+		from_nquads_str::<Counter>(input, &subject),
+		Err(FromNQuadsError::Deserialize(_))
+	));
+
+	let context = Context::default().with_coercion(Coercion::Lenient);
+	let counter: Counter = from_nquads_str_in(input, &subject, context).unwrap();
+	assert_eq!(counter.count, 42)
+}
@@ -0,0 +1,120 @@
+use iref::IriBuf;
+use linked_data_next::{
+	IntoQuadsError, QuadSink, RdfQuad, Serialize, to_lexical_quads, to_lexical_quads_into,
+	to_quads, to_quads_into,
+};
+use rdf_types::{
+	Quad, Term,
+	dataset::{BTreeDataset, IndexedBTreeDataset},
+	generator,
+};
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Person {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:name")]
+	name: String,
+
+	#[ld("ex:knows")]
+	knows: Vec<Friend>,
+}
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Friend {
+	#[ld("ex:name")]
+	name: String,
+}
+
+fn person() -> Person {
+	Person {
+		id: IriBuf::new("http://example.org/alice".to_owned()).unwrap(),
+		name: "Alice".to_owned(),
+		knows: vec![Friend {
+			name: "Bob".to_owned(),
+		}],
+	}
+}
+
+#[test]
+fn vec_sink() {
+	let mut quads = Vec::new();
+	to_quads_into(generator::Blank::new(), &mut quads, &person()).unwrap();
+	assert_eq!(quads, to_quads(generator::Blank::new(), &person()).unwrap());
+}
+
+#[test]
+fn lexical_vec_sink() {
+	let mut quads = Vec::new();
+	to_lexical_quads_into(generator::Blank::new(), &mut quads, &person()).unwrap();
+	assert_eq!(
+		quads,
+		to_lexical_quads(generator::Blank::new(), &person()).unwrap()
+	);
+}
+
+#[test]
+fn mut_ref_sink() {
+	let mut quads = Vec::new();
+	let mut sink = &mut quads;
+	to_quads_into(generator::Blank::new(), &mut sink, &person()).unwrap();
+	assert!(!quads.is_empty());
+}
+
+#[test]
+fn dataset_sinks() {
+	let quads = to_quads(generator::Blank::new(), &person()).unwrap();
+
+	let mut dataset = BTreeDataset::new();
+	to_quads_into(generator::Blank::new(), &mut dataset, &person()).unwrap();
+	assert_eq!(dataset.len(), quads.len());
+
+	let mut indexed = IndexedBTreeDataset::new();
+	to_quads_into(generator::Blank::new(), &mut indexed, &person()).unwrap();
+	assert_eq!(indexed.len(), quads.len());
+
+	for Quad(s, p, o, g) in quads {
+		let quad = Quad(Term::Id(s), Term::iri(p), o, g.map(Term::Id));
+		assert!(dataset.contains(quad.as_ref()));
+		assert!(indexed.contains(quad.as_ref()));
+	}
+}
+
+#[derive(Debug)]
+enum LimitError {
+	Full,
+	Serialization,
+}
+
+impl From<IntoQuadsError> for LimitError {
+	fn from(_: IntoQuadsError) -> Self {
+		Self::Serialization
+	}
+}
+
+/// Sink refusing quads past a given count.
+struct Limit(usize, Vec<RdfQuad>);
+
+impl QuadSink<RdfQuad> for Limit {
+	type Error = LimitError;
+
+	fn push(&mut self, quad: RdfQuad) -> Result<(), Self::Error> {
+		if self.1.len() == self.0 {
+			Err(LimitError::Full)
+		} else {
+			self.1.push(quad);
+			Ok(())
+		}
+	}
+}
+
+#[test]
+fn sink_error_stops_serialization() {
+	let mut sink = Limit(2, Vec::new());
+	let result = to_quads_into(generator::Blank::new(), &mut sink, &person());
+	assert!(matches!(result, Err(LimitError::Full)));
+	assert_eq!(sink.1.len(), 2);
+}
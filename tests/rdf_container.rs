@@ -0,0 +1,150 @@
+use iref::IriBuf;
+use linked_data_next::{
+	Alt, Bag, Deserialize, FromLinkedDataError, MalformedContainerReason, Seq, Serialize,
+	from_nquads_str, nquads::FromNQuadsError, roundtrip,
+};
+use rdf_types::{Id, Term, generator};
+
+fn ex(local: &str) -> IriBuf {
+	IriBuf::new(format!("http://example.org/{local}")).unwrap()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Item {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:seq")]
+	seq: Seq<String>,
+
+	#[ld("ex:bag")]
+	bag: Bag<String>,
+
+	#[ld("ex:alt")]
+	alt: Alt<String>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct SeqItem {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:seq")]
+	seq: Seq<String>,
+}
+
+const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+
+/// Builds a document binding `ex:item` to a container `_:c` of the given
+/// class, with `(index, value)` members.
+fn container(class: &str, members: &[(&str, &str)]) -> String {
+	let mut document = format!(
+		"<http://example.org/item> <http://example.org/seq> _:c .\n\
+		_:c <{RDF}type> <{RDF}{class}> .\n"
+	);
+	for (n, value) in members {
+		document.push_str(&format!("_:c <{RDF}_{n}> \"{value}\" .\n"))
+	}
+
+	document
+}
+
+fn read(document: &str) -> Result<SeqItem, FromLinkedDataError> {
+	match from_nquads_str(document, &Term::<Id>::iri(ex("item"))) {
+		Ok(item) => Ok(item),
+		Err(FromNQuadsError::Deserialize(e)) => Err(e),
+		Err(e) => panic!("{e}"),
+	}
+}
+
+fn malformed(document: &str) -> MalformedContainerReason {
+	match read(document) {
+		Err(FromLinkedDataError::MalformedContainer { reason, .. }) => reason,
+		other => panic!("unexpected result: {other:?}"),
+	}
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+	values.iter().map(|v| (*v).to_owned()).collect()
+}
+
+#[test]
+fn container_round_trip() {
+	let value = Item {
+		id: ex("item"),
+		seq: Seq(strings(&["b", "a", "b"])),
+		bag: Bag(strings(&["x"])),
+		alt: Alt(strings(&["en", "fr"])),
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value);
+
+	let value = Item {
+		id: ex("item"),
+		seq: Seq::default(),
+		bag: Bag::default(),
+		alt: Alt::default(),
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn members_are_ordered_by_index() {
+	let document = container("Seq", &[("10", "j"), ("2", "b"), ("1", "a")]);
+	assert_eq!(malformed(&document), MalformedContainerReason::Gap(3));
+
+	let members: Vec<_> = (1..=10).map(|n| n.to_string()).collect();
+	let statements: Vec<_> = members
+		.iter()
+		.rev()
+		.map(|n| (n.as_str(), n.as_str()))
+		.collect();
+	let item = read(&container("Seq", &statements)).unwrap();
+	assert_eq!(item.seq.0, members)
+}
+
+#[test]
+fn gap() {
+	let document = container("Seq", &[("1", "a"), ("3", "c")]);
+	assert_eq!(malformed(&document), MalformedContainerReason::Gap(2));
+
+	let document = container("Seq", &[("2", "b")]);
+	assert_eq!(malformed(&document), MalformedContainerReason::Gap(1))
+}
+
+#[test]
+fn duplicate_member() {
+	let document = container("Seq", &[("1", "a"), ("2", "b"), ("2", "c")]);
+	assert_eq!(
+		malformed(&document),
+		MalformedContainerReason::DuplicateMember(2)
+	)
+}
+
+#[test]
+fn invalid_membership_properties_are_ignored() {
+	let document = container("Seq", &[("1", "a"), ("01", "x"), ("0", "y"), ("2a", "z")]);
+	assert_eq!(read(&document).unwrap().seq.0, strings(&["a"]))
+}
+
+#[test]
+fn untyped_container() {
+	let document = container("Seq", &[("1", "a")])
+		.replace(&format!("<{RDF}type>"), "<http://example.org/type>");
+	assert_eq!(read(&document).unwrap().seq.0, strings(&["a"]))
+}
+
+#[test]
+fn type_mismatch() {
+	let document = container("Bag", &[("1", "a")]);
+	match read(&document) {
+		Err(FromLinkedDataError::TypeMismatch {
+			expected, found, ..
+		}) => {
+			assert_eq!(expected, [IriBuf::new(format!("{RDF}Seq")).unwrap()]);
+			assert_eq!(found, [IriBuf::new(format!("{RDF}Bag")).unwrap()])
+		}
+		other => panic!("unexpected result: {other:?}"),
+	}
+}
@@ -0,0 +1,195 @@
+use iref::IriBuf;
+use linked_data_next::{
+	Context, Deserialize, FromLinkedDataError, MalformedListReason, RdfListReader, Serialize,
+	from_nquads_str, from_nquads_str_in, nquads::FromNQuadsError, roundtrip,
+};
+use rdf_types::{BlankIdBuf, Id, Term, generator};
+
+fn ex(local: &str) -> IriBuf {
+	IriBuf::new(format!("http://example.org/{local}")).unwrap()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Item {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:values")]
+	values: Vec<String>,
+}
+
+const HEAD: &str = "<http://example.org/item> <http://example.org/values> _:a .\n";
+const FIRST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#first";
+const REST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#rest";
+const NIL: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#nil";
+
+/// Builds a list document from `(node, predicate, object)` statements, with
+/// `first` and `rest` expanding to `rdf:first` and `rdf:rest`.
+fn list(statements: &[(&str, &str, &str)]) -> String {
+	let mut document = HEAD.to_owned();
+	for (node, predicate, object) in statements {
+		let predicate = match *predicate {
+			"first" => FIRST,
+			_ => REST,
+		};
+
+		document.push_str(&format!("{node} <{predicate}> {object} .\n"))
+	}
+
+	document
+}
+
+fn read(document: &str) -> Result<Item, FromLinkedDataError> {
+	match from_nquads_str(document, &Term::<Id>::iri(ex("item"))) {
+		Ok(item) => Ok(item),
+		Err(FromNQuadsError::Deserialize(e)) => Err(e),
+		Err(e) => panic!("{e}"),
+	}
+}
+
+fn malformed(document: &str) -> MalformedListReason {
+	match read(document) {
+		Err(FromLinkedDataError::MalformedList { reason, .. }) => reason,
+		other => panic!("unexpected result: {other:?}"),
+	}
+}
+
+fn nil() -> String {
+	format!("<{NIL}>")
+}
+
+#[test]
+fn list_round_trip() {
+	let value = Item {
+		id: ex("item"),
+		values: vec!["b".to_owned(), "a".to_owned(), "b".to_owned()],
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value);
+
+	let value = Item {
+		id: ex("item"),
+		values: Vec::new(),
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn cyclic_list() {
+	let document = list(&[
+		("_:a", "first", "\"a\""),
+		("_:a", "rest", "_:b"),
+		("_:b", "first", "\"b\""),
+		("_:b", "rest", "_:a"),
+	]);
+
+	assert!(matches!(
+		read(&document),
+		Err(FromLinkedDataError::CyclicList(_))
+	));
+
+	let document = list(&[("_:a", "first", "\"a\""), ("_:a", "rest", "_:a")]);
+	assert!(matches!(
+		read(&document),
+		Err(FromLinkedDataError::CyclicList(_))
+	))
+}
+
+#[test]
+fn unterminated_list() {
+	let document = list(&[
+		("_:a", "first", "\"a\""),
+		("_:a", "rest", "_:b"),
+		("_:b", "first", "\"b\""),
+	]);
+	assert_eq!(malformed(&document), MalformedListReason::MissingRest);
+
+	let document = list(&[("_:a", "first", "\"a\""), ("_:a", "rest", "_:b")]);
+	assert_eq!(malformed(&document), MalformedListReason::MissingFirst)
+}
+
+#[test]
+fn multiple_rests_and_firsts() {
+	let document = list(&[
+		("_:a", "first", "\"a\""),
+		("_:a", "rest", &nil()),
+		("_:a", "rest", "_:b"),
+		("_:b", "first", "\"b\""),
+		("_:b", "rest", &nil()),
+	]);
+	assert_eq!(malformed(&document), MalformedListReason::MultipleRests);
+
+	let document = list(&[
+		("_:a", "first", "\"a\""),
+		("_:a", "first", "\"b\""),
+		("_:a", "rest", &nil()),
+	]);
+	assert_eq!(malformed(&document), MalformedListReason::MultipleFirsts)
+}
+
+/// Builds a well-formed list of `len` items.
+fn long_list(len: usize) -> String {
+	let mut statements = Vec::new();
+	let nodes: Vec<_> = (0..len).map(|i| format!("_:n{i}")).collect();
+	let mut document = "<http://example.org/item> <http://example.org/values> _:n0 .\n".to_owned();
+	for (i, node) in nodes.iter().enumerate() {
+		let rest = nodes.get(i + 1).cloned().unwrap_or_else(nil);
+		statements.push(format!(
+			"{node} <{FIRST}> \"{i}\" .\n{node} <{REST}> {rest} .\n"
+		));
+	}
+
+	document.extend(statements);
+	document
+}
+
+#[test]
+fn over_long_list() {
+	let subject = Term::<Id>::iri(ex("item"));
+	let document = long_list(4);
+
+	let context = Context::default().with_list_reader(RdfListReader::with_max_length(3));
+	assert!(matches!(
+		from_nquads_str_in::<Item>(&document, &subject, context),
+		Err(FromNQuadsError::Deserialize(
+			FromLinkedDataError::ListTooLong { max_length: 3, .. }
+		))
+	));
+
+	let context = Context::default().with_list_reader(RdfListReader::with_max_length(4));
+	let item: Item = from_nquads_str_in(&document, &subject, context).unwrap();
+	assert_eq!(item.values, ["0", "1", "2", "3"]);
+
+	let context = Context::default().with_list_reader(RdfListReader::unbounded());
+	let item: Item = from_nquads_str_in(&document, &subject, context).unwrap();
+	assert_eq!(item.values.len(), 4);
+
+	let context = Context::<()>::default();
+	assert_eq!(
+		context.list_reader.max_length,
+		Some(RdfListReader::DEFAULT_MAX_LENGTH)
+	)
+}
+
+#[test]
+fn reader_from_head() {
+	let dataset = linked_data_next::nquads::parse_str(&long_list(2)).unwrap();
+	let head = Term::blank(BlankIdBuf::new("_:n0".to_owned()).unwrap());
+
+	let items: Vec<String> = RdfListReader::new()
+		.read(&(), &(), &dataset, None, &head, Context::default())
+		.unwrap();
+	assert_eq!(items, ["0", "1"]);
+
+	assert!(matches!(
+		RdfListReader::with_max_length(1).read::<_, _, _, String>(
+			&(),
+			&(),
+			&dataset,
+			None,
+			&head,
+			Context::default()
+		),
+		Err(FromLinkedDataError::ListTooLong { max_length: 1, .. })
+	))
+}
@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+
+use iref::IriBuf;
+use linked_data_next::{Deserialize, Serialize, from_nquads_str, nquads, roundtrip};
+use rdf_types::{Id, Term, generator};
+
+fn ex(local: &str) -> IriBuf {
+	IriBuf::new(format!("http://example.org/{local}")).unwrap()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Group {
+	#[ld(id)]
+	id: IriBuf,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Person {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:name")]
+	name: String,
+
+	#[ld(reverse = "ex:owner")]
+	owned: Group,
+
+	#[ld(reverse = "ex:member")]
+	groups: HashSet<Group>,
+
+	#[ld(reverse = "ex:manager")]
+	managed: Option<Group>,
+}
+
+fn alice() -> Person {
+	Person {
+		id: ex("alice"),
+		name: "Alice".to_owned(),
+		owned: Group { id: ex("house") },
+		groups: [Group { id: ex("club") }, Group { id: ex("team") }]
+			.into_iter()
+			.collect(),
+		managed: None,
+	}
+}
+
+#[test]
+fn serialize_reverse() {
+	let output = nquads::to_string_sorted(generator::Blank::new(), &alice()).unwrap();
+	assert_eq!(
+		output,
+		"<http://example.org/alice> <http://example.org/name> \"Alice\" .\n\
+		<http://example.org/club> <http://example.org/member> <http://example.org/alice> .\n\
+		<http://example.org/house> <http://example.org/owner> <http://example.org/alice> .\n\
+		<http://example.org/team> <http://example.org/member> <http://example.org/alice> .\n"
+	)
+}
+
+#[test]
+fn reverse_round_trip() {
+	let value = alice();
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value);
+
+	let value = Person {
+		groups: HashSet::new(),
+		managed: Some(Group { id: ex("shop") }),
+		..alice()
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn deserialize_reverse() {
+	let input = "\
+		<http://example.org/alice> <http://example.org/name> \"Alice\" .\n\
+		<http://example.org/house> <http://example.org/owner> <http://example.org/alice> .\n\
+		<http://example.org/club> <http://example.org/member> <http://example.org/alice> .\n\
+		<http://example.org/club> <http://example.org/member> <http://example.org/bob> .\n\
+		<http://example.org/team> <http://example.org/member> <http://example.org/alice> .\n\
+		<http://example.org/alice> <http://example.org/member> <http://example.org/other> .\n";
+
+	let result: Person = from_nquads_str(input, &Term::<Id>::iri(ex("alice"))).unwrap();
+	assert_eq!(result, alice())
+}
+
+#[test]
+fn reverse_in_other_graph_is_ignored() {
+	let input = "\
+		<http://example.org/alice> <http://example.org/name> \"Alice\" .\n\
+		<http://example.org/house> <http://example.org/owner> <http://example.org/alice> <http://example.org/g> .\n";
+
+	assert!(from_nquads_str::<Person>(input, &Term::<Id>::iri(ex("alice"))).is_err())
+}
+
+#[test]
+fn too_many_reverse_values() {
+	let input = "\
+		<http://example.org/alice> <http://example.org/name> \"Alice\" .\n\
+		<http://example.org/house> <http://example.org/owner> <http://example.org/alice> .\n\
+		<http://example.org/flat> <http://example.org/owner> <http://example.org/alice> .\n";
+
+	assert!(matches!(
+		from_nquads_str::<Person>(input, &Term::<Id>::iri(ex("alice"))),
+		Err(nquads::FromNQuadsError::Deserialize(
+			linked_data_next::FromLinkedDataError::TooManyValues(_)
+		))
+	))
+}
@@ -0,0 +1,531 @@
+use iref::IriBuf;
+use linked_data_next::{
+	Coercion, Context, Deserialize, RdfQuad, Serialize, from_turtle_reader, from_turtle_str,
+	from_turtle_str_in,
+	turtle::{self, FromTurtleError, ParseErrorKind, PrefixMap},
+};
+use rdf_types::{
+	BlankIdBuf, Id, Literal, LiteralType, Quad, RDF_FIRST, RDF_NIL, RDF_REST, Term, XSD_STRING,
+	generator,
+};
+use xsd_types::{XSD_BOOLEAN, XSD_INTEGER};
+
+fn iri(value: &str) -> IriBuf {
+	IriBuf::new(value.to_owned()).unwrap()
+}
+
+fn blank(value: &str) -> BlankIdBuf {
+	BlankIdBuf::new(value.to_owned()).unwrap()
+}
+
+fn ex(local: &str) -> IriBuf {
+	iri(&format!("http://example.org/{local}"))
+}
+
+fn string(value: &str) -> Term {
+	Term::Literal(Literal::new(
+		value.to_owned(),
+		LiteralType::Any(XSD_STRING.to_owned()),
+	))
+}
+
+fn prefixes() -> PrefixMap {
+	[("ex".to_owned(), "http://example.org/".to_owned())]
+		.into_iter()
+		.collect()
+}
+
+fn write(quads: &[RdfQuad]) -> String {
+	turtle::quads_to_string(&prefixes(), quads)
+}
+
+#[test]
+fn write_escapes() {
+	let output = write(&[Quad(
+		Id::Iri(ex("s")),
+		ex("p"),
+		string("a \"quoted\"\nline with back\\slash"),
+		None,
+	)]);
+
+	assert_eq!(
+		output,
+		"@prefix ex: <http://example.org/> .\n\nex:s ex:p \"a \\\"quoted\\\"\\nline with back\\\\slash\" .\n"
+	);
+}
+
+#[test]
+fn write_literals() {
+	let output = write(&[
+		Quad(
+			Id::Iri(ex("s")),
+			ex("p"),
+			Term::Literal(Literal::new(
+				"bonjour".to_owned(),
+				LiteralType::LangString("fr".parse().unwrap()),
+			)),
+			None,
+		),
+		Quad(
+			Id::Iri(ex("s")),
+			ex("p"),
+			Term::Literal(Literal::new(
+				"42".to_owned(),
+				LiteralType::Any(ex("Celsius")),
+			)),
+			None,
+		),
+	]);
+
+	assert_eq!(
+		output,
+		"@prefix ex: <http://example.org/> .\n\nex:s ex:p \"bonjour\"@fr, \"42\"^^ex:Celsius .\n"
+	);
+}
+
+#[test]
+fn prefix_compaction() {
+	let prefixes: PrefixMap = [
+		("ex".to_owned(), "http://example.org/".to_owned()),
+		("ns".to_owned(), "http://example.org/ns/".to_owned()),
+	]
+	.into_iter()
+	.collect();
+
+	let output = turtle::quads_to_string(
+		&prefixes,
+		&[
+			Quad(
+				Id::Iri(ex("ns/s")),
+				iri("http://www.w3.org/1999/02/22-rdf-syntax-ns#type"),
+				Term::iri(ex("Class")),
+				None,
+			),
+			Quad(
+				Id::Iri(ex("ns/s")),
+				ex("p"),
+				Term::iri(ex("not/a/local.name.")),
+				None,
+			),
+			Quad(
+				Id::Iri(ex("ns/s")),
+				ex("p"),
+				Term::iri(iri("http://other.org/o")),
+				None,
+			),
+		],
+	);
+
+	// The longest namespace wins, and IRIs that are not valid local names are
+	// written in full.
+	assert_eq!(
+		output,
+		"@prefix ex: <http://example.org/> .\n\
+		@prefix ns: <http://example.org/ns/> .\n\n\
+		ns:s a ex:Class ;\n\
+		\tex:p <http://example.org/not/a/local.name.>, <http://other.org/o> .\n"
+	);
+}
+
+#[test]
+fn write_blank_nodes() {
+	let output = write(&[
+		Quad(
+			Id::Iri(ex("s")),
+			ex("p"),
+			Term::blank(blank("_:nested")),
+			None,
+		),
+		Quad(Id::Blank(blank("_:nested")), ex("q"), string("x"), None),
+		Quad(
+			Id::Iri(ex("s")),
+			ex("p"),
+			Term::blank(blank("_:shared")),
+			None,
+		),
+		Quad(
+			Id::Iri(ex("t")),
+			ex("p"),
+			Term::blank(blank("_:shared")),
+			None,
+		),
+		Quad(Id::Blank(blank("_:shared")), ex("q"), string("y"), None),
+		Quad(
+			Id::Iri(ex("t")),
+			ex("p"),
+			Term::blank(blank("_:empty")),
+			None,
+		),
+	]);
+
+	// Blank nodes referenced once are nested, the others keep their label.
+	assert_eq!(
+		output,
+		"@prefix ex: <http://example.org/> .\n\n\
+		ex:s ex:p [\n\
+		\t\tex:q \"x\"\n\
+		\t], _:shared .\n\n\
+		ex:t ex:p _:shared, [] .\n\n\
+		_:shared ex:q \"y\" .\n"
+	);
+}
+
+#[test]
+fn write_collections() {
+	let rdf = |local: &str| {
+		iri(&format!(
+			"http://www.w3.org/1999/02/22-rdf-syntax-ns#{local}"
+		))
+	};
+	let output = write(&[
+		Quad(Id::Iri(ex("s")), ex("p"), Term::blank(blank("_:l0")), None),
+		Quad(Id::Blank(blank("_:l0")), rdf("first"), string("a"), None),
+		Quad(
+			Id::Blank(blank("_:l0")),
+			rdf("rest"),
+			Term::blank(blank("_:l1")),
+			None,
+		),
+		Quad(Id::Blank(blank("_:l1")), rdf("first"), string("b"), None),
+		Quad(
+			Id::Blank(blank("_:l1")),
+			rdf("rest"),
+			Term::iri(rdf("nil")),
+			None,
+		),
+		Quad(Id::Iri(ex("s")), ex("q"), Term::iri(rdf("nil")), None),
+	]);
+
+	assert_eq!(
+		output,
+		"@prefix ex: <http://example.org/> .\n\nex:s ex:p ( \"a\" \"b\" ) ;\n\tex:q () .\n"
+	);
+}
+
+#[test]
+fn write_trig_graphs() {
+	let output = write(&[
+		Quad(Id::Iri(ex("s")), ex("p"), string("default"), None),
+		Quad(
+			Id::Iri(ex("s")),
+			ex("p"),
+			string("named"),
+			Some(Id::Iri(ex("g"))),
+		),
+		Quad(
+			Id::Iri(ex("s")),
+			ex("p"),
+			string("anonymous"),
+			Some(Id::Blank(blank("_:g"))),
+		),
+	]);
+
+	assert_eq!(
+		output,
+		"@prefix ex: <http://example.org/> .\n\n\
+		ex:s ex:p \"default\" .\n\n\
+		ex:g {\n\
+		\tex:s ex:p \"named\" .\n\
+		}\n\n\
+		_:g {\n\
+		\tex:s ex:p \"anonymous\" .\n\
+		}\n"
+	);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+#[ld(type = "ex:Person")]
+struct Person {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:name")]
+	name: String,
+}
+
+#[test]
+fn derived_prefixes() {
+	let value = Person {
+		id: ex("alice"),
+		name: "Alice".to_owned(),
+	};
+
+	let output = turtle::to_string(generator::Blank::new(), &value).unwrap();
+	assert_eq!(
+		output,
+		"@prefix ex: <http://example.org/> .\n\nex:alice a ex:Person ;\n\tex:name \"Alice\" .\n"
+	);
+
+	let mut bytes = Vec::new();
+	turtle::to_writer(&mut bytes, generator::Blank::new(), &value).unwrap();
+	assert_eq!(String::from_utf8(bytes).unwrap(), output);
+}
+
+type TermQuad = Quad<Term, Term, Term, Term>;
+
+/// Parses the given document into a sorted list of quads.
+fn parse(input: &str) -> Vec<TermQuad> {
+	let mut quads: Vec<_> = turtle::parse_str(input).unwrap().into_iter().collect();
+	quads.sort();
+	quads
+}
+
+fn quad(s: impl Into<Term>, p: IriBuf, o: impl Into<Term>, g: Option<Term>) -> TermQuad {
+	Quad(s.into(), Term::iri(p), o.into(), g)
+}
+
+fn b(value: &str) -> Term {
+	Term::blank(blank(value))
+}
+
+fn i(value: IriBuf) -> Term {
+	Term::iri(value)
+}
+
+fn literal(value: &str, ty: LiteralType) -> Term {
+	Term::Literal(Literal::new(value.to_owned(), ty))
+}
+
+fn sorted(mut quads: Vec<TermQuad>) -> Vec<TermQuad> {
+	quads.sort();
+	quads
+}
+
+fn parse_error(input: &str) -> ParseErrorKind {
+	turtle::parse_str(input).unwrap_err().kind
+}
+
+#[test]
+fn parse_prefixed_names() {
+	let quads = parse(
+		"@prefix ex: <http://example.org/> .\n\
+		PREFIX : <http://example.org/default#>\n\
+		ex:s ex:p :o, ex:a.b, ex:c\\/d, ex:e%20f .\n\
+		ex:s a ex:Thing .",
+	);
+
+	assert_eq!(
+		quads,
+		sorted(vec![
+			quad(
+				i(ex("s")),
+				ex("p"),
+				i(iri("http://example.org/default#o")),
+				None
+			),
+			quad(i(ex("s")), ex("p"), i(ex("a.b")), None),
+			quad(i(ex("s")), ex("p"), i(ex("c/d")), None),
+			quad(i(ex("s")), ex("p"), i(ex("e%20f")), None),
+			quad(
+				i(ex("s")),
+				iri("http://www.w3.org/1999/02/22-rdf-syntax-ns#type"),
+				i(ex("Thing")),
+				None
+			),
+		])
+	);
+
+	assert!(matches!(
+		parse_error("unknown:s <http://example.org/p> <http://example.org/o> ."),
+		ParseErrorKind::UnknownPrefix(p) if p == "unknown"
+	));
+}
+
+#[test]
+fn parse_literal_escapes() {
+	let quads = parse(
+		r#"@prefix ex: <http://example.org/> .
+		ex:s ex:p "tab\there \"quoted\" \u00E9\U0001F600", 'single \'quote\'', """long
+"line" """, "bonjour"@fr-CA, "42"^^ex:Celsius ."#,
+	);
+
+	assert_eq!(
+		quads,
+		sorted(vec![
+			quad(
+				i(ex("s")),
+				ex("p"),
+				string("tab\there \"quoted\" \u{e9}\u{1f600}"),
+				None
+			),
+			quad(i(ex("s")), ex("p"), string("single 'quote'"), None),
+			quad(i(ex("s")), ex("p"), string("long\n\"line\" "), None),
+			quad(
+				i(ex("s")),
+				ex("p"),
+				literal("bonjour", LiteralType::LangString("fr-CA".parse().unwrap())),
+				None
+			),
+			quad(
+				i(ex("s")),
+				ex("p"),
+				literal("42", LiteralType::Any(ex("Celsius"))),
+				None
+			),
+		])
+	);
+
+	assert!(matches!(
+		parse_error(r#"<http://example.org/s> <http://example.org/p> "\q" ."#),
+		ParseErrorKind::InvalidEscape
+	));
+}
+
+#[test]
+fn parse_keyword_before_dot() {
+	let quads = parse(
+		"@prefix ex: <http://example.org/> .\n\
+		ex:s ex:p true.\n\
+		ex:s ex:q false, 42.",
+	);
+
+	assert_eq!(
+		quads,
+		sorted(vec![
+			quad(
+				i(ex("s")),
+				ex("p"),
+				literal("true", LiteralType::Any(XSD_BOOLEAN.to_owned())),
+				None
+			),
+			quad(
+				i(ex("s")),
+				ex("q"),
+				literal("false", LiteralType::Any(XSD_BOOLEAN.to_owned())),
+				None
+			),
+			quad(
+				i(ex("s")),
+				ex("q"),
+				literal("42", LiteralType::Any(XSD_INTEGER.to_owned())),
+				None
+			),
+		])
+	);
+}
+
+#[test]
+fn parse_non_ascii_blank_node_label() {
+	let quads = parse("_:caf\u{e9} <http://example.org/p> _:na\u{ef}ve.");
+	assert_eq!(quads, vec![quad(b("_:0"), ex("p"), b("_:1"), None)]);
+}
+
+#[test]
+fn parse_blank_node_property_lists() {
+	let quads = parse(
+		"@prefix ex: <http://example.org/> .\n\
+		[ ex:name \"Alice\" ] .\n\
+		[ ] ex:p [ ex:q ex:o ] .",
+	);
+
+	assert_eq!(
+		quads,
+		sorted(vec![
+			quad(b("_:0"), ex("name"), string("Alice"), None),
+			quad(b("_:1"), ex("p"), b("_:2"), None),
+			quad(b("_:2"), ex("q"), i(ex("o")), None),
+		])
+	);
+
+	assert!(matches!(
+		parse_error("[ ] ."),
+		ParseErrorKind::Unexpected('.')
+	));
+	assert!(matches!(
+		parse_error("[] ."),
+		ParseErrorKind::Unexpected('.')
+	));
+}
+
+#[test]
+fn parse_collections() {
+	let quads = parse(
+		"@prefix ex: <http://example.org/> .\n\
+		ex:s ex:p ( ex:a \"b\" ) .\n\
+		ex:s ex:q () .",
+	);
+
+	assert_eq!(
+		quads,
+		sorted(vec![
+			quad(i(ex("s")), ex("p"), b("_:1"), None),
+			quad(b("_:1"), RDF_FIRST.to_owned(), i(ex("a")), None),
+			quad(b("_:1"), RDF_REST.to_owned(), b("_:0"), None),
+			quad(b("_:0"), RDF_FIRST.to_owned(), string("b"), None),
+			quad(b("_:0"), RDF_REST.to_owned(), i(RDF_NIL.to_owned()), None),
+			quad(i(ex("s")), ex("q"), i(RDF_NIL.to_owned()), None),
+		])
+	);
+}
+
+#[test]
+fn parse_trig_graphs() {
+	let quads = parse(
+		"@prefix ex: <http://example.org/> .\n\
+		ex:s ex:p ex:o .\n\
+		ex:g { ex:s ex:p ex:a }\n\
+		GRAPH <http://example.org/h> { ex:s ex:p ex:b . ex:s ex:q ex:c . }\n\
+		{ ex:t ex:p ex:o }\n\
+		[ ] { ex:s ex:p ex:d }",
+	);
+
+	assert_eq!(
+		quads,
+		sorted(vec![
+			quad(i(ex("s")), ex("p"), i(ex("o")), None),
+			quad(i(ex("t")), ex("p"), i(ex("o")), None),
+			quad(i(ex("s")), ex("p"), i(ex("a")), Some(i(ex("g")))),
+			quad(i(ex("s")), ex("p"), i(ex("b")), Some(i(ex("h")))),
+			quad(i(ex("s")), ex("q"), i(ex("c")), Some(i(ex("h")))),
+			quad(i(ex("s")), ex("p"), i(ex("d")), Some(b("_:0"))),
+		])
+	);
+}
+
+#[test]
+fn parse_error_position() {
+	let error =
+		turtle::parse_str("<http://example.org/s>\n  <http://example.org/p> ?").unwrap_err();
+	assert_eq!((error.line, error.column), (2, 26));
+	assert!(matches!(error.kind, ParseErrorKind::Unexpected('?')));
+}
+
+#[test]
+fn writer_parser_round_trip() {
+	let value = Person {
+		id: ex("alice"),
+		name: "Alice \"A\"\n".to_owned(),
+	};
+
+	let document = turtle::to_string(generator::Blank::new(), &value).unwrap();
+	let subject = Term::<Id>::iri(ex("alice"));
+
+	let result: Person = from_turtle_str(&document, &subject).unwrap();
+	assert_eq!(result, value);
+
+	let result: Person = from_turtle_reader(document.as_bytes(), &subject).unwrap();
+	assert_eq!(result, value)
+}
+
+#[derive(Debug, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Counter {
+	#[ld("ex:count")]
+	count: u32,
+}
+
+#[test]
+fn deserialize_with_context() {
+	let input = r#"<http://example.org/c> <http://example.org/count> "42" ."#;
+	let subject = Term::<Id>::iri(ex("c"));
+
+	assert!(matches!(
+		from_turtle_str::<Counter>(input, &subject),
+		Err(FromTurtleError::Deserialize(_))
+	));
+
+	let context = Context::default().with_coercion(Coercion::Lenient);
+	let counter: Counter = from_turtle_str_in(input, &subject, context).unwrap();
+	assert_eq!(counter.count, 42)
+}
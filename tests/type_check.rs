@@ -0,0 +1,128 @@
+use iref::IriBuf;
+use linked_data_next::{
+	Deserialize, FromLinkedDataError, LinkedDataDeserializeSubject, Serialize, from_nquads_str,
+	nquads::FromNQuadsError, roundtrip,
+};
+use rdf_types::{Id, Term, generator};
+
+fn ex(local: &str) -> IriBuf {
+	IriBuf::new(format!("http://example.org/{local}")).unwrap()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+#[ld(type = "ex:Person")]
+struct Person {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:name")]
+	name: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+#[ld(type = "ex:Person", skip_type_check)]
+struct LoosePerson {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:name")]
+	name: String,
+}
+
+const NAME: &str = "<http://example.org/jane> <http://example.org/name> \"Jane\" .\n";
+
+fn typed(types: &[&str]) -> String {
+	let mut document = NAME.to_owned();
+	for ty in types {
+		document.push_str(&format!(
+			"<http://example.org/jane> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/{ty}> .\n"
+		))
+	}
+
+	document
+}
+
+fn read<T: LinkedDataDeserializeSubject>(document: &str) -> Result<T, FromLinkedDataError> {
+	match from_nquads_str(document, &Term::<Id>::iri(ex("jane"))) {
+		Ok(value) => Ok(value),
+		Err(FromNQuadsError::Deserialize(e)) => Err(e),
+		Err(e) => panic!("{e}"),
+	}
+}
+
+fn person() -> Person {
+	Person {
+		id: ex("jane"),
+		name: "Jane".to_owned(),
+	}
+}
+
+#[test]
+fn matching_type() {
+	assert_eq!(read::<Person>(&typed(&["Person"])).unwrap(), person());
+
+	let value = person();
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn extra_types() {
+	assert_eq!(
+		read::<Person>(&typed(&["Agent", "Person", "Author"])).unwrap(),
+		person()
+	)
+}
+
+#[test]
+fn type_mismatch() {
+	match read::<Person>(&typed(&["Organization", "Agent"])) {
+		Err(FromLinkedDataError::TypeMismatch {
+			expected, found, ..
+		}) => {
+			assert_eq!(expected, [ex("Person")]);
+			let mut found = found;
+			found.sort();
+			assert_eq!(found, [ex("Agent"), ex("Organization")])
+		}
+		other => panic!("unexpected result: {other:?}"),
+	}
+}
+
+#[test]
+fn untyped_node() {
+	match read::<Person>(NAME) {
+		Err(FromLinkedDataError::TypeMismatch {
+			expected, found, ..
+		}) => {
+			assert_eq!(expected, [ex("Person")]);
+			assert!(found.is_empty())
+		}
+		other => panic!("unexpected result: {other:?}"),
+	}
+}
+
+#[test]
+fn skip_type_check() {
+	let expected = LoosePerson {
+		id: ex("jane"),
+		name: "Jane".to_owned(),
+	};
+
+	assert_eq!(read::<LoosePerson>(NAME).unwrap(), expected);
+	assert_eq!(
+		read::<LoosePerson>(&typed(&["Organization"])).unwrap(),
+		expected
+	);
+
+	// The type is still serialized.
+	assert_eq!(
+		read::<Person>(
+			&linked_data_next::nquads::to_string_sorted(generator::Blank::new(), &expected)
+				.unwrap()
+		)
+		.unwrap(),
+		person()
+	)
+}
@@ -0,0 +1,10 @@
+use linked_data_next::Serialize;
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Person {
+	#[ld("ex:nickname", set, list)]
+	nicknames: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use linked_data_next::Serialize;
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+#[ld(dataset)]
+struct Document {
+	#[ld(default_graph, named_graph = "ex:people")]
+	people: Vec<String>,
+}
+
+fn main() {}
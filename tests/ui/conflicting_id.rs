@@ -0,0 +1,11 @@
+use iref::IriBuf;
+use linked_data_next::Serialize;
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Person {
+	#[ld(id, "ex:id")]
+	id: IriBuf,
+}
+
+fn main() {}
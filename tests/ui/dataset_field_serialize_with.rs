@@ -0,0 +1,10 @@
+use linked_data_next::Serialize;
+
+#[derive(Serialize)]
+#[ld(dataset)]
+struct Document {
+	#[ld(default_graph, serialize_with = "serialize_people")]
+	people: Vec<String>,
+}
+
+fn main() {}
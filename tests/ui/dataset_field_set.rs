@@ -0,0 +1,10 @@
+use linked_data_next::Deserialize;
+
+#[derive(Deserialize)]
+#[ld(dataset)]
+struct Document {
+	#[ld(default_graph, set)]
+	people: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use linked_data_next::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Item {
+	#[ld("ex:name")]
+	name: String,
+
+	#[ld(default)]
+	cache: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use linked_data_next::Serialize;
+
+#[derive(Serialize)]
+#[ld(dataset)]
+struct Document {
+	#[ld(default_graph)]
+	index: Vec<String>,
+
+	#[ld(default_graph)]
+	people: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use iref::IriBuf;
+use linked_data_next::Serialize;
+
+#[derive(Serialize)]
+struct Person {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld(id)]
+	same_as: IriBuf,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use linked_data_next::Deserialize;
+
+#[derive(Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+#[ld(dataset)]
+struct Document {
+	#[ld(named_graph = "ex:people")]
+	index: Vec<String>,
+
+	#[ld(named_graph = "http://example.org/people")]
+	people: Vec<String>,
+}
+
+fn main() {}
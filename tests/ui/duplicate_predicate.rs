@@ -0,0 +1,13 @@
+use linked_data_next::Serialize;
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Person {
+	#[ld("ex:name")]
+	name: String,
+
+	#[ld("http://example.org/name")]
+	full_name: String,
+}
+
+fn main() {}
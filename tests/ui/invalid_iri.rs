@@ -0,0 +1,9 @@
+use linked_data_next::Serialize;
+
+#[derive(Serialize)]
+struct Person {
+	#[ld("full name")]
+	name: String,
+}
+
+fn main() {}
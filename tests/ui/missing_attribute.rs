@@ -0,0 +1,12 @@
+use linked_data_next::Serialize;
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Person {
+	#[ld("ex:name")]
+	name: String,
+
+	age: u32,
+}
+
+fn main() {}
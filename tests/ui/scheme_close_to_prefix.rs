@@ -0,0 +1,10 @@
+use linked_data_next::Serialize;
+
+#[derive(Serialize)]
+#[ld(prefix("sio" = "http://semanticscience.org/resource/"))]
+struct Contact {
+	#[ld("sip:alice@example.org")]
+	phone: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use linked_data_next::Serialize;
+
+#[derive(Serialize)]
+struct Label {
+	#[ld("rdfs:label")]
+	label: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use linked_data_next::Serialize;
+
+#[derive(Serialize)]
+#[ld(prefix("schema" = "https://schema.org/"))]
+struct Person {
+	#[ld("shcema:name")]
+	name: String,
+}
+
+fn main() {}
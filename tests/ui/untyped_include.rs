@@ -0,0 +1,17 @@
+use linked_data_next::Deserialize;
+
+#[derive(Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Friend {
+	#[ld("ex:name")]
+	name: String,
+}
+
+#[derive(Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Doc {
+	#[ld(include)]
+	extra: Vec<Friend>,
+}
+
+fn main() {}
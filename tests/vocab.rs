@@ -0,0 +1,150 @@
+use iref::IriBuf;
+use json_syntax::json;
+use linked_data_next::{Deserialize, JsonLdContext, Serialize, nquads, roundtrip};
+use rdf_types::generator;
+
+fn ex(local: &str) -> IriBuf {
+	IriBuf::new(format!("http://example.org/{local}")).unwrap()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, JsonLdContext)]
+#[ld(prefix("ex" = "http://example.org/"))]
+#[ld(vocab = "https://schema.org/", rename_all = "camelCase")]
+struct Person {
+	#[ld(id)]
+	id: IriBuf,
+
+	given_name: String,
+
+	family_name: Option<String>,
+
+	#[ld("ex:nickname")]
+	nick_name: Vec<String>,
+
+	#[ld(set)]
+	knows_about: Vec<String>,
+
+	r#type: Option<String>,
+
+	#[ld(ignore)]
+	cache_key: u32,
+}
+
+fn person() -> Person {
+	Person {
+		id: ex("jane"),
+		given_name: "Jane".to_owned(),
+		family_name: Some("Doe".to_owned()),
+		nick_name: Vec::new(),
+		knows_about: vec!["RDF".to_owned()],
+		r#type: Some("admin".to_owned()),
+		cache_key: 0,
+	}
+}
+
+#[test]
+fn vocab_expansion() {
+	let document = nquads::to_string_sorted(generator::Blank::new(), &person()).unwrap();
+	assert_eq!(
+		document,
+		"<http://example.org/jane> <http://example.org/nickname> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .\n\
+		<http://example.org/jane> <https://schema.org/familyName> \"Doe\" .\n\
+		<http://example.org/jane> <https://schema.org/givenName> \"Jane\" .\n\
+		<http://example.org/jane> <https://schema.org/knowsAbout> \"RDF\" .\n\
+		<http://example.org/jane> <https://schema.org/type> \"admin\" .\n"
+	)
+}
+
+#[test]
+fn vocab_round_trip() {
+	let value = person();
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn vocab_context() {
+	assert_eq!(
+		Person::json_ld_context(),
+		json!({
+			"ex": "http://example.org/",
+			"@vocab": "https://schema.org/",
+			"id": "@id",
+			"given_name": "https://schema.org/givenName",
+			"family_name": "https://schema.org/familyName",
+			"nick_name": { "@id": "http://example.org/nickname", "@container": "@list" },
+			"knows_about": { "@id": "https://schema.org/knowsAbout", "@container": "@set" },
+			"type": "https://schema.org/type"
+		})
+	)
+}
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/terms#"))]
+#[ld(vocab = "ex:")]
+struct Compact {
+	#[ld(id)]
+	id: IriBuf,
+
+	http_status: u16,
+}
+
+#[test]
+fn compact_vocab() {
+	let value = Compact {
+		id: ex("response"),
+		http_status: 200,
+	};
+
+	let document = nquads::to_string_sorted(generator::Blank::new(), &value).unwrap();
+	assert_eq!(
+		document,
+		"<http://example.org/response> <http://example.org/terms#http_status> \"200\"^^<http://www.w3.org/2001/XMLSchema#unsignedShort> .\n"
+	)
+}
+
+macro_rules! rename_rules {
+	($($id:ident: $rule:tt => $term:literal),*) => {
+		$(
+			#[derive(Serialize)]
+			#[ld(vocab = "http://example.org/", rename_all = $rule)]
+			struct $id {
+				#[ld(id)]
+				id: IriBuf,
+
+				http_status_code: u16,
+			}
+		)*
+
+		#[test]
+		fn rename_all() {
+			$(
+				let value = $id {
+					id: ex("response"),
+					http_status_code: 200,
+				};
+
+				let document = nquads::to_string_sorted(generator::Blank::new(), &value).unwrap();
+				assert_eq!(
+					document,
+					format!(
+						"<http://example.org/response> <http://example.org/{}> \"200\"^^<http://www.w3.org/2001/XMLSchema#unsignedShort> .\n",
+						$term
+					),
+					"rule {}",
+					$rule
+				);
+			)*
+		}
+	};
+}
+
+rename_rules! {
+	LowerCase: "lowercase" => "httpstatuscode",
+	UpperCase: "UPPERCASE" => "HTTPSTATUSCODE",
+	PascalCase: "PascalCase" => "HttpStatusCode",
+	CamelCase: "camelCase" => "httpStatusCode",
+	SnakeCase: "snake_case" => "http_status_code",
+	ScreamingSnakeCase: "SCREAMING_SNAKE_CASE" => "HTTP_STATUS_CODE",
+	KebabCase: "kebab-case" => "http-status-code",
+	ScreamingKebabCase: "SCREAMING-KEBAB-CASE" => "HTTP-STATUS-CODE"
+}
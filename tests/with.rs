@@ -0,0 +1,216 @@
+use std::{fmt, net::IpAddr, str::FromStr};
+
+use iref::IriBuf;
+use linked_data_next::{
+	Deserialize, FromLinkedDataError, Serialize, from_nquads_str,
+	nquads::{self, FromNQuadsError},
+	roundtrip,
+};
+use rdf_types::{Id, Term, generator};
+
+/// Maps any `Display + FromStr` value to a plain string literal.
+mod display {
+	use std::{fmt, str::FromStr};
+
+	use linked_data_next::{
+		Context, FromLinkedDataError, LinkedDataDeserializePredicateObjects,
+		PredicateObjectsVisitor,
+	};
+	use rdf_types::{
+		Interpretation, Vocabulary,
+		dataset::PatternMatchingDataset,
+		interpretation::{ReverseIriInterpretation, ReverseLiteralInterpretation},
+		vocabulary::{IriVocabularyMut, LiteralVocabulary, LiteralVocabularyMut},
+	};
+
+	pub fn serialize<T, I, V, S>(value: &T, mut visitor: S) -> Result<S::Ok, S::Error>
+	where
+		T: fmt::Display,
+		I: Interpretation,
+		V: Vocabulary + IriVocabularyMut + LiteralVocabularyMut,
+		S: PredicateObjectsVisitor<I, V>,
+	{
+		visitor.object(&value.to_string())?;
+		visitor.end()
+	}
+
+	pub fn deserialize<'a, T, I, V, D>(
+		vocabulary: &V,
+		interpretation: &I,
+		dataset: &D,
+		graph: Option<&I::Resource>,
+		objects: impl IntoIterator<Item = &'a I::Resource>,
+		context: Context<I>,
+	) -> Result<T, FromLinkedDataError>
+	where
+		T: FromStr,
+		I: Interpretation
+			+ ReverseIriInterpretation<Iri = V::Iri>
+			+ ReverseLiteralInterpretation<Literal = V::Literal>,
+		V: Vocabulary + LiteralVocabulary,
+		I::Resource: 'a,
+		D: PatternMatchingDataset<Resource = I::Resource>,
+	{
+		let value = String::deserialize_objects_in(
+			vocabulary,
+			interpretation,
+			dataset,
+			graph,
+			objects,
+			context,
+		)?;
+
+		value.parse().map_err(|_| {
+			FromLinkedDataError::InvalidLiteral(context.into_iris(vocabulary, interpretation))
+		})
+	}
+}
+
+/// Serializes a list of values as a single comma-separated string literal.
+mod comma_separated {
+	use std::fmt;
+
+	use linked_data_next::PredicateObjectsVisitor;
+	use rdf_types::{
+		Interpretation, Vocabulary,
+		vocabulary::{IriVocabularyMut, LiteralVocabularyMut},
+	};
+
+	pub fn serialize<T, I, V, S>(values: &[T], mut visitor: S) -> Result<S::Ok, S::Error>
+	where
+		T: fmt::Display,
+		I: Interpretation,
+		V: Vocabulary + IriVocabularyMut + LiteralVocabularyMut,
+		S: PredicateObjectsVisitor<I, V>,
+	{
+		let value: Vec<_> = values.iter().map(ToString::to_string).collect();
+		visitor.object(&value.join(","))?;
+		visitor.end()
+	}
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Host<T: fmt::Display + FromStr> {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:address", with = "display")]
+	address: IpAddr,
+
+	#[ld(
+		"ex:gateway",
+		serialize_with = "display::serialize",
+		deserialize_with = "display::deserialize"
+	)]
+	gateway: IpAddr,
+
+	#[ld("ex:port", with = "display")]
+	port: T,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Ports<T: fmt::Display> {
+	#[ld(id)]
+	id: IriBuf,
+
+	#[ld("ex:ports", serialize_with = "comma_separated::serialize")]
+	ports: Vec<T>,
+}
+
+#[derive(Serialize)]
+#[ld(prefix("ex" = "http://example.org/"))]
+struct Borrowed<'a, T: fmt::Display> {
+	#[ld(id)]
+	id: &'a IriBuf,
+
+	#[ld("ex:name", serialize_with = "display::serialize")]
+	name: &'a str,
+
+	#[ld("ex:ports", serialize_with = "comma_separated::serialize")]
+	ports: &'a [T],
+}
+
+fn ex(local: &str) -> IriBuf {
+	IriBuf::new(format!("http://example.org/{local}")).unwrap()
+}
+
+fn host() -> Host<u16> {
+	Host {
+		id: ex("host"),
+		address: "192.168.1.12".parse().unwrap(),
+		gateway: "192.168.1.1".parse().unwrap(),
+		port: 8080,
+	}
+}
+
+#[test]
+fn with_round_trip() {
+	let value = host();
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value);
+
+	let value = Host {
+		id: ex("host"),
+		address: "::1".parse().unwrap(),
+		gateway: "::2".parse().unwrap(),
+		port: "http".to_owned(),
+	};
+	assert_eq!(roundtrip(generator::Blank::new(), &value).unwrap(), value)
+}
+
+#[test]
+fn with_serialization() {
+	let document = nquads::to_string_sorted(generator::Blank::new(), &host()).unwrap();
+	assert_eq!(
+		document,
+		"<http://example.org/host> <http://example.org/address> \"192.168.1.12\" .\n\
+		<http://example.org/host> <http://example.org/gateway> \"192.168.1.1\" .\n\
+		<http://example.org/host> <http://example.org/port> \"8080\" .\n"
+	)
+}
+
+#[test]
+fn deserialize_with_error() {
+	let document = "<http://example.org/host> <http://example.org/address> \"192.168.1.12\" .\n\
+		<http://example.org/host> <http://example.org/gateway> \"192.168.1.1\" .\n\
+		<http://example.org/host> <http://example.org/port> \"eighty\" .\n";
+
+	assert!(matches!(
+		from_nquads_str::<Host<u16>>(document, &Term::<Id>::iri(ex("host"))),
+		Err(FromNQuadsError::Deserialize(
+			FromLinkedDataError::InvalidLiteral(_)
+		))
+	))
+}
+
+#[test]
+fn serialize_with_generic_items() {
+	let value = Ports {
+		id: ex("ports"),
+		ports: vec![80u16, 443],
+	};
+
+	let document = nquads::to_string_sorted(generator::Blank::new(), &value).unwrap();
+	assert_eq!(
+		document,
+		"<http://example.org/ports> <http://example.org/ports> \"80,443\" .\n"
+	)
+}
+
+#[test]
+fn serialize_with_lifetimes() {
+	let id = ex("server");
+	let value = Borrowed {
+		id: &id,
+		name: "web",
+		ports: &[80u16, 443],
+	};
+
+	let document = nquads::to_string_sorted(generator::Blank::new(), &value).unwrap();
+	assert_eq!(
+		document,
+		"<http://example.org/server> <http://example.org/name> \"web\" .\n\
+		<http://example.org/server> <http://example.org/ports> \"80,443\" .\n"
+	)
+}